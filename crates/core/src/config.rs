@@ -1,10 +1,18 @@
 //! Application configuration handling.
 
-use std::{collections::HashMap, fs, path::PathBuf};
+use std::{
+    collections::HashMap,
+    env,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result};
 use config::{Config, Environment, File};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::currency::{CurrencyFormat, DigitGrouping};
 
 /// Default config file name searched relative to the user config directory.
 pub const CONFIG_FILE: &str = "18tui.toml";
@@ -12,18 +20,203 @@ pub const CONFIG_FILE: &str = "18tui.toml";
 /// Environment variable prefix (e.g. `TUI18__CACHE_ROOT`).
 pub const ENV_PREFIX: &str = "TUI18";
 
+/// Environment variable that sets [`AppConfig::data_dir`], overriding the config file.
+pub const DATA_DIR_ENV: &str = "TUI18_DATA_DIR";
+
+/// File listing recently used engine checkouts, beside the config file or in `data_dir`.
+pub const RECENT_ENGINES_FILE: &str = "recent_engines.json";
+
+/// How many engine checkouts [`RecentEngines`] remembers.
+pub const MAX_RECENT_ENGINES: usize = 8;
+
 /// Runtime configuration derived from files/environment.
 #[derive(Debug, Clone, Deserialize)]
 pub struct AppConfig {
     /// Path to the cache directory where the engine repo lives.
     #[serde(default = "default_cache_root")]
     pub cache_root: PathBuf,
+    /// Single root for saves (`saves/`), the engine cache (`cache/`) and logs (`logs/`);
+    /// takes precedence over `cache_root`. Also set by `TUI18_DATA_DIR`.
+    #[serde(default)]
+    pub data_dir: Option<PathBuf>,
     /// Engine repository URL.
     #[serde(default = "default_repo_url")]
     pub repo_url: String,
     /// Git branch to follow.
     #[serde(default = "default_repo_branch")]
     pub repo_branch: String,
+    /// History depth for clone and fetch; `None` (written as `0`) keeps full history.
+    #[serde(
+        default = "default_clone_depth",
+        deserialize_with = "deserialize_clone_depth"
+    )]
+    pub clone_depth: Option<u32>,
+    /// Existing engine working copy to read games from instead of the managed clone under
+    /// `cache_root`; sync never fetches or resets it.
+    #[serde(default)]
+    pub local_engine_path: Option<PathBuf>,
+    /// `GIT_SSH_COMMAND` for engine git operations, e.g. `ssh -i ~/.ssh/deploy_key` for a
+    /// private fork. Without it, ssh runs in batch mode so it never waits for a passphrase.
+    #[serde(default)]
+    pub git_ssh_command: Option<String>,
+    /// Extra environment for engine git operations (e.g. `GIT_ASKPASS`).
+    #[serde(default)]
+    pub git_env: HashMap<String, String>,
+    /// Preferred market table for games defining several (e.g. `MARKET_2D`).
+    #[serde(default)]
+    pub market_variant: Option<String>,
+    /// Operating rounds assumed for phases whose engine data omits `operating_rounds`.
+    #[serde(default = "default_operating_rounds")]
+    pub default_operating_rounds: usize,
+    /// Show the active game in the terminal window title.
+    #[serde(default = "default_true")]
+    pub terminal_title: bool,
+    /// Feedback when an action fails: `off`, `bell`, or `flash`.
+    #[serde(default)]
+    pub failure_alert: FailureAlert,
+    /// Idle UI ticks (250ms each) before pending input is committed and saved; 0 disables.
+    #[serde(default = "default_autosave_idle_ticks")]
+    pub autosave_idle_ticks: u32,
+    /// Allow launching the system browser/file manager; disable on headless machines.
+    #[serde(default = "default_true")]
+    pub open_urls: bool,
+    /// Where the copy key sends text: `osc52` (works over SSH), `system`, or `off`.
+    #[serde(default)]
+    pub clipboard: ClipboardMode,
+    /// Money template with `%s` for the amount, as in the engine (`$%s`, `%sM`, `£%s`).
+    #[serde(default = "default_currency_format")]
+    pub currency_format: String,
+    /// Thousands separator for money: `comma`, `period`, `space`, or `none`.
+    #[serde(default)]
+    pub currency_grouping: DigitGrouping,
+    /// Refuse train purchases beyond the current phase's train limit.
+    #[serde(default = "default_true")]
+    pub enforce_train_limit: bool,
+    /// Minimum market cell width in columns; cells still grow to fit their labels.
+    #[serde(default)]
+    pub market_cell_width: usize,
+    /// Blank columns between market cells.
+    #[serde(default)]
+    pub market_cell_spacing: usize,
+    /// How corporations appear on the market: `subscript` glyphs or full `symbol`s.
+    #[serde(default)]
+    pub market_tokens: MarketTokens,
+    /// Market zone colors by letter code (`y`, `o`, `b`, `p`, ...) as `#rrggbb` or color
+    /// names; zones left out keep the theme colors.
+    #[serde(default)]
+    pub market_palette: HashMap<String, String>,
+    /// Preselect the game of the most recently written save on launch.
+    #[serde(default = "default_true")]
+    pub select_last_played: bool,
+    /// UI ticks (250ms each) between history snapshots while replaying a save.
+    #[serde(default = "default_replay_step_ticks")]
+    pub replay_step_ticks: u32,
+    /// Echo routine confirmations (cursor moves, view toggles, filter text) in the status
+    /// bar; errors and outcomes are always shown.
+    #[serde(default = "default_true")]
+    pub verbose_status: bool,
+    /// Reload the game list whenever files under the engine's `lib/engine/game` change
+    /// (also enabled by `--watch`); meant for editing a `local_engine_path`.
+    #[serde(default)]
+    pub watch_engine: bool,
+    /// Ask for a yes/no before destructive actions (resetting a corporation, clearing the
+    /// market, removing a custom phase); when false they run immediately and can be undone.
+    #[serde(default = "default_true")]
+    pub confirm_destructive: bool,
+    /// Show at most this many games in the list, most recently played first (`A` reveals
+    /// the rest); 0 shows every game. Filtering always searches all games.
+    #[serde(default)]
+    pub max_games: usize,
+    /// Longest train distance label, in characters, before it is cut off with `…`;
+    /// 0 never truncates.
+    #[serde(default = "default_distance_width")]
+    pub distance_width: usize,
+    /// Keep the play-screen title banner up until the market or revenue view needs its
+    /// space, instead of hiding it on the first key.
+    #[serde(default)]
+    pub pin_title_banner: bool,
+    /// List corporations by symbol only, dropping full names to leave room on narrow
+    /// terminals (`v` toggles it in play).
+    #[serde(default)]
+    pub abbreviate_corporations: bool,
+    /// Let a quick second Enter on the same cell in par selection update a par that is
+    /// already set, instead of requiring `p`. Price selection sets on the first Enter.
+    #[serde(default)]
+    pub double_key_confirm: bool,
+    /// Every payout edit in the revenue view also selects that corporation in the list
+    /// and aims the market cursor at its share price.
+    #[serde(default)]
+    pub focus_follows_revenue: bool,
+    /// UI ticks (250ms each) before a status message reverts to "Ready"; errors stay until
+    /// replaced. 0 keeps every status until the next one.
+    #[serde(default)]
+    pub status_timeout_ticks: u32,
+}
+
+/// How the UI draws attention to failed actions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureAlert {
+    /// Status text only.
+    #[default]
+    Off,
+    /// Ring the terminal bell.
+    Bell,
+    /// Invert the status bar until the next tick.
+    Flash,
+}
+
+impl FailureAlert {
+    fn as_str(self) -> &'static str {
+        match self {
+            FailureAlert::Off => "off",
+            FailureAlert::Bell => "bell",
+            FailureAlert::Flash => "flash",
+        }
+    }
+}
+
+/// Clipboard backend used by copy shortcuts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardMode {
+    /// Emit an OSC 52 escape so the terminal (even over SSH) sets the clipboard.
+    #[default]
+    Osc52,
+    /// Pipe into the platform clipboard tool (`pbcopy`, `wl-copy`, `xclip`, `clip`).
+    System,
+    /// Only show the text in the status line.
+    Off,
+}
+
+impl ClipboardMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            ClipboardMode::Osc52 => "osc52",
+            ClipboardMode::System => "system",
+            ClipboardMode::Off => "off",
+        }
+    }
+}
+
+/// Rendering of corporation tokens on the stock market grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketTokens {
+    /// One subscript glyph per corporation overlaid on the price.
+    #[default]
+    Subscript,
+    /// Full corporation symbols in corporation colors on a line below the price.
+    Symbol,
+}
+
+impl MarketTokens {
+    fn as_str(self) -> &'static str {
+        match self {
+            MarketTokens::Subscript => "subscript",
+            MarketTokens::Symbol => "symbol",
+        }
+    }
 }
 
 impl AppConfig {
@@ -35,28 +228,227 @@ impl AppConfig {
         let config = builder
             .build()
             .context("failed to build configuration sources")?;
-        config
+        let mut config = config
             .try_deserialize::<AppConfig>()
-            .context("failed to deserialize configuration")
+            .context("failed to deserialize configuration")?;
+        config.override_data_dir(env::var_os(DATA_DIR_ENV));
+        Ok(config)
+    }
+
+    /// Applies the value of [`DATA_DIR_ENV`]; unset or empty keeps the configured
+    /// `data_dir`.
+    fn override_data_dir(&mut self, value: Option<OsString>) {
+        if let Some(dir) = value.filter(|dir| !dir.is_empty()) {
+            self.data_dir = Some(PathBuf::from(dir));
+        }
+    }
+
+    /// Directory holding the engine checkout: `data_dir/cache` when a data directory is
+    /// set, otherwise `cache_root`.
+    pub fn cache_dir(&self) -> PathBuf {
+        match &self.data_dir {
+            Some(dir) => dir.join("cache"),
+            None => self.cache_root.clone(),
+        }
+    }
+
+    /// Directory for log files: `data_dir/logs`, or `logs` under the working directory.
+    pub fn log_dir(&self) -> Result<PathBuf> {
+        match &self.data_dir {
+            Some(dir) => Ok(dir.join("logs")),
+            None => Ok(env::current_dir()?.join("logs")),
+        }
+    }
+
+    /// Directory for session exports: `data_dir/exports`, or `exports` under the working
+    /// directory.
+    pub fn export_dir(&self) -> Result<PathBuf> {
+        match &self.data_dir {
+            Some(dir) => Ok(dir.join("exports")),
+            None => Ok(env::current_dir()?.join("exports")),
+        }
+    }
+
+    /// Where [`RecentEngines`] is kept: `data_dir`, or beside the config file.
+    pub fn recent_engines_path(&self) -> PathBuf {
+        match &self.data_dir {
+            Some(dir) => dir.join(RECENT_ENGINES_FILE),
+            None => default_config_path().with_file_name(RECENT_ENGINES_FILE),
+        }
+    }
+
+    /// `data_dir` as a path, for APIs such as `SaveManager::default_root`.
+    pub fn data_dir(&self) -> Option<&Path> {
+        self.data_dir.as_deref()
     }
 
     /// Returns a flat map of settings, useful for diagnostics.
     pub fn as_map(&self) -> HashMap<&'static str, String> {
         HashMap::from([
             ("cache_root", self.cache_root.to_string_lossy().into_owned()),
+            (
+                "data_dir",
+                self.data_dir
+                    .as_ref()
+                    .map(|dir| dir.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ),
             ("repo_url", self.repo_url.clone()),
             ("repo_branch", self.repo_branch.clone()),
+            ("clone_depth", self.clone_depth.unwrap_or(0).to_string()),
+            (
+                "local_engine_path",
+                self.local_engine_path
+                    .as_ref()
+                    .map(|path| path.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ),
+            (
+                "market_variant",
+                self.market_variant.clone().unwrap_or_default(),
+            ),
+            (
+                "git_ssh_command",
+                self.git_ssh_command.clone().unwrap_or_default(),
+            ),
+            // Names only: values may hold credentials.
+            ("git_env", {
+                let mut names: Vec<&str> = self.git_env.keys().map(String::as_str).collect();
+                names.sort_unstable();
+                names.join(",")
+            }),
+            (
+                "default_operating_rounds",
+                self.default_operating_rounds.to_string(),
+            ),
+            ("terminal_title", self.terminal_title.to_string()),
+            ("failure_alert", self.failure_alert.as_str().to_string()),
+            ("autosave_idle_ticks", self.autosave_idle_ticks.to_string()),
+            ("open_urls", self.open_urls.to_string()),
+            ("clipboard", self.clipboard.as_str().to_string()),
+            ("currency_format", self.currency_format.clone()),
+            (
+                "currency_grouping",
+                self.currency_grouping.as_str().to_string(),
+            ),
+            ("enforce_train_limit", self.enforce_train_limit.to_string()),
+            ("market_cell_width", self.market_cell_width.to_string()),
+            ("market_cell_spacing", self.market_cell_spacing.to_string()),
+            ("market_tokens", self.market_tokens.as_str().to_string()),
+            ("market_palette", {
+                let mut zones: Vec<String> = self
+                    .market_palette
+                    .iter()
+                    .map(|(zone, color)| format!("{zone}={color}"))
+                    .collect();
+                zones.sort();
+                zones.join(",")
+            }),
+            ("select_last_played", self.select_last_played.to_string()),
+            ("replay_step_ticks", self.replay_step_ticks.to_string()),
+            ("verbose_status", self.verbose_status.to_string()),
+            ("watch_engine", self.watch_engine.to_string()),
+            ("confirm_destructive", self.confirm_destructive.to_string()),
+            ("max_games", self.max_games.to_string()),
+            ("distance_width", self.distance_width.to_string()),
+            ("pin_title_banner", self.pin_title_banner.to_string()),
+            (
+                "abbreviate_corporations",
+                self.abbreviate_corporations.to_string(),
+            ),
+            ("double_key_confirm", self.double_key_confirm.to_string()),
+            (
+                "focus_follows_revenue",
+                self.focus_follows_revenue.to_string(),
+            ),
+            (
+                "status_timeout_ticks",
+                self.status_timeout_ticks.to_string(),
+            ),
         ])
     }
+
+    /// Formatter for money amounts built from the currency settings.
+    pub fn currency(&self) -> CurrencyFormat {
+        CurrencyFormat::new(self.currency_format.clone(), self.currency_grouping)
+    }
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             cache_root: default_cache_root(),
+            data_dir: None,
             repo_url: default_repo_url(),
             repo_branch: default_repo_branch(),
+            clone_depth: default_clone_depth(),
+            local_engine_path: None,
+            git_ssh_command: None,
+            git_env: HashMap::new(),
+            market_variant: None,
+            default_operating_rounds: default_operating_rounds(),
+            terminal_title: default_true(),
+            failure_alert: FailureAlert::default(),
+            autosave_idle_ticks: default_autosave_idle_ticks(),
+            open_urls: default_true(),
+            clipboard: ClipboardMode::default(),
+            currency_format: default_currency_format(),
+            currency_grouping: DigitGrouping::default(),
+            enforce_train_limit: default_true(),
+            market_cell_width: 0,
+            market_cell_spacing: 0,
+            market_tokens: MarketTokens::default(),
+            market_palette: HashMap::new(),
+            select_last_played: default_true(),
+            replay_step_ticks: default_replay_step_ticks(),
+            verbose_status: default_true(),
+            watch_engine: false,
+            confirm_destructive: default_true(),
+            max_games: 0,
+            distance_width: default_distance_width(),
+            pin_title_banner: false,
+            abbreviate_corporations: false,
+            double_key_confirm: false,
+            focus_follows_revenue: false,
+            status_timeout_ticks: 0,
+        }
+    }
+}
+
+/// Engine checkouts the UI has used, most recent first, for the engine switcher.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentEngines {
+    /// Engine roots, newest first, without duplicates.
+    pub paths: Vec<PathBuf>,
+}
+
+impl RecentEngines {
+    /// Reads the list at `path`; a missing file is an empty list.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    /// Writes the list to `path`, creating its directory.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
         }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Moves `engine` to the front, dropping an older entry for it and anything past
+    /// [`MAX_RECENT_ENGINES`].
+    pub fn record(&mut self, engine: &Path) {
+        self.paths.retain(|path| path != engine);
+        self.paths.insert(0, engine.to_path_buf());
+        self.paths.truncate(MAX_RECENT_ENGINES);
     }
 }
 
@@ -81,6 +473,41 @@ fn default_repo_branch() -> String {
     "master".to_string()
 }
 
+fn default_clone_depth() -> Option<u32> {
+    Some(1)
+}
+
+fn deserialize_clone_depth<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Some(u32::deserialize(deserializer)?).filter(|depth| *depth > 0))
+}
+
+fn default_operating_rounds() -> usize {
+    2
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_distance_width() -> usize {
+    12
+}
+
+fn default_autosave_idle_ticks() -> u32 {
+    20
+}
+
+fn default_replay_step_ticks() -> u32 {
+    4
+}
+
+fn default_currency_format() -> String {
+    "$%s".to_string()
+}
+
 /// Persist a default configuration file if none exists.
 pub fn ensure_default_config() -> Result<()> {
     let path = default_config_path();
@@ -97,10 +524,101 @@ pub fn ensure_default_config() -> Result<()> {
     let defaults = AppConfig::load().unwrap_or_else(|_| AppConfig::default());
 
     let body = format!(
-        "# Default configuration generated by tui18\ncache_root = \"{}\"\nrepo_url = \"{}\"\nrepo_branch = \"{}\"\n",
+        "# Default configuration generated by tui18\ncache_root = \"{}\"\nrepo_url = \"{}\"\nrepo_branch = \"{}\"\nclone_depth = {}\ndefault_operating_rounds = {}\nterminal_title = {}\nfailure_alert = \"{}\"\nautosave_idle_ticks = {}\nopen_urls = {}\nclipboard = \"{}\"\ncurrency_format = \"{}\"\ncurrency_grouping = \"{}\"\nenforce_train_limit = {}\nmarket_cell_width = {}\nmarket_cell_spacing = {}\nmarket_tokens = \"{}\"\nselect_last_played = {}\nreplay_step_ticks = {}\nverbose_status = {}\nwatch_engine = {}\nconfirm_destructive = {}\nmax_games = {}\ndistance_width = {}\npin_title_banner = {}\nabbreviate_corporations = {}\ndouble_key_confirm = {}\nfocus_follows_revenue = {}\nstatus_timeout_ticks = {}\n# data_dir = \"/path/to/18tui-data\"\n# local_engine_path = \"/path/to/18xx\"\n# market_variant = \"MARKET_2D\"\n# git_ssh_command = \"ssh -i ~/.ssh/18xx_deploy_key\"\n# [market_palette]\n# y = \"#e8c547\"\n# [git_env]\n# GIT_ASKPASS = \"/path/to/askpass\"\n",
         defaults.cache_root.display(),
         defaults.repo_url,
-        defaults.repo_branch
+        defaults.repo_branch,
+        defaults.clone_depth.unwrap_or(0),
+        defaults.default_operating_rounds,
+        defaults.terminal_title,
+        defaults.failure_alert.as_str(),
+        defaults.autosave_idle_ticks,
+        defaults.open_urls,
+        defaults.clipboard.as_str(),
+        defaults.currency_format,
+        defaults.currency_grouping.as_str(),
+        defaults.enforce_train_limit,
+        defaults.market_cell_width,
+        defaults.market_cell_spacing,
+        defaults.market_tokens.as_str(),
+        defaults.select_last_played,
+        defaults.replay_step_ticks,
+        defaults.verbose_status,
+        defaults.watch_engine,
+        defaults.confirm_destructive,
+        defaults.max_games,
+        defaults.distance_width,
+        defaults.pin_title_banner,
+        defaults.abbreviate_corporations,
+        defaults.double_key_confirm,
+        defaults.focus_follows_revenue,
+        defaults.status_timeout_ticks
     );
     fs::write(&path, body).with_context(|| format!("failed to write {}", path.display()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{resource::ResourceSync, save::SaveManager};
+
+    #[test]
+    fn data_dir_env_relocates_saves_cache_and_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = AppConfig::default();
+        config.override_data_dir(Some(OsString::new()));
+        assert_eq!(config.data_dir(), None, "empty value is ignored");
+        config.override_data_dir(Some(dir.path().into()));
+        config.override_data_dir(None);
+
+        assert_eq!(config.data_dir(), Some(dir.path()));
+        assert_eq!(
+            SaveManager::default_root(config.data_dir()),
+            dir.path().join("saves")
+        );
+        assert_eq!(
+            ResourceSync::new(AppConfig {
+                local_engine_path: None,
+                ..config.clone()
+            })
+            .repo_path(),
+            dir.path().join("cache").join("engine")
+        );
+        assert_eq!(config.log_dir().unwrap(), dir.path().join("logs"));
+
+        let unset = AppConfig::default();
+        assert_eq!(unset.cache_dir(), unset.cache_root);
+        assert_ne!(SaveManager::default_root(None), dir.path().join("saves"));
+    }
+
+    #[test]
+    fn recent_engines_dedupe_cap_and_round_trip() -> Result<()> {
+        let mut recent = RecentEngines::default();
+        for name in ["a", "b", "a", "c"] {
+            recent.record(Path::new(name));
+        }
+        let names: Vec<_> = recent
+            .paths
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["c", "a", "b"]);
+
+        for index in 0..MAX_RECENT_ENGINES + 2 {
+            recent.record(&PathBuf::from(format!("engine-{index}")));
+        }
+        assert_eq!(recent.paths.len(), MAX_RECENT_ENGINES);
+        assert_eq!(
+            recent.paths[0],
+            PathBuf::from(format!("engine-{}", MAX_RECENT_ENGINES + 1))
+        );
+        assert!(!recent.paths.contains(&PathBuf::from("b")));
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("nested").join(RECENT_ENGINES_FILE);
+        assert_eq!(RecentEngines::load(&path)?, RecentEngines::default());
+        recent.save(&path)?;
+        assert_eq!(RecentEngines::load(&path)?, recent);
+        Ok(())
+    }
+}
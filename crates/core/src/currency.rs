@@ -0,0 +1,124 @@
+//! Money formatting shared by frontends and exports.
+//!
+//! Templates follow the engine's `CURRENCY_FORMAT_STR` convention: `%s` marks where the
+//! grouped amount goes, so `"$%s"` renders `$12,345` and `"%sM"` renders `12,345M`.
+
+use serde::Deserialize;
+
+/// Placeholder replaced by the amount in a currency template.
+pub const AMOUNT_PLACEHOLDER: &str = "%s";
+
+/// Thousands separator used when grouping digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigitGrouping {
+    /// `12,345`
+    #[default]
+    Comma,
+    /// `12.345`
+    Period,
+    /// `12 345`
+    Space,
+    /// `12345`
+    None,
+}
+
+impl DigitGrouping {
+    /// Config spelling of the grouping style.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DigitGrouping::Comma => "comma",
+            DigitGrouping::Period => "period",
+            DigitGrouping::Space => "space",
+            DigitGrouping::None => "none",
+        }
+    }
+
+    fn separator(self) -> Option<char> {
+        match self {
+            DigitGrouping::Comma => Some(','),
+            DigitGrouping::Period => Some('.'),
+            DigitGrouping::Space => Some(' '),
+            DigitGrouping::None => None,
+        }
+    }
+}
+
+/// Currency template plus digit grouping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    template: String,
+    grouping: DigitGrouping,
+}
+
+impl Default for CurrencyFormat {
+    fn default() -> Self {
+        Self::new("$%s", DigitGrouping::Comma)
+    }
+}
+
+impl CurrencyFormat {
+    /// Builds a formatter; templates without `%s` get the amount appended.
+    pub fn new(template: impl Into<String>, grouping: DigitGrouping) -> Self {
+        let mut template = template.into();
+        if !template.contains(AMOUNT_PLACEHOLDER) {
+            template.push_str(AMOUNT_PLACEHOLDER);
+        }
+        Self { template, grouping }
+    }
+
+    /// Formats `amount`, keeping the sign in front of the symbol (`-$1,200`).
+    pub fn format(&self, amount: impl Into<i64>) -> String {
+        let amount = amount.into();
+        let digits = group_digits(amount.unsigned_abs(), self.grouping);
+        let body = self.template.replacen(AMOUNT_PLACEHOLDER, &digits, 1);
+        if amount < 0 {
+            format!("-{body}")
+        } else {
+            body
+        }
+    }
+}
+
+/// Renders `value` with a separator every three digits.
+pub fn group_digits(value: u64, grouping: DigitGrouping) -> String {
+    let digits = value.to_string();
+    let Some(separator) = grouping.separator() else {
+        return digits;
+    };
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, ch) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_digits_at_each_magnitude() {
+        let dollars = CurrencyFormat::default();
+        assert_eq!(dollars.format(0), "$0");
+        assert_eq!(dollars.format(999), "$999");
+        assert_eq!(dollars.format(1_000), "$1,000");
+        assert_eq!(dollars.format(12_345), "$12,345");
+        assert_eq!(dollars.format(123_456), "$123,456");
+        assert_eq!(dollars.format(1_234_567), "$1,234,567");
+        assert_eq!(dollars.format(-4_500), "-$4,500");
+        assert_eq!(group_digits(1_234_567, DigitGrouping::Space), "1 234 567");
+        assert_eq!(group_digits(1_234_567, DigitGrouping::None), "1234567");
+    }
+
+    #[test]
+    fn custom_symbols_follow_the_template() {
+        let marks = CurrencyFormat::new("%sM", DigitGrouping::Period);
+        assert_eq!(marks.format(12_345), "12.345M");
+        let pounds = CurrencyFormat::new("£", DigitGrouping::Comma);
+        assert_eq!(pounds.format(2_500), "£2,500");
+    }
+}
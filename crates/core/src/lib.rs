@@ -7,9 +7,13 @@
 //! used by the terminal UI and any future frontends.
 
 pub mod config;
+pub mod currency;
+pub mod lock;
 pub mod manifest;
 pub mod models;
+pub mod play;
 pub mod resource;
+pub mod revenue;
 pub mod save;
 pub mod session;
 
@@ -0,0 +1,218 @@
+//! Single-instance guard so two frontends never write saves or the cache at once.
+//!
+//! The lock is a `.lock` file holding the owner's PID. A lock whose PID is no longer
+//! running is stale (the owner crashed or was killed) and is taken over.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::Mutex,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use tracing::warn;
+
+/// File name of the lock inside the guarded directory.
+pub const LOCK_FILE: &str = ".lock";
+
+/// How often an empty or unreadable lock is re-read before it counts as stale.
+const OWNER_READ_ATTEMPTS: usize = 3;
+
+/// Pause between those reads.
+const OWNER_READ_DELAY: Duration = Duration::from_millis(20);
+
+/// Lock files created by this process, so panic and signal handlers can release them.
+static HELD: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Result of trying to take the lock.
+#[derive(Debug)]
+pub enum LockStatus {
+    /// This process now owns the lock.
+    Acquired(InstanceLock),
+    /// Another live process owns the lock.
+    HeldBy(u32),
+}
+
+/// An acquired lock; dropping it removes the lock file.
+#[derive(Debug)]
+pub struct InstanceLock {
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    /// Takes the lock in `dir`, replacing a stale lock left by a process that is gone.
+    pub fn acquire(dir: &Path) -> Result<LockStatus> {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+        let path = dir.join(LOCK_FILE);
+        // The PID is written under a private name and linked into place, so the lock file
+        // never exists without its owner in it.
+        let staged = dir.join(format!("{LOCK_FILE}.{}.tmp", process::id()));
+        fs::write(&staged, process::id().to_string())
+            .with_context(|| format!("failed to write {}", staged.display()))?;
+        let status = Self::link_staged(&staged, path);
+        let _ = fs::remove_file(&staged);
+        status
+    }
+
+    fn link_staged(staged: &Path, path: PathBuf) -> Result<LockStatus> {
+        // Retries: a stale lock removed on one pass is taken on the next.
+        for _ in 0..3 {
+            match fs::hard_link(staged, &path) {
+                Ok(()) => {
+                    lock_registry().push(path.clone());
+                    return Ok(LockStatus::Acquired(Self { path }));
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    match settled_owner(&path) {
+                        Some(pid) if pid != process::id() && process_alive(pid) => {
+                            return Ok(LockStatus::HeldBy(pid));
+                        }
+                        owner => remove_stale(&path, owner)?,
+                    }
+                }
+                Err(err) => {
+                    return Err(err).with_context(|| format!("failed to create {}", path.display()))
+                }
+            }
+        }
+        anyhow::bail!("failed to acquire {}", path.display())
+    }
+
+    /// Location of the lock file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        release(&self.path);
+        lock_registry().retain(|held| held != &self.path);
+    }
+}
+
+/// Removes every lock this process holds; for panic and signal handlers that exit
+/// without running destructors.
+pub fn release_held() {
+    for path in lock_registry().iter() {
+        release(path);
+    }
+}
+
+/// Whether a process with `pid` is still running.
+#[cfg(unix)]
+pub fn process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks the target exists; EPERM means it exists but is not ours.
+    // SAFETY: kill takes two plain integers and touches no memory of ours; signal 0
+    // delivers nothing to the target.
+    let signalled = unsafe { libc::kill(pid, 0) } == 0;
+    signalled || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Whether a process with `pid` is still running.
+#[cfg(not(unix))]
+pub fn process_alive(pid: u32) -> bool {
+    process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+        .unwrap_or(true)
+}
+
+fn lock_registry() -> std::sync::MutexGuard<'static, Vec<PathBuf>> {
+    HELD.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn read_owner(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Reads the lock's owner, re-reading a few times while it is empty or unreadable before
+/// giving up on it.
+fn settled_owner(path: &Path) -> Option<u32> {
+    for _ in 0..OWNER_READ_ATTEMPTS {
+        if let Some(pid) = read_owner(path) {
+            return Some(pid);
+        }
+        thread::sleep(OWNER_READ_DELAY);
+    }
+    None
+}
+
+/// Deletes a stale lock, unless it changed hands since `owner` was read.
+fn remove_stale(path: &Path, owner: Option<u32>) -> Result<()> {
+    if read_owner(path) != owner {
+        return Ok(());
+    }
+    warn!(?owner, path = %path.display(), "Removing stale lock");
+    remove_if_present(path)
+}
+
+/// Deletes the lock file, but only while it still names this process.
+fn release(path: &Path) {
+    if read_owner(path) == Some(process::id()) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn remove_if_present(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => {
+            Err(err).with_context(|| format!("failed to remove {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquire_and_release_on_drop() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let LockStatus::Acquired(lock) = InstanceLock::acquire(dir.path())? else {
+            panic!("lock should be free");
+        };
+        let path = lock.path().to_path_buf();
+        assert_eq!(fs::read_to_string(&path)?, process::id().to_string());
+        drop(lock);
+        assert!(!path.exists());
+
+        // A live foreign owner blocks us; our parent process stands in for one.
+        #[cfg(unix)]
+        {
+            let parent = std::os::unix::process::parent_id();
+            fs::write(&path, parent.to_string())?;
+            let status = InstanceLock::acquire(dir.path())?;
+            assert!(
+                matches!(status, LockStatus::HeldBy(pid) if pid == parent),
+                "{status:?}"
+            );
+            assert_eq!(fs::read_to_string(&path)?, parent.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stale_lock_is_taken_over() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(LOCK_FILE);
+        // Far above any real pid_max, so nothing can be running with it.
+        for stale in ["999999999", "not a pid", ""] {
+            fs::write(&path, stale)?;
+            let status = InstanceLock::acquire(dir.path())?;
+            assert!(matches!(status, LockStatus::Acquired(_)), "{status:?}");
+            assert_eq!(fs::read_to_string(&path)?, process::id().to_string());
+            // The staged PID file is gone once the lock is linked into place.
+            assert_eq!(fs::read_dir(dir.path())?.count(), 1);
+        }
+        Ok(())
+    }
+}
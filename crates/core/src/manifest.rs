@@ -16,6 +16,12 @@ pub struct ResourceMetadata {
     pub commit: Option<String>,
     /// ISO8601 timestamp for the snapshot.
     pub updated_at: Option<DateTime<Utc>>,
+    /// Branch the snapshot was fetched from; absent in manifests written by older builds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Full ref that was fetched (e.g. `refs/heads/master`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ref_spec: Option<String>,
 }
 
 impl ResourceMetadata {
@@ -33,6 +39,18 @@ impl ResourceMetadata {
         Ok(metadata)
     }
 
+    /// Returns `(previous, current)` branch names when this snapshot tracks a different
+    /// branch than `previous`. Unknown branches never count as a switch.
+    pub fn branch_switch<'a>(
+        &'a self,
+        previous: &'a ResourceMetadata,
+    ) -> Option<(&'a str, &'a str)> {
+        match (previous.branch.as_deref(), self.branch.as_deref()) {
+            (Some(old), Some(new)) if old != new => Some((old, new)),
+            _ => None,
+        }
+    }
+
     /// Persist metadata to the given file, creating parent directories if needed.
     pub fn persist(&self, path: impl AsRef<Path>) -> Result<()> {
         let path = path.as_ref();
@@ -53,3 +71,35 @@ impl ResourceMetadata {
 pub fn manifest_path(repo_path: impl AsRef<Path>) -> PathBuf {
     repo_path.as_ref().join(".18tui-manifest.json")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branch_fields_round_trip_and_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = manifest_path(dir.path());
+        fs::write(&path, r#"{"commit":"abc1234","updated_at":null}"#).unwrap();
+        let legacy = ResourceMetadata::load(&path).unwrap().unwrap();
+        assert_eq!(legacy.branch, None);
+        assert_eq!(legacy.ref_spec, None);
+
+        let current = ResourceMetadata {
+            branch: Some("dev".to_string()),
+            ref_spec: Some("refs/heads/dev".to_string()),
+            ..legacy.clone()
+        };
+        current.persist(&path).unwrap();
+        let loaded = ResourceMetadata::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.branch.as_deref(), Some("dev"));
+        assert_eq!(loaded.ref_spec.as_deref(), Some("refs/heads/dev"));
+
+        assert_eq!(current.branch_switch(&legacy), None);
+        let master = ResourceMetadata {
+            branch: Some("master".to_string()),
+            ..legacy
+        };
+        assert_eq!(current.branch_switch(&master), Some(("master", "dev")));
+    }
+}
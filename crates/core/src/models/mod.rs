@@ -1,5 +1,7 @@
 //! Shared domain models.
 
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -34,4 +36,152 @@ impl GameInfo {
             _ => self.title.clone(),
         }
     }
+
+    /// Text for the large block-font banner: the title, or the id when the title is blank.
+    pub fn banner_text(&self) -> &str {
+        let title = self.title.trim();
+        if title.is_empty() {
+            self.id.trim()
+        } else {
+            title
+        }
+    }
+}
+
+/// Changes between two game lists, matched by game id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GamesDiff {
+    /// Ids only present in the new list.
+    pub added: Vec<String>,
+    /// Ids only present in the old list.
+    pub removed: Vec<String>,
+    /// `(id, old title, new title)` for games present in both whose title changed.
+    pub renamed: Vec<(String, String, String)>,
+}
+
+impl GamesDiff {
+    /// Number of ids listed per category in [`GamesDiff::summary`].
+    const SUMMARY_LIMIT: usize = 3;
+
+    /// Compares `old` against `new`; each category comes out sorted by id.
+    pub fn between(old: &[GameInfo], new: &[GameInfo]) -> Self {
+        let old_titles: BTreeMap<&str, &str> = old
+            .iter()
+            .map(|game| (game.id.as_str(), game.title.as_str()))
+            .collect();
+        let new_titles: BTreeMap<&str, &str> = new
+            .iter()
+            .map(|game| (game.id.as_str(), game.title.as_str()))
+            .collect();
+        let mut diff = GamesDiff::default();
+        for (id, title) in &new_titles {
+            match old_titles.get(id) {
+                None => diff.added.push(id.to_string()),
+                Some(old_title) if old_title != title => {
+                    diff.renamed
+                        .push((id.to_string(), old_title.to_string(), title.to_string()));
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed = old_titles
+            .keys()
+            .filter(|id| !new_titles.contains_key(*id))
+            .map(|id| id.to_string())
+            .collect();
+        diff
+    }
+
+    /// True when both lists hold the same ids and titles.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+
+    /// One-line summary such as `2 added (1830, 1846) · 1 renamed (1889)`.
+    pub fn summary(&self) -> String {
+        let renamed: Vec<String> = self.renamed.iter().map(|(id, ..)| id.clone()).collect();
+        [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("renamed", &renamed),
+        ]
+        .into_iter()
+        .filter(|(_, ids)| !ids.is_empty())
+        .map(|(label, ids)| {
+            let mut listed = ids[..ids.len().min(Self::SUMMARY_LIMIT)].join(", ");
+            if ids.len() > Self::SUMMARY_LIMIT {
+                listed.push_str(&format!(", +{} more", ids.len() - Self::SUMMARY_LIMIT));
+            }
+            format!("{} {label} ({listed})", ids.len())
+        })
+        .collect::<Vec<_>>()
+        .join(" · ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(id: &str, title: &str) -> GameInfo {
+        GameInfo {
+            id: id.to_string(),
+            title: title.to_string(),
+            subtitle: None,
+            folder: format!("g_{id}"),
+            designer: None,
+            location: None,
+            rules_url: None,
+            commit: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn games_diff_detects_added_removed_and_renamed() {
+        let old = [
+            game("1889", "Shikoku"),
+            game("1830", "1830"),
+            game("18AL", "Alabama"),
+        ];
+        let new = [
+            game("1830", "1830"),
+            game("1889", "Shikoku 1889"),
+            game("1846", "The Race for the Midwest"),
+            game("1817", "1817"),
+        ];
+        let diff = GamesDiff::between(&old, &new);
+        assert_eq!(diff.added, ["1817", "1846"]);
+        assert_eq!(diff.removed, ["18AL"]);
+        assert_eq!(
+            diff.renamed,
+            [(
+                "1889".to_string(),
+                "Shikoku".to_string(),
+                "Shikoku 1889".to_string()
+            )]
+        );
+        assert_eq!(
+            diff.summary(),
+            "2 added (1817, 1846) · 1 removed (18AL) · 1 renamed (1889)"
+        );
+        assert!(GamesDiff::between(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn games_diff_summary_caps_long_lists() {
+        let new: Vec<GameInfo> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|id| game(id, id))
+            .collect();
+        let diff = GamesDiff::between(&[], &new);
+        assert_eq!(diff.summary(), "5 added (a, b, c, +2 more)");
+    }
+
+    #[test]
+    fn banner_text_falls_back_to_id() {
+        assert_eq!(game("1889", "Shikoku 1889").banner_text(), "Shikoku 1889");
+        assert_eq!(game("1889", "   ").banner_text(), "1889");
+        assert_eq!(game("1889", "").banner_text(), "1889");
+    }
 }
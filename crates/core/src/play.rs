@@ -0,0 +1,1176 @@
+//! Frontend-independent game mechanics layered over a loaded [`GameSession`].
+//!
+//! [`Game`] owns everything that survives a save/load: the session (corporations,
+//! market, train pool), the phase list, and per-OR revenue. Cursors, scroll offsets, and
+//! modes stay with the frontend, which addresses corporations by index.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::session::{
+    Corporation, CorporationTrain, GameSession, MarketCell, MarketPosition, TrainType,
+};
+
+/// Percentage of the president's certificate, the first one a corporation hands out.
+pub const PRESIDENT_SHARE: u8 = 20;
+
+/// Percentage of an ordinary share certificate.
+pub const SHARE: u8 = 10;
+
+/// Largest engine `operating_rounds` considered plausible; larger counts are still used
+/// but reported as suspect.
+pub const MAX_OPERATING_ROUNDS: usize = 10;
+
+/// Simplified phase metadata normalized from the engine's `PHASES` data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseInfo {
+    /// Phase name as written in the engine (e.g. `2`, `3`, `D`).
+    pub name: String,
+    /// Operating rounds per set while this phase is active.
+    pub operating_rounds: usize,
+    /// Original engine value the phase was built from.
+    pub raw: Value,
+    /// Added by the player during play rather than sourced from engine data.
+    #[serde(default)]
+    pub custom: bool,
+    /// Most trains a corporation may hold during this phase, if the engine sets one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub train_limit: Option<TrainLimit>,
+}
+
+/// Phase train limit, either shared by every corporation or keyed by corporation type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TrainLimit {
+    /// Same limit for all corporations.
+    Fixed(usize),
+    /// Limit per corporation type (`major`, `minor`, ...).
+    ByType(BTreeMap<String, usize>),
+}
+
+impl TrainLimit {
+    /// Parses the engine's `train_limit` value; anything unrecognised means no limit.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(number) => number
+                .as_u64()
+                .map(|limit| TrainLimit::Fixed(limit as usize)),
+            Value::Object(map) => {
+                let limits: BTreeMap<String, usize> = map
+                    .iter()
+                    .filter_map(|(kind, limit)| Some((kind.clone(), limit.as_u64()? as usize)))
+                    .collect();
+                (!limits.is_empty()).then_some(TrainLimit::ByType(limits))
+            }
+            _ => None,
+        }
+    }
+
+    /// Limit for a corporation of type `kind`. Untyped corporations use the `major`
+    /// entry, matching the engine's default corporation type.
+    pub fn for_kind(&self, kind: Option<&str>) -> Option<usize> {
+        match self {
+            TrainLimit::Fixed(limit) => Some(*limit),
+            TrainLimit::ByType(limits) => limits.get(kind.unwrap_or("major")).copied(),
+        }
+    }
+}
+
+impl PhaseInfo {
+    /// Normalizes the various PHASES Ruby data shapes into a `PhaseInfo`, using
+    /// `default_rounds` when the engine data omits `operating_rounds`.
+    pub fn from_value(value: &Value, default_rounds: usize) -> Self {
+        let default_rounds = default_rounds.max(1);
+        match value {
+            Value::String(name) => PhaseInfo {
+                name: name.clone(),
+                operating_rounds: default_rounds,
+                raw: value.clone(),
+                custom: false,
+                train_limit: None,
+            },
+            Value::Object(map) => {
+                let name = map
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?")
+                    .to_string();
+                let operating_rounds = map
+                    .get("operating_rounds")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v as usize)
+                    .unwrap_or(default_rounds);
+                PhaseInfo {
+                    name,
+                    operating_rounds: operating_rounds.max(1),
+                    raw: value.clone(),
+                    custom: false,
+                    train_limit: map.get("train_limit").and_then(TrainLimit::from_value),
+                }
+            }
+            _ => PhaseInfo {
+                name: "?".to_string(),
+                operating_rounds: default_rounds,
+                raw: value.clone(),
+                custom: false,
+                train_limit: None,
+            },
+        }
+    }
+
+    /// Whether the engine OR count is missing, 0 or above [`MAX_OPERATING_ROUNDS`], so
+    /// `operating_rounds` may not be the game's own value.
+    pub fn rounds_suspect(&self) -> bool {
+        !self.custom && engine_rounds(&self.raw).is_none()
+    }
+
+    /// Phase name as shown in the UI; custom phases are tagged.
+    pub fn label(&self) -> String {
+        if self.custom {
+            format!("{} (custom)", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+/// Plausible `operating_rounds` from raw engine phase data.
+fn engine_rounds(raw: &Value) -> Option<usize> {
+    let rounds = raw.get("operating_rounds")?.as_u64()?;
+    usize::try_from(rounds)
+        .ok()
+        .filter(|rounds| (1..=MAX_OPERATING_ROUNDS).contains(rounds))
+}
+
+/// Holds per-corporation revenue values for a single OR in a phase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatingRound {
+    /// Revenue per corporation, indexed like `GameSession::corporations`.
+    pub revenues: Vec<i32>,
+}
+
+impl OperatingRound {
+    /// Pre-allocates a revenue slot for every corporation.
+    pub fn new(corporations: usize) -> Self {
+        OperatingRound {
+            revenues: vec![0; corporations],
+        }
+    }
+}
+
+/// How a corporation handles revenue from a train run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevenueAction {
+    /// Pay out to shareholders; the share price moves right (or up at the edge).
+    Dividend,
+    /// Keep the revenue; the share price moves left (or down at the edge).
+    Withhold,
+}
+
+/// Snapshot of a stock movement after resolving a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueOutcome {
+    /// Corporation that ran.
+    pub corp_sym: String,
+    /// Revenue the corporation earned.
+    pub total: i32,
+    /// Share price after the move.
+    pub position: MarketPosition,
+    /// `false` when the market edge prevented any movement.
+    pub moved: bool,
+    /// Whether the revenue was paid or withheld.
+    pub action: RevenueAction,
+}
+
+/// Failures that can occur when adjusting stock prices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevenueError {
+    /// The corporation index does not exist.
+    NoCorporation,
+    /// The corporation has not been parred yet.
+    NoMarketPosition,
+}
+
+impl std::fmt::Display for RevenueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevenueError::NoCorporation => write!(f, "No corporation selected"),
+            RevenueError::NoMarketPosition => {
+                write!(f, "Set par price before adjusting stock price")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RevenueError {}
+
+/// Reasons a share cannot be sold back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareError {
+    /// The corporation index does not exist.
+    NoCorporation,
+    /// The owner holds no shares of the corporation.
+    NotHeld,
+    /// Only the president's certificate is left, which cannot be split into a 10% share.
+    PresidentsCertificate,
+}
+
+impl std::fmt::Display for ShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareError::NoCorporation => write!(f, "No corporation selected"),
+            ShareError::NotHeld => write!(f, "No shares held"),
+            ShareError::PresidentsCertificate => {
+                write!(f, "The president's certificate cannot be sold")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShareError {}
+
+/// Persistent game state and the rules that mutate it.
+///
+/// Serializes with `session`, `phases`, `phase_index`, and `phase_rounds` as top-level
+/// keys so frontends can `#[serde(flatten)]` it into their own save format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Game {
+    /// Engine data plus per-corporation runtime state.
+    pub session: GameSession,
+    /// Phases in play order, including player-added ones.
+    #[serde(default)]
+    pub phases: Vec<PhaseInfo>,
+    /// Index into `phases` of the active phase.
+    #[serde(default)]
+    pub phase_index: usize,
+    /// Operating rounds per phase, parallel to `phases`.
+    #[serde(default)]
+    pub phase_rounds: Vec<Vec<OperatingRound>>,
+}
+
+impl Game {
+    /// Seeds phases and empty revenue tables from a freshly loaded session; phases
+    /// without an explicit OR count get `default_rounds`.
+    pub fn new(session: GameSession, default_rounds: usize) -> Self {
+        let phases: Vec<PhaseInfo> = if session.phases.is_empty() {
+            vec![PhaseInfo {
+                name: "Phase".to_string(),
+                operating_rounds: default_rounds.max(1),
+                raw: Value::Null,
+                custom: false,
+                train_limit: None,
+            }]
+        } else {
+            session
+                .phases
+                .iter()
+                .map(|phase| PhaseInfo::from_value(phase, default_rounds))
+                .collect()
+        };
+        let corp_count = session.corporations.len();
+        let phase_rounds = phases
+            .iter()
+            .map(|phase| {
+                (0..phase.operating_rounds.max(1))
+                    .map(|_| OperatingRound::new(corp_count))
+                    .collect()
+            })
+            .collect();
+        Self {
+            session,
+            phases,
+            phase_index: 0,
+            phase_rounds,
+        }
+    }
+
+    /// Takes engine data from a freshly extracted `fresh` session (game info, market,
+    /// train types, corporation names and colors) while keeping play progress: pars,
+    /// share prices, trains, revenues and phases. Corporations are matched by symbol, and
+    /// share prices pick up the new value of their cell when it still exists. Corporations
+    /// only found in `fresh` are appended. The train pool follows the new train types,
+    /// keeping the remaining count of every train that still exists.
+    pub fn refresh_engine_data(&mut self, fresh: GameSession) {
+        let session = &mut self.session;
+        let added: Vec<Corporation> = fresh
+            .corporations
+            .iter()
+            .filter(|source| {
+                !session
+                    .corporations
+                    .iter()
+                    .any(|corp| corp.sym == source.sym)
+            })
+            .cloned()
+            .collect();
+        for corp in &mut session.corporations {
+            let Some(source) = fresh
+                .corporations
+                .iter()
+                .find(|source| source.sym == corp.sym)
+            else {
+                continue;
+            };
+            corp.name = source.name.clone();
+            corp.color = source.color.clone();
+            corp.text_color = source.text_color.clone();
+            corp.kind = source.kind.clone();
+            let cell = corp
+                .market_position
+                .as_ref()
+                .and_then(|position| fresh.market_cell(position.row, position.col));
+            if let Some(cell) = cell {
+                corp.market_position = Some(MarketPosition::from(cell));
+            }
+            let par_cell = corp
+                .par_cell
+                .as_ref()
+                .and_then(|position| fresh.market_cell(position.row, position.col));
+            if let Some(cell) = par_cell {
+                corp.par_cell = Some(MarketPosition::from(cell));
+            }
+        }
+        let train_pool = fresh
+            .train_pool
+            .into_iter()
+            .map(|fresh_entry| {
+                session
+                    .train_pool
+                    .iter()
+                    .find(|entry| entry.name == fresh_entry.name)
+                    .cloned()
+                    .unwrap_or(fresh_entry)
+            })
+            .collect();
+        session.info = fresh.info;
+        session.loaded_at = fresh.loaded_at;
+        session.market = fresh.market;
+        session.market_name = fresh.market_name;
+        session.market_cells = fresh.market_cells;
+        session.market_index = fresh.market_index;
+        session.par_cells = fresh.par_cells;
+        session.train_types = fresh.train_types;
+        session.train_pool = train_pool;
+        session.corporations.extend(added);
+        let corp_count = session.corporations.len();
+        for round in self.phase_rounds.iter_mut().flatten() {
+            if round.revenues.len() < corp_count {
+                round.revenues.resize(corp_count, 0);
+            }
+        }
+    }
+
+    /// Corporation at `index`, if any.
+    pub fn corporation(&self, index: usize) -> Option<&Corporation> {
+        self.session.corporations.get(index)
+    }
+
+    /// Mutable corporation at `index`, if any.
+    pub fn corporation_mut(&mut self, index: usize) -> Option<&mut Corporation> {
+        self.session.corporations.get_mut(index)
+    }
+
+    /// Names of engine phases with a suspect OR count, so the revenue grid may have the
+    /// wrong number of columns for them.
+    pub fn suspect_round_phases(&self) -> Vec<&str> {
+        self.phases
+            .iter()
+            .filter(|phase| phase.rounds_suspect())
+            .map(|phase| phase.name.as_str())
+            .collect()
+    }
+
+    /// Number of phases, including custom ones.
+    pub fn phase_count(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Active phase index, clamped to the phase list.
+    pub fn current_phase_index(&self) -> usize {
+        self.phase_index.min(self.phases.len().saturating_sub(1))
+    }
+
+    /// Active phase, if any are defined.
+    pub fn current_phase(&self) -> Option<&PhaseInfo> {
+        self.phases.get(self.current_phase_index())
+    }
+
+    /// Switches to phase `index` (clamped). Returns `true` when the phase changed.
+    pub fn set_phase_index(&mut self, index: usize) -> bool {
+        if self.phases.is_empty() {
+            self.phase_index = 0;
+            return false;
+        }
+        let clamped = index.min(self.phases.len() - 1);
+        if clamped == self.phase_index {
+            return false;
+        }
+        self.phase_index = clamped;
+        self.ensure_phase_round_capacity(clamped);
+        true
+    }
+
+    /// Appends a player-defined phase and returns its index; the active phase is unchanged.
+    pub fn add_custom_phase(&mut self, name: String, operating_rounds: usize) -> usize {
+        self.phases.push(PhaseInfo {
+            name,
+            operating_rounds: operating_rounds.max(1),
+            raw: Value::Null,
+            custom: true,
+            train_limit: None,
+        });
+        let index = self.phases.len() - 1;
+        self.ensure_phase_round_capacity(index);
+        index
+    }
+
+    /// Drops the most recently added custom phase along with its revenue rows, keeping
+    /// the active phase pointed at the same phase where possible.
+    pub fn remove_last_custom_phase(&mut self) -> Option<PhaseInfo> {
+        let index = self.phases.iter().rposition(|phase| phase.custom)?;
+        let removed = self.phases.remove(index);
+        if index < self.phase_rounds.len() {
+            self.phase_rounds.remove(index);
+        }
+        if self.phase_index > index {
+            self.phase_index -= 1;
+        }
+        self.phase_index = self.current_phase_index();
+        self.ensure_phase_round_capacity(self.phase_index);
+        Some(removed)
+    }
+
+    /// Makes sure `phase_idx` has at least its configured ORs and a revenue slot per
+    /// corporation in each.
+    pub fn ensure_phase_round_capacity(&mut self, phase_idx: usize) {
+        while self.phase_rounds.len() <= phase_idx {
+            self.phase_rounds.push(Vec::new());
+        }
+        let corp_count = self.session.corporations.len();
+        let desired = self
+            .phases
+            .get(phase_idx)
+            .map(|phase| phase.operating_rounds.max(1))
+            .unwrap_or(1);
+        let rounds = &mut self.phase_rounds[phase_idx];
+        if rounds.len() < desired {
+            rounds.resize_with(desired, || OperatingRound::new(corp_count));
+        }
+        for round in rounds.iter_mut() {
+            if round.revenues.len() < corp_count {
+                round.revenues.resize(corp_count, 0);
+            }
+        }
+    }
+
+    /// Operating rounds of the active phase.
+    pub fn current_phase_rounds(&self) -> &[OperatingRound] {
+        self.phase_rounds
+            .get(self.current_phase_index())
+            .map(|rounds| rounds.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Mutable operating rounds of the active phase, allocating them if needed.
+    pub fn current_phase_rounds_mut(&mut self) -> &mut Vec<OperatingRound> {
+        let idx = self.current_phase_index();
+        self.ensure_phase_round_capacity(idx);
+        &mut self.phase_rounds[idx]
+    }
+
+    /// Adds an OR to the active phase and returns its index; `None` without corporations.
+    pub fn add_operating_round(&mut self) -> Option<usize> {
+        let corp_count = self.session.corporations.len();
+        if corp_count == 0 {
+            return None;
+        }
+        let rounds = self.current_phase_rounds_mut();
+        rounds.push(OperatingRound::new(corp_count));
+        Some(rounds.len() - 1)
+    }
+
+    /// Appends an OR to the active phase pre-filled with OR `source`'s revenues and returns
+    /// its index; `None` without corporations or when `source` does not exist.
+    pub fn add_operating_round_copying(&mut self, source: usize) -> Option<usize> {
+        let corp_count = self.session.corporations.len();
+        if corp_count == 0 {
+            return None;
+        }
+        let rounds = self.current_phase_rounds_mut();
+        let mut round = rounds.get(source)?.clone();
+        round.revenues.resize(corp_count, 0);
+        rounds.push(round);
+        Some(rounds.len() - 1)
+    }
+
+    /// Revenue recorded for corporation `corp` in OR `or` of the active phase.
+    pub fn revenue(&self, corp: usize, or: usize) -> Option<i32> {
+        self.current_phase_rounds()
+            .get(or)
+            .and_then(|round| round.revenues.get(corp))
+            .copied()
+    }
+
+    /// Records revenue for corporation `corp` in OR `or` of the active phase, growing the
+    /// OR list when `or` is past the end. Unknown corporations are ignored.
+    pub fn set_revenue(&mut self, corp: usize, or: usize, value: i32) {
+        let corp_count = self.session.corporations.len();
+        if corp >= corp_count {
+            return;
+        }
+        let rounds = self.current_phase_rounds_mut();
+        if or >= rounds.len() {
+            rounds.resize_with(or + 1, || OperatingRound::new(corp_count));
+        }
+        let round = &mut rounds[or];
+        if round.revenues.len() < corp_count {
+            round.revenues.resize(corp_count, 0);
+        }
+        round.revenues[corp] = value;
+    }
+
+    /// Pars corporation `corp` at market cell `(row, col)`. Returns the par value, or
+    /// `None` when the cell has no numeric value to par at.
+    pub fn set_par(&mut self, corp: usize, row: usize, col: usize) -> Option<i32> {
+        let position = MarketPosition::from(self.session.market_cell(row, col)?);
+        let value = position.numeric_value()?;
+        let corporation = self.corporation_mut(corp)?;
+        corporation.par_value = Some(value);
+        corporation.par_cell = Some(position.clone());
+        corporation.market_position = Some(position);
+        Some(value)
+    }
+
+    /// Moves corporation `corp`'s share price to market cell `(row, col)`.
+    pub fn set_price(&mut self, corp: usize, row: usize, col: usize) -> Option<MarketPosition> {
+        let position = MarketPosition::from(self.session.market_cell(row, col)?);
+        self.corporation_mut(corp)?.market_position = Some(position.clone());
+        Some(position)
+    }
+
+    /// Gives `owner` the next certificate of corporation `corp`: the president's 20% when
+    /// nobody holds shares yet, otherwise 10%. Returns the owner's new holding, or `None`
+    /// when the corporation is missing or the certificate would exceed 100%.
+    pub fn buy_share(&mut self, corp: usize, owner: &str) -> Option<u8> {
+        let corporation = self.corporation_mut(corp)?;
+        let held: u32 = corporation.shares.values().map(|&pct| u32::from(pct)).sum();
+        let certificate = if held == 0 { PRESIDENT_SHARE } else { SHARE };
+        if held + u32::from(certificate) > 100 {
+            return None;
+        }
+        if certificate == PRESIDENT_SHARE {
+            corporation.president = Some(owner.to_string());
+        }
+        let holding = corporation.shares.entry(owner.to_string()).or_insert(0);
+        *holding += certificate;
+        Some(*holding)
+    }
+
+    /// Takes one 10% share of corporation `corp` back from `owner`. Returns the remaining
+    /// holding (an owner at 0% is dropped). The president keeps their certificate, so they
+    /// can only sell the shares they hold beyond it.
+    pub fn sell_share(&mut self, corp: usize, owner: &str) -> Result<u8, ShareError> {
+        let corporation = self
+            .corporation_mut(corp)
+            .ok_or(ShareError::NoCorporation)?;
+        let is_president = corporation.president.as_deref() == Some(owner);
+        let holding = corporation
+            .shares
+            .get_mut(owner)
+            .ok_or(ShareError::NotHeld)?;
+        if is_president && *holding <= PRESIDENT_SHARE {
+            return Err(ShareError::PresidentsCertificate);
+        }
+        *holding = holding.saturating_sub(SHARE);
+        let remaining = *holding;
+        if remaining == 0 {
+            corporation.shares.remove(owner);
+        }
+        Ok(remaining)
+    }
+
+    /// Pays or withholds corporation `corp`'s last revenue and moves its share price.
+    pub fn apply_revenue_action(
+        &mut self,
+        corp: usize,
+        action: RevenueAction,
+    ) -> Result<RevenueOutcome, RevenueError> {
+        let corporation = self.corporation(corp).ok_or(RevenueError::NoCorporation)?;
+        let current = corporation
+            .market_position
+            .clone()
+            .ok_or(RevenueError::NoMarketPosition)?;
+        let corp_sym = corporation.sym.clone();
+        let total = corporation.last_revenue;
+
+        let desired = match action {
+            RevenueAction::Dividend => self
+                .next_market_cell(current.row, current.col, 1, false, |_| true)
+                .or_else(|| self.offset_market_position(&current, (-1, 0))),
+            RevenueAction::Withhold => self
+                .next_market_cell(current.row, current.col, -1, false, |_| true)
+                .or_else(|| self.offset_market_position(&current, (1, 0))),
+        };
+        let moved = desired.is_some();
+        let position = desired.unwrap_or(current);
+        if let Some(corporation) = self.corporation_mut(corp) {
+            corporation.market_position = Some(position.clone());
+        }
+        Ok(RevenueOutcome {
+            corp_sym,
+            total,
+            position,
+            moved,
+            action,
+        })
+    }
+
+    /// Market position `delta` (rows, cols) away from `position`, if that cell exists.
+    pub fn offset_market_position(
+        &self,
+        position: &MarketPosition,
+        delta: (isize, isize),
+    ) -> Option<MarketPosition> {
+        let row = position.row.checked_add_signed(delta.0)?;
+        let col = position.col.checked_add_signed(delta.1)?;
+        self.session.market_cell(row, col).map(MarketPosition::from)
+    }
+
+    /// Next cell along market row `row` from column `col`, stepping `col_delta` columns at a
+    /// time and skipping cells `usable` rejects. With `wrap` the search carries on from the
+    /// other end of the row; without it the search stops at the row's edge. Share price
+    /// moves never wrap; cursor navigation in a single-row market does.
+    pub fn next_market_cell(
+        &self,
+        row: usize,
+        col: usize,
+        col_delta: isize,
+        wrap: bool,
+        usable: impl Fn(&MarketCell) -> bool,
+    ) -> Option<MarketPosition> {
+        let len = self.session.market.get(row)?.len() as isize;
+        if len == 0 || col_delta == 0 {
+            return None;
+        }
+        let mut col = col as isize;
+        for _ in 0..len {
+            col += col_delta;
+            if wrap {
+                col = col.rem_euclid(len);
+            } else if col < 0 || col >= len {
+                return None;
+            }
+            if let Some(cell) = self.session.market_cell(row, col as usize) {
+                if usable(cell) {
+                    return Some(MarketPosition::from(cell));
+                }
+            }
+        }
+        None
+    }
+
+    /// Indices of corporations with a share price, in operating order: highest price
+    /// first, then further right, then higher up the market, then listing order.
+    pub fn operating_order(&self) -> Vec<usize> {
+        self.operating_order_with(None)
+    }
+
+    /// 1-based place corporation `corp` would take in [`Game::operating_order`] if its
+    /// price were market cell `(row, col)`; `None` for cells without a numeric value.
+    pub fn operating_rank_at(&self, corp: usize, row: usize, col: usize) -> Option<usize> {
+        self.corporation(corp)?;
+        let position = MarketPosition::from(self.session.market_cell(row, col)?);
+        position.numeric_value()?;
+        let order = self.operating_order_with(Some((corp, &position)));
+        order
+            .iter()
+            .position(|&idx| idx == corp)
+            .map(|rank| rank + 1)
+    }
+
+    /// Operating order with corporation `moved.0` priced at `moved.1` instead of its own
+    /// market position.
+    fn operating_order_with(&self, moved: Option<(usize, &MarketPosition)>) -> Vec<usize> {
+        let mut priced: Vec<(usize, i32, usize, usize)> = self
+            .session
+            .corporations
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, corp)| {
+                let position = match moved {
+                    Some((moved_idx, position)) if moved_idx == idx => position,
+                    _ => corp.market_position.as_ref()?,
+                };
+                Some((idx, position.numeric_value()?, position.col, position.row))
+            })
+            .collect();
+        priced.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then(b.2.cmp(&a.2))
+                .then(a.3.cmp(&b.3))
+                .then(a.0.cmp(&b.0))
+        });
+        priced.into_iter().map(|(idx, ..)| idx).collect()
+    }
+
+    /// Train definition named `name`.
+    pub fn train_type_for(&self, name: &str) -> Option<&TrainType> {
+        self.session.train_types.iter().find(|ty| ty.name == name)
+    }
+
+    /// Train types with stock left in the pool as `(type index, type, remaining)`.
+    pub fn available_trains(&self) -> Vec<(usize, &TrainType, i64)> {
+        self.train_supply()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, (ty, remaining))| (remaining > 0).then_some((idx, ty, remaining)))
+            .collect()
+    }
+
+    /// Current phase's train limit for corporation `corp`, if the phase sets one.
+    pub fn train_limit(&self, corp: usize) -> Option<usize> {
+        let phase = self.current_phase()?;
+        // Saves written before limits were parsed only carry them in `raw`.
+        let limit = phase
+            .train_limit
+            .clone()
+            .or_else(|| TrainLimit::from_value(phase.raw.get("train_limit")?))?;
+        limit.for_kind(self.corporation(corp)?.kind.as_deref())
+    }
+
+    /// Whether corporation `corp` already holds as many trains as the phase allows.
+    pub fn at_train_limit(&self, corp: usize) -> bool {
+        match (self.train_limit(corp), self.corporation(corp)) {
+            (Some(limit), Some(corporation)) => corporation.trains.len() >= limit,
+            _ => false,
+        }
+    }
+
+    /// Every train type with its remaining supply, including sold-out types.
+    pub fn train_supply(&self) -> Vec<(&TrainType, i64)> {
+        self.session
+            .train_types
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let remaining = self
+                    .session
+                    .train_pool
+                    .get(idx)
+                    .map(|entry| entry.remaining)
+                    .unwrap_or(0);
+                (ty, remaining)
+            })
+            .collect()
+    }
+
+    /// Takes one train of the `selection`-th available type out of the pool.
+    pub fn purchase_train(&mut self, selection: usize) -> Option<CorporationTrain> {
+        let (idx, _, _) = *self.available_trains().get(selection)?;
+        let ty = self.session.train_types.get(idx)?.clone();
+        let pool_entry = self.session.train_pool.get_mut(idx)?;
+        if pool_entry.remaining <= 0 {
+            return None;
+        }
+        pool_entry.remaining -= 1;
+        Some(CorporationTrain {
+            name: ty.name,
+            distance: ty.distance,
+            price: ty.price,
+            revenue_stops: Vec::new(),
+            last_revenue: 0,
+        })
+    }
+
+    /// Removes train `train` from corporation `corp` permanently (rusting), updating the
+    /// corporation's revenue.
+    pub fn rust_train(&mut self, corp: usize, train: usize) -> Option<CorporationTrain> {
+        self.scrap_train(corp, train, false)
+    }
+
+    /// Removes train `train` from corporation `corp` and updates its revenue. With
+    /// `to_pool` the train's supply goes back to the pool so it can be bought again.
+    pub fn scrap_train(
+        &mut self,
+        corp: usize,
+        train: usize,
+        to_pool: bool,
+    ) -> Option<CorporationTrain> {
+        let corporation = self.corporation_mut(corp)?;
+        if train >= corporation.trains.len() {
+            return None;
+        }
+        let removed = corporation.trains.remove(train);
+        corporation.recompute_revenue();
+        if to_pool {
+            if let Some(entry) = self
+                .session
+                .train_pool
+                .iter_mut()
+                .find(|entry| entry.name == removed.name)
+            {
+                entry.remaining += 1;
+            }
+        }
+        Some(removed)
+    }
+
+    /// Returns corporation `corp` to its freshly loaded state and hands its trains back to
+    /// the pool. Yields the corporation symbol and how many trains were returned.
+    pub fn reset_corporation(&mut self, corp: usize) -> Option<(String, usize)> {
+        let corporation = self.corporation_mut(corp)?;
+        let trains = corporation.reset();
+        corporation.recompute_revenue();
+        let sym = corporation.sym.clone();
+        for train in &trains {
+            if let Some(entry) = self
+                .session
+                .train_pool
+                .iter_mut()
+                .find(|entry| entry.name == train.name)
+            {
+                entry.remaining += 1;
+            }
+        }
+        Some((sym, trains.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::TrainPoolEntry;
+
+    #[test]
+    fn revenue_actions_move_along_the_market() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        assert_eq!(
+            game.apply_revenue_action(0, RevenueAction::Dividend)
+                .unwrap_err(),
+            RevenueError::NoMarketPosition
+        );
+        assert_eq!(game.set_par(0, 0, 0), Some(100));
+
+        let outcome = game
+            .apply_revenue_action(0, RevenueAction::Dividend)
+            .unwrap();
+        assert!(outcome.moved);
+        assert_eq!((outcome.position.row, outcome.position.col), (0, 1));
+        game.apply_revenue_action(0, RevenueAction::Withhold)
+            .unwrap();
+        let outcome = game
+            .apply_revenue_action(0, RevenueAction::Withhold)
+            .unwrap();
+        assert_eq!((outcome.position.row, outcome.position.col), (1, 0));
+        assert_eq!(
+            game.apply_revenue_action(5, RevenueAction::Dividend)
+                .unwrap_err(),
+            RevenueError::NoCorporation
+        );
+    }
+
+    #[test]
+    fn par_is_refused_on_cells_without_a_value() {
+        let session = GameSession::fixture_with_market(&[
+            &["-p", "110", "120", "130"],
+            &["90p", "100", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        let mut game = Game::new(session, 2);
+        assert_eq!(game.set_par(0, 0, 0), None);
+        assert_eq!(game.session.corporations[0].par_value, None);
+        assert!(game.session.corporations[0].market_position.is_none());
+        assert_eq!(game.set_par(0, 1, 0), Some(90));
+    }
+
+    #[test]
+    fn revenue_moves_stop_at_the_end_of_a_single_row_market() {
+        let session = GameSession::fixture_with_market(&[&["100p", "110", "120", "130"]]);
+        let mut game = Game::new(session, 2);
+        game.set_price(0, 0, 3);
+
+        let outcome = game
+            .apply_revenue_action(0, RevenueAction::Dividend)
+            .unwrap();
+        assert!(!outcome.moved);
+        assert_eq!((outcome.position.row, outcome.position.col), (0, 3));
+        let wrapped = game
+            .next_market_cell(0, 3, 1, true, |_| true)
+            .expect("wraps");
+        assert_eq!((wrapped.row, wrapped.col), (0, 0));
+
+        game.set_price(0, 0, 0);
+        let outcome = game
+            .apply_revenue_action(0, RevenueAction::Withhold)
+            .unwrap();
+        assert!(!outcome.moved);
+        assert_eq!(outcome.position.col, 0);
+        let skipped = game.next_market_cell(0, 0, -1, true, |cell| cell.col != 3);
+        assert_eq!(skipped.map(|position| position.col), Some(2));
+    }
+
+    #[test]
+    fn refreshed_engine_data_keeps_play_progress() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        assert_eq!(game.set_par(0, 0, 0), Some(100));
+        game.set_price(0, 0, 1);
+
+        let mut fresh = GameSession::fixture_with_market(&[
+            &["100p", "115", "120", "130"],
+            &["90p", "100", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        fresh.corporations[0].name = "Pennsy".to_string();
+        fresh.train_types.remove(1);
+        fresh.train_pool.remove(1);
+        fresh.train_types.push(fresh.train_types[0].clone());
+        fresh.train_types[1].name = "4".to_string();
+        fresh.train_pool.push(TrainPoolEntry {
+            name: "4".to_string(),
+            remaining: 2,
+        });
+        fresh.corporations.push(Corporation::new(
+            "B&O".to_string(),
+            "Baltimore & Ohio".to_string(),
+            None,
+            None,
+        ));
+        game.session.train_pool[0].remaining = 1;
+        game.refresh_engine_data(fresh);
+
+        let corp = game.corporation(0).unwrap();
+        assert_eq!(corp.name, "Pennsy");
+        assert_eq!(corp.par_value, Some(100));
+        assert_eq!(
+            corp.market_position.as_ref().and_then(|p| p.value),
+            Some(115)
+        );
+        let types: Vec<&str> = game
+            .session
+            .train_types
+            .iter()
+            .map(|ty| ty.name.as_str())
+            .collect();
+        let pool: Vec<(&str, i64)> = game
+            .session
+            .train_pool
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.remaining))
+            .collect();
+        assert_eq!(types, ["2", "4"]);
+        assert_eq!(pool, [("2", 1), ("4", 2)]);
+        let syms: Vec<&str> = game
+            .session
+            .corporations
+            .iter()
+            .map(|corp| corp.sym.as_str())
+            .collect();
+        assert_eq!(syms, ["PRR", "NYC", "B&O"]);
+        assert_eq!(game.revenue(2, 0), Some(0));
+    }
+
+    #[test]
+    fn operating_order_ranks_by_price_then_position() {
+        let mut session = GameSession::fixture();
+        session.corporations.push(Corporation::new(
+            "B&O".to_string(),
+            "Baltimore & Ohio".to_string(),
+            None,
+            None,
+        ));
+        let mut game = Game::new(session, 2);
+        assert!(game.operating_order().is_empty());
+
+        game.set_price(0, 1, 2);
+        game.set_price(1, 0, 1);
+        game.set_price(2, 2, 2);
+        // (1, 2) and (0, 1) are both 110, so the cell further right operates first.
+        assert_eq!(game.operating_order(), [0, 1, 2]);
+        game.set_price(2, 0, 3);
+        assert_eq!(game.operating_order(), [2, 0, 1]);
+    }
+
+    #[test]
+    fn operating_rank_treats_the_hovered_cell_as_the_new_price() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        // Nobody priced: any priced cell makes the corporation first.
+        assert_eq!(game.operating_rank_at(0, 2, 0), Some(1));
+
+        game.set_price(0, 0, 1); // PRR at 110
+        assert_eq!(game.operating_rank_at(1, 0, 2), Some(1), "120 beats 110");
+        assert_eq!(game.operating_rank_at(1, 1, 0), Some(2), "90 trails 110");
+        // Same value: further right operates first.
+        assert_eq!(game.operating_rank_at(1, 1, 2), Some(1));
+        assert_eq!(game.operating_rank_at(1, 2, 0), Some(2));
+        // The hypothetical price replaces the corporation's own.
+        assert_eq!(game.operating_rank_at(0, 1, 0), Some(1));
+        assert_eq!(game.operating_order(), [0], "the real order is untouched");
+        assert_eq!(game.operating_rank_at(5, 0, 0), None);
+        assert_eq!(game.operating_rank_at(1, 9, 9), None);
+    }
+
+    #[test]
+    fn trains_leave_and_return_to_the_pool() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        let train = game.purchase_train(1).expect("3 train");
+        assert_eq!(train.name, "3");
+        assert_eq!(game.session.train_pool[1].remaining, 1);
+        game.corporation_mut(0).unwrap().trains.push(train);
+        game.purchase_train(1).expect("last 3 train");
+        assert_eq!(game.available_trains().len(), 1);
+
+        assert_eq!(game.reset_corporation(0), Some(("PRR".to_string(), 1)));
+        assert_eq!(game.session.train_pool[1].remaining, 1);
+        assert!(game.rust_train(0, 0).is_none());
+    }
+
+    #[test]
+    fn train_supply_lists_sold_out_types() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        game.purchase_train(1).expect("3 train");
+        game.purchase_train(1).expect("last 3 train");
+        assert_eq!(game.available_trains().len(), 1);
+
+        let supply: Vec<_> = game
+            .train_supply()
+            .into_iter()
+            .map(|(ty, remaining)| (ty.name.as_str(), remaining, ty.total))
+            .collect();
+        assert_eq!(supply, [("2", 3, 3), ("3", 0, 2)]);
+    }
+
+    #[test]
+    fn integer_train_limit_blocks_purchases_at_the_limit() {
+        let mut session = GameSession::fixture();
+        session.phases = vec![serde_json::json!({ "name": "2", "train_limit": 2 })];
+        let mut game = Game::new(session, 1);
+        assert_eq!(game.train_limit(0), Some(2));
+
+        let train = game.purchase_train(0).expect("first 2 train");
+        game.corporation_mut(0).unwrap().trains.push(train);
+        assert!(!game.at_train_limit(0));
+        let train = game.purchase_train(0).expect("second 2 train");
+        game.corporation_mut(0).unwrap().trains.push(train);
+        assert!(game.at_train_limit(0));
+        assert!(!game.at_train_limit(1));
+
+        game.rust_train(0, 0).expect("rusted");
+        assert!(!game.at_train_limit(0));
+    }
+
+    #[test]
+    fn shares_start_with_the_presidents_certificate() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        assert_eq!(game.buy_share(0, "Alice"), Some(20));
+        assert_eq!(game.buy_share(0, "Bob"), Some(10));
+        assert_eq!(game.buy_share(0, "Alice"), Some(30));
+        for _ in 0..6 {
+            assert!(game.buy_share(0, "Cara").is_some());
+        }
+        assert_eq!(game.buy_share(0, "Dan"), None, "corporation is fully owned");
+
+        assert_eq!(game.sell_share(0, "Bob"), Ok(0));
+        assert!(!game.session.corporations[0].shares.contains_key("Bob"));
+        assert_eq!(game.sell_share(0, "Bob"), Err(ShareError::NotHeld));
+        // The president sells down to their certificate and no further.
+        assert_eq!(game.sell_share(0, "Alice"), Ok(20));
+        assert_eq!(
+            game.sell_share(0, "Alice"),
+            Err(ShareError::PresidentsCertificate)
+        );
+        assert_eq!(game.session.corporations[0].shares["Alice"], 20);
+        assert_eq!(
+            game.session.corporations[0].president.as_deref(),
+            Some("Alice")
+        );
+        assert_eq!(
+            game.buy_share(1, "Bob"),
+            Some(20),
+            "shares are per corporation"
+        );
+
+        game.reset_corporation(0);
+        assert!(game.session.corporations[0].shares.is_empty());
+        assert_eq!(game.session.corporations[0].president, None);
+    }
+
+    #[test]
+    fn phases_without_usable_round_counts_are_reported() {
+        let mut session = GameSession::fixture();
+        session.phases = vec![
+            serde_json::json!({ "name": "2", "operating_rounds": 1 }),
+            serde_json::json!({ "name": "3" }),
+            serde_json::json!({ "name": "4", "operating_rounds": 0 }),
+            serde_json::json!({ "name": "5", "operating_rounds": 500 }),
+        ];
+        let mut game = Game::new(session, 2);
+        assert_eq!(game.suspect_round_phases(), ["3", "4", "5"]);
+        let rounds: Vec<usize> = game
+            .phases
+            .iter()
+            .map(|phase| phase.operating_rounds)
+            .collect();
+        // Suspect counts are only reported: 0 still clamps to 1 and 500 is kept.
+        assert_eq!(rounds, [1, 2, 1, 500]);
+
+        game.add_custom_phase("D".to_string(), 3);
+        assert_eq!(
+            game.suspect_round_phases().len(),
+            3,
+            "custom phases are never suspect"
+        );
+    }
+
+    #[test]
+    fn typed_train_limits_follow_corporation_kind() {
+        let limit = TrainLimit::from_value(&serde_json::json!({ "minor": 2, "major": 4 }));
+        let limit = limit.expect("hash limit");
+        assert_eq!(limit.for_kind(Some("minor")), Some(2));
+        assert_eq!(limit.for_kind(None), Some(4));
+        assert_eq!(limit.for_kind(Some("national")), None);
+        assert_eq!(TrainLimit::from_value(&Value::from("4")), None);
+    }
+
+    #[test]
+    fn phases_and_revenue_tables_stay_in_step() {
+        let mut game = Game::new(GameSession::fixture(), 2);
+        assert_eq!(game.current_phase_rounds().len(), 1);
+        game.set_revenue(1, 2, 70);
+        assert_eq!(game.current_phase_rounds().len(), 3);
+        assert_eq!(game.revenue(1, 2), Some(70));
+
+        let index = game.add_custom_phase("5".to_string(), 2);
+        assert!(game.set_phase_index(index));
+        assert_eq!(game.current_phase_rounds().len(), 2);
+        assert_eq!(game.add_operating_round(), Some(2));
+        game.set_revenue(0, 1, 40);
+        game.set_revenue(1, 1, 90);
+        assert_eq!(game.add_operating_round_copying(1), Some(3));
+        assert_eq!(
+            (game.revenue(0, 3), game.revenue(1, 3)),
+            (Some(40), Some(90))
+        );
+        assert_eq!(game.add_operating_round_copying(9), None);
+
+        let removed = game.remove_last_custom_phase().expect("custom phase");
+        assert_eq!(removed.name, "5");
+        assert_eq!(game.current_phase_index(), 0);
+        assert_eq!(game.revenue(1, 2), Some(70));
+    }
+
+    #[test]
+    fn serializes_with_flat_save_keys() {
+        let game = Game::new(GameSession::fixture(), 2);
+        let value = serde_json::to_value(&game).unwrap();
+        let object = value.as_object().unwrap();
+        for key in ["session", "phases", "phase_index", "phase_rounds"] {
+            assert!(object.contains_key(key), "missing {key}");
+        }
+        let legacy = serde_json::json!({ "session": value["session"].clone() });
+        let restored: Game = serde_json::from_value(legacy).unwrap();
+        assert!(restored.phases.is_empty());
+        assert_eq!(restored.phase_index, 0);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
+    collections::HashMap,
     fs,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::Arc,
 };
 
@@ -21,6 +22,16 @@ struct Inner {
     root_path: PathBuf,
     metadata: ResourceMetadata,
     cache: Vec<GameInfo>,
+    skipped: Vec<SkippedGame>,
+}
+
+/// A game folder that discovery ignored, with the reason shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedGame {
+    /// Folder name under `lib/engine/game` (e.g. `g_1889`).
+    pub folder: String,
+    /// Why the game cannot be played (e.g. `MARKET data missing`).
+    pub reason: String,
 }
 
 impl ResourceLoader {
@@ -31,6 +42,7 @@ impl ResourceLoader {
                 root_path: root_path.into(),
                 metadata,
                 cache: Vec::new(),
+                skipped: Vec::new(),
             })),
         }
     }
@@ -51,17 +63,32 @@ impl ResourceLoader {
         inner.root_path = root_path.into();
         inner.metadata = metadata;
         inner.cache.clear();
+        inner.skipped.clear();
+    }
+
+    /// Drops the discovered games so the next `games` call rescans the engine directory.
+    pub fn invalidate(&self) {
+        let mut inner = self.inner.write();
+        inner.cache.clear();
+        inner.skipped.clear();
     }
 
     /// Return all known games, populating the cache on first use.
     pub fn games(&self) -> Result<Vec<GameInfo>> {
         let mut inner = self.inner.write();
         if inner.cache.is_empty() {
-            inner.cache = discover_games(&inner.root_path, &inner.metadata)?;
+            let (games, skipped) = discover_games_with_report(&inner.root_path, &inner.metadata)?;
+            inner.cache = games;
+            inner.skipped = skipped;
         }
         Ok(inner.cache.clone())
     }
 
+    /// Game folders rejected by the last discovery pass, in folder order.
+    pub fn skipped_games(&self) -> Vec<SkippedGame> {
+        self.inner.read().skipped.clone()
+    }
+
     /// Filter games using a case-insensitive substring search.
     pub fn games_matching(&self, query: &str) -> Result<Vec<GameInfo>> {
         let needle = query.trim().to_lowercase();
@@ -105,17 +132,31 @@ impl GameDiscovery {
         root_path: impl Into<PathBuf>,
         metadata: &ResourceMetadata,
     ) -> Result<Vec<GameInfo>> {
-        discover_games(&root_path.into(), metadata)
+        discover_games_with_report(&root_path.into(), metadata).map(|(games, _)| games)
+    }
+
+    /// Like [`GameDiscovery::discover`], also returning the folders that were skipped.
+    pub fn discover_with_report(
+        root_path: impl Into<PathBuf>,
+        metadata: &ResourceMetadata,
+    ) -> Result<(Vec<GameInfo>, Vec<SkippedGame>)> {
+        discover_games_with_report(&root_path.into(), metadata)
     }
 }
 
-fn discover_games(root: &PathBuf, metadata: &ResourceMetadata) -> Result<Vec<GameInfo>> {
+fn discover_games_with_report(
+    root: &Path,
+    metadata: &ResourceMetadata,
+) -> Result<(Vec<GameInfo>, Vec<SkippedGame>)> {
     let game_root = root.join("lib").join("engine").join("game");
     if !game_root.is_dir() {
-        return Ok(Vec::new());
+        return Ok((Vec::new(), Vec::new()));
     }
 
     let mut games = Vec::new();
+    let mut skipped = Vec::new();
+    // Game id -> folder that claimed it first, so ids stay unique.
+    let mut claimed: HashMap<String, String> = HashMap::new();
     let mut folders: Vec<_> = fs::read_dir(&game_root)?
         .filter_map(|entry| entry.ok())
         .filter(|entry| entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false))
@@ -130,22 +171,40 @@ fn discover_games(root: &PathBuf, metadata: &ResourceMetadata) -> Result<Vec<Gam
         }
 
         let meta_path = entry.path().join("meta.rb");
-        if !meta_path.is_file() {
-            warn!("Skipping {} – missing meta.rb", folder_name);
-            continue;
-        }
-
-        match build_game(&meta_path, metadata) {
-            Ok(Some(game)) => games.push(game),
-            Ok(None) => continue,
-            Err(err) => warn!("Skipping {}: {}", folder_name, err),
+        let outcome = if meta_path.is_file() {
+            build_game(&meta_path, metadata).unwrap_or_else(|err| Err(err.to_string()))
+        } else {
+            Err("missing meta.rb".to_string())
+        };
+        let outcome = outcome.and_then(|game| match claimed.get(&game.id) {
+            Some(first) => Err(format!(
+                "duplicate game id '{}' (already used by {first})",
+                game.id
+            )),
+            None => Ok(game),
+        });
+        match outcome {
+            Ok(game) => {
+                claimed.insert(game.id.clone(), game.folder.clone());
+                games.push(game);
+            }
+            Err(reason) => {
+                warn!("Skipping {}: {}", folder_name, reason);
+                skipped.push(SkippedGame {
+                    folder: folder_name,
+                    reason,
+                });
+            }
         }
     }
 
-    Ok(games)
+    Ok((games, skipped))
 }
 
-fn build_game(meta_path: &Path, metadata: &ResourceMetadata) -> Result<Option<GameInfo>> {
+fn build_game(
+    meta_path: &Path,
+    metadata: &ResourceMetadata,
+) -> Result<std::result::Result<GameInfo, String>> {
     let folder = meta_path
         .parent()
         .and_then(|path| path.file_name())
@@ -153,23 +212,23 @@ fn build_game(meta_path: &Path, metadata: &ResourceMetadata) -> Result<Option<Ga
         .ok_or_else(|| anyhow::anyhow!("failed to determine folder for {:?}", meta_path))?
         .to_string();
 
-    let compatibility = ensure_game_compatible(meta_path)?;
-    if let Err(reason) = compatibility {
-        warn!("Skipping {}: {}", folder, reason);
-        return Ok(None);
+    if let Err(reason) = ensure_game_compatible(meta_path)? {
+        return Ok(Err(reason));
     }
 
-    let content = fs::read_to_string(meta_path)
-        .with_context(|| format!("failed to read {}", meta_path.display()))?;
+    let content = read_source(meta_path)?;
     let id = folder.trim_start_matches("g_").to_string();
 
-    let title = extract_constant(&content, "GAME_TITLE").unwrap_or_else(|| id.to_uppercase());
+    let title = match extract_constant(&content, "GAME_TITLE") {
+        Some(title) => title,
+        None => fallback_title(meta_path)?.unwrap_or_else(|| id.to_uppercase()),
+    };
     let subtitle = extract_constant(&content, "GAME_SUBTITLE");
     let designer = extract_constant(&content, "GAME_DESIGNER");
     let location = extract_constant(&content, "GAME_LOCATION");
     let rules_url = extract_constant(&content, "GAME_RULES_URL");
 
-    Ok(Some(GameInfo {
+    Ok(Ok(GameInfo {
         id,
         title: title.trim().to_string(),
         subtitle: subtitle
@@ -186,10 +245,86 @@ fn build_game(meta_path: &Path, metadata: &ResourceMetadata) -> Result<Option<Ga
             .map(|value| value.trim().to_string())
             .filter(|s| !s.is_empty()),
         commit: metadata.commit.clone(),
-        updated_at: metadata.updated_at.clone(),
+        updated_at: metadata.updated_at,
     }))
 }
 
+/// Constants a game's `config.rb` may carry its title in when `meta.rb` has none.
+const CONFIG_TITLE_CONSTANTS: [&str; 2] = ["GAME_TITLE", "TITLE"];
+
+/// Title from the `config.rb` beside `meta_path`, for games that keep it out of the
+/// meta constants.
+fn fallback_title(meta_path: &Path) -> Result<Option<String>> {
+    let config_path = meta_path.with_file_name("config.rb");
+    if !config_path.is_file() {
+        return Ok(None);
+    }
+    let content = read_source(&config_path)?;
+    Ok(CONFIG_TITLE_CONSTANTS
+        .iter()
+        .find_map(|name| extract_constant(&content, name))
+        .filter(|title| !title.trim().is_empty()))
+}
+
+/// Reads a Ruby source file, replacing invalid UTF-8 (e.g. a stray Latin-1 byte in a
+/// designer's name) with U+FFFD instead of rejecting the whole game.
+pub(crate) fn read_source(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    match String::from_utf8(bytes) {
+        Ok(content) => Ok(content),
+        Err(err) => {
+            warn!(path = %path.display(), "Source is not valid UTF-8; decoding lossily");
+            Ok(String::from_utf8_lossy(err.as_bytes()).into_owned())
+        }
+    }
+}
+
+/// `entities.rb` in the game `folder`, followed by the files it pulls in (directly or
+/// through another split file) with `require_relative`. Only files inside the folder are
+/// followed; entities shared from another game's folder belong to that game's module.
+pub(crate) fn entity_source_files(folder: &Path) -> Vec<PathBuf> {
+    static REQUIRE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"(?m)^\s*require_relative\s*\(?\s*['"]([^'"]+)['"]"#)
+            .expect("invalid require regex")
+    });
+
+    let mut files = vec![folder.join("entities.rb")];
+    let mut next = 0;
+    while let Some(file) = files.get(next).cloned() {
+        next += 1;
+        let Ok(content) = read_source(&file) else {
+            continue;
+        };
+        let dir = file.parent().unwrap_or(folder);
+        for caps in REQUIRE_RE.captures_iter(&content) {
+            let relative = Path::new(&caps[1]);
+            if !relative
+                .components()
+                .all(|part| matches!(part, Component::Normal(_) | Component::CurDir))
+            {
+                continue;
+            }
+            let mut required: PathBuf = dir.join(relative).components().collect();
+            if required.extension().is_none() {
+                required.set_extension("rb");
+            }
+            if required.is_file() && !files.contains(&required) {
+                files.push(required);
+            }
+        }
+    }
+    files
+}
+
+/// Contents of `files` (see [`entity_source_files`]) joined into one source.
+pub(crate) fn read_entity_sources(files: &[PathBuf]) -> Result<String> {
+    let sources = files
+        .iter()
+        .map(|file| read_source(file))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(sources.join("\n"))
+}
+
 fn extract_constant(content: &str, name: &str) -> Option<String> {
     let quoted_pattern = Regex::new(&format!(
         r#"(?ms)^\s*{}\s*=\s*(?:"([^"]+)"|'([^']+)')"#,
@@ -244,12 +379,10 @@ pub(crate) fn ensure_game_compatible(meta_path: &Path) -> Result<Result<(), Stri
         return Ok(Err(format!("missing game.rb at {}", game_file.display())));
     }
 
-    let entities_content = fs::read_to_string(&entities)
-        .with_context(|| format!("failed to read {}", entities.display()))?;
-    let game_content = fs::read_to_string(&game_file)
-        .with_context(|| format!("failed to read {}", game_file.display()))?;
+    let entities_content = read_entity_sources(&entity_source_files(&folder))?;
+    let game_content = read_source(&game_file)?;
 
-    let module_name = extract_module_name(&entities_content).ok_or_else(|| {
+    let module_name = extract_module_name(&entities_content, &game_content).ok_or_else(|| {
         anyhow::anyhow!(
             "unable to determine module name from {}",
             entities.display()
@@ -283,14 +416,42 @@ pub(crate) fn ensure_game_compatible(meta_path: &Path) -> Result<Result<(), Stri
     Ok(Ok(()))
 }
 
-pub(crate) fn extract_module_name(content: &str) -> Option<String> {
-    static MODULE_RE: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"module\s+(G\d[0-9A-Za-z_]*)").expect("invalid module regex"));
-
-    MODULE_RE
-        .captures_iter(content)
-        .last()
-        .and_then(|caps| caps.get(1).map(|m| m.as_str().to_string()))
+/// Picks the game module declared in the entities source, or in `game.rb` when the
+/// entities files declare none (a split file may only open `module Entities`).
+///
+/// Most games use `G<digit>...` (`G1889`), but the engine also has modules named
+/// `G<Uppercase>...` (`GSystem18`, `GRollingStock`). When several are declared, the one
+/// `game.rb` references wins, then the `G<digit>` form, then the last declaration.
+pub(crate) fn extract_module_name(entities: &str, game: &str) -> Option<String> {
+    static MODULE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"module\s+(G[0-9A-Z][0-9A-Za-z_]*)").expect("invalid module regex")
+    });
+
+    let declared = |source| -> Vec<&str> {
+        MODULE_RE
+            .captures_iter(source)
+            .filter_map(|caps| caps.get(1).map(|m| m.as_str()))
+            .collect()
+    };
+    let mut candidates = declared(entities);
+    if candidates.is_empty() {
+        candidates = declared(game);
+    }
+    let numeric = |name: &&str| name.as_bytes().get(1).is_some_and(u8::is_ascii_digit);
+    let referenced = |name: &&str| {
+        Regex::new(&format!(r"\b{}\b", regex::escape(name)))
+            .map(|re| re.is_match(game))
+            .unwrap_or(false)
+    };
+
+    let mut in_order = candidates.iter().rev();
+    in_order
+        .clone()
+        .find(|name| referenced(name) && numeric(name))
+        .or_else(|| in_order.clone().find(|name| referenced(name)))
+        .or_else(|| in_order.clone().find(|name| numeric(name)))
+        .or_else(|| in_order.next())
+        .map(|name| name.to_string())
 }
 
 #[cfg(test)]
@@ -299,6 +460,31 @@ mod tests {
     use chrono::Utc;
     use tempfile::tempdir;
 
+    #[test]
+    fn meta_with_invalid_utf8_still_parses() -> Result<()> {
+        let temp = tempdir()?;
+        let game_dir = temp.path().join("lib/engine/game/g_latin");
+        fs::create_dir_all(&game_dir)?;
+        let mut meta = b"GAME_TITLE = \"Latin\"\nGAME_DESIGNER = \"Jos".to_vec();
+        meta.extend_from_slice(&[0xE9]);
+        meta.extend_from_slice(b"\"\n");
+        fs::write(game_dir.join("meta.rb"), meta)?;
+        fs::write(
+            game_dir.join("entities.rb"),
+            b"module GLatin\n  module Entities\n    # caf\xE9\n    CORPORATIONS = []\n  end\nend\n",
+        )?;
+        fs::write(
+            game_dir.join("game.rb"),
+            "module GLatin\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n",
+        )?;
+
+        let games = GameDiscovery::discover(temp.path(), &ResourceMetadata::default())?;
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].title, "Latin");
+        assert_eq!(games[0].designer.as_deref(), Some("Jos\u{FFFD}"));
+        Ok(())
+    }
+
     #[test]
     fn discovers_valid_games() -> Result<()> {
         let temp = tempdir()?;
@@ -361,6 +547,7 @@ end
         let metadata = ResourceMetadata {
             commit: Some("abc1234".to_string()),
             updated_at: Some(Utc::now()),
+            ..ResourceMetadata::default()
         };
 
         let games = GameDiscovery::discover(root, &metadata)?;
@@ -376,4 +563,194 @@ end
 
         Ok(())
     }
+
+    #[test]
+    fn title_falls_back_to_config_rb() -> Result<()> {
+        let temp = tempdir()?;
+        let entities = "module G18T\n  module Entities\n    CORPORATIONS = []\n  end\nend\n";
+        let game = "module G18T\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n";
+        for folder in ["g_config", "g_bare", "g_meta"] {
+            let meta = write_game(temp.path(), folder, entities, game)?;
+            fs::write(&meta, "GAME_DESIGNER = \"Someone\"\n")?;
+        }
+        let game_root = temp.path().join("lib/engine/game");
+        fs::write(
+            game_root.join("g_config/config.rb"),
+            "module G18T\n  module Config\n    TITLE = 'Config Title'\n  end\nend\n",
+        )?;
+        // meta.rb stays the primary source.
+        fs::write(
+            game_root.join("g_meta/meta.rb"),
+            "GAME_TITLE = \"Meta Title\"\n",
+        )?;
+        fs::write(
+            game_root.join("g_meta/config.rb"),
+            "GAME_TITLE = \"Config Title\"\n",
+        )?;
+
+        let games = GameDiscovery::discover(temp.path(), &ResourceMetadata::default())?;
+        let titles: Vec<_> = games.iter().map(|game| game.title.as_str()).collect();
+        assert_eq!(titles, ["BARE", "Config Title", "Meta Title"]);
+        Ok(())
+    }
+
+    fn write_game(root: &Path, folder: &str, entities: &str, game: &str) -> Result<PathBuf> {
+        let dir = root.join("lib/engine/game").join(folder);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("meta.rb"), "GAME_TITLE = \"Alt\"\n")?;
+        fs::write(dir.join("entities.rb"), entities)?;
+        fs::write(dir.join("game.rb"), game)?;
+        Ok(dir.join("meta.rb"))
+    }
+
+    #[test]
+    fn reports_skipped_games() -> Result<()> {
+        let temp = tempdir()?;
+        write_game(
+            temp.path(),
+            "g_good",
+            "module G18Good\n  module Entities\n    CORPORATIONS = []\n  end\nend\n",
+            "module G18Good\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n",
+        )?;
+        write_game(
+            temp.path(),
+            "g_no_market",
+            "module G18NoMarket\n  module Entities\n    CORPORATIONS = []\n  end\nend\n",
+            "module G18NoMarket\n  module Game\n    TRAINS = []\n  end\nend\n",
+        )?;
+        fs::create_dir_all(temp.path().join("lib/engine/game/g_no_meta"))?;
+
+        let (games, skipped) =
+            GameDiscovery::discover_with_report(temp.path(), &ResourceMetadata::default())?;
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].folder, "g_good");
+        assert_eq!(
+            skipped,
+            [
+                SkippedGame {
+                    folder: "g_no_market".to_string(),
+                    reason: "MARKET data missing".to_string(),
+                },
+                SkippedGame {
+                    folder: "g_no_meta".to_string(),
+                    reason: "missing meta.rb".to_string(),
+                },
+            ]
+        );
+
+        let loader = ResourceLoader::new(temp.path(), ResourceMetadata::default());
+        assert_eq!(loader.games()?.len(), 1);
+        assert_eq!(loader.skipped_games(), skipped);
+        Ok(())
+    }
+
+    #[test]
+    fn skips_folders_with_duplicate_ids() -> Result<()> {
+        let temp = tempdir()?;
+        let entities = "module G1830\n  module Entities\n    CORPORATIONS = []\n  end\nend\n";
+        let game = "module G1830\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n";
+        write_game(temp.path(), "g_1830", entities, game)?;
+        write_game(temp.path(), "g_g_1830", entities, game)?;
+
+        let (games, skipped) =
+            GameDiscovery::discover_with_report(temp.path(), &ResourceMetadata::default())?;
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].id, "1830");
+        assert_eq!(games[0].folder, "g_1830");
+        assert_eq!(
+            skipped,
+            [SkippedGame {
+                folder: "g_g_1830".to_string(),
+                reason: "duplicate game id '1830' (already used by g_1830)".to_string(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_alternate_module_names() -> Result<()> {
+        let temp = tempdir()?;
+        let game_body = |module: &str| {
+            format!(
+                "module {module}\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n"
+            )
+        };
+
+        let entities = "module GSystem18\n  module Entities\n    CORPORATIONS = []\n  end\nend\n";
+        let meta = write_game(temp.path(), "g_system18", entities, &game_body("GSystem18"))?;
+        assert!(ensure_game_compatible(&meta)?.is_ok());
+        assert_eq!(
+            extract_module_name(entities, &game_body("GSystem18")).as_deref(),
+            Some("GSystem18")
+        );
+
+        // A shared `G<digit>` helper is declared too, but game.rb only uses the alternate name.
+        let entities = "module G18Shared\nend\nmodule GRollingStock\n  module Entities\n    \
+                        CORPORATIONS = []\n  end\nend\n";
+        let game = game_body("GRollingStock");
+        let meta = write_game(temp.path(), "g_rolling_stock", entities, &game)?;
+        assert!(ensure_game_compatible(&meta)?.is_ok());
+        assert_eq!(
+            extract_module_name(entities, &game).as_deref(),
+            Some("GRollingStock")
+        );
+
+        // When both are referenced the `G<digit>` form still wins.
+        let game = "module G1822\n  include GShared\nend\n";
+        let entities = "module G1822\nend\nmodule GShared\nend\n";
+        assert_eq!(
+            extract_module_name(entities, game).as_deref(),
+            Some("G1822")
+        );
+        // Without game.rb hints, prefer `G<digit>`; `Game` itself is never a candidate.
+        let entities = "module GShared\nend\nmodule G1889\n  module Game\n  end\nend\n";
+        assert_eq!(extract_module_name(entities, "").as_deref(), Some("G1889"));
+        assert_eq!(extract_module_name("module Game\nend\n", ""), None);
+
+        let games = GameDiscovery::discover(temp.path(), &ResourceMetadata::default())?;
+        let folders: Vec<_> = games.iter().map(|game| game.folder.as_str()).collect();
+        assert_eq!(folders, ["g_rolling_stock", "g_system18"]);
+        Ok(())
+    }
+
+    #[test]
+    fn follows_entities_split_across_files() -> Result<()> {
+        let temp = tempdir()?;
+        let meta = write_game(
+            temp.path(),
+            "g_split",
+            "require_relative 'entities/corporations'\nrequire_relative '../g_other/entities'\n",
+            "module G18Split\n  module Game\n    MARKET = []\n    TRAINS = []\n  end\nend\n",
+        )?;
+        let dir = meta.parent().unwrap();
+        fs::create_dir_all(dir.join("entities"))?;
+        fs::write(
+            dir.join("entities/corporations.rb"),
+            "require_relative './companies'\nmodule Entities\n  CORPORATIONS = []\nend\n",
+        )?;
+        fs::write(dir.join("entities/companies.rb"), "COMPANIES = []\n")?;
+
+        let files = entity_source_files(dir);
+        let names: Vec<_> = files
+            .iter()
+            .map(|file| file.strip_prefix(dir).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                Path::new("entities.rb"),
+                Path::new("entities/corporations.rb"),
+                Path::new("entities/companies.rb"),
+            ]
+        );
+        assert!(ensure_game_compatible(&meta)?.is_ok());
+        // No entities file names the game module, so game.rb's declaration is used.
+        let entities = read_entity_sources(&files)?;
+        let game = read_source(&dir.join("game.rb"))?;
+        assert_eq!(
+            extract_module_name(&entities, &game).as_deref(),
+            Some("G18Split")
+        );
+        Ok(())
+    }
 }
@@ -4,6 +4,9 @@
 pub mod loader;
 /// Git-based resource synchronisation helpers.
 pub mod sync;
+/// Filesystem watch over an engine checkout for local development.
+pub mod watch;
 
-pub use loader::{GameDiscovery, ResourceLoader};
+pub use loader::{GameDiscovery, ResourceLoader, SkippedGame};
 pub use sync::{ResourceSync, SyncEvent};
+pub use watch::{EngineWatcher, WATCH_DEBOUNCE};
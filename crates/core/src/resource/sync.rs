@@ -1,9 +1,16 @@
-use std::{path::PathBuf, process::Stdio};
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use tokio::{process::Command, sync::mpsc};
-use tracing::info;
+use tokio::{
+    io::AsyncReadExt,
+    process::Command,
+    sync::{mpsc, OnceCell},
+};
+use tracing::{info, warn};
 
 use crate::{config::AppConfig, manifest, manifest::ResourceMetadata};
 
@@ -17,6 +24,18 @@ pub enum SyncEvent {
         /// Manifest metadata describing the new snapshot.
         metadata: ResourceMetadata,
     },
+    /// Transfer progress parsed from git's `--progress` output.
+    Progress {
+        /// Git's label for the current phase, e.g. `Receiving objects`.
+        stage: String,
+        /// Completion of that phase, 0-100.
+        percent: u8,
+    },
+    /// Files under `lib/engine/game` changed while watching the engine directory.
+    EngineChanged {
+        /// Game folders (e.g. `g_1889`) touched by the change, sorted.
+        folders: Vec<String>,
+    },
     /// Sync failed with an error.
     Error(anyhow::Error),
 }
@@ -24,29 +43,58 @@ pub enum SyncEvent {
 /// Coordinates fetching the 18xx engine repository.
 pub struct ResourceSync {
     config: AppConfig,
+    /// Set once `repo_branch` has been confirmed on the remote, so we only ask once.
+    branch_verified: OnceCell<()>,
 }
 
 impl ResourceSync {
     /// Create a new synchroniser from configuration.
     pub fn new(config: AppConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            branch_verified: OnceCell::new(),
+        }
     }
 
-    /// Path to the local engine repository.
+    /// Path to the local engine repository: `local_engine_path` when configured, otherwise
+    /// the managed clone under the cache directory (`cache_root` or `data_dir/cache`).
     pub fn repo_path(&self) -> PathBuf {
-        self.config.cache_root.join("engine")
+        match &self.config.local_engine_path {
+            Some(path) => path.clone(),
+            None => self.config.cache_dir().join("engine"),
+        }
+    }
+
+    /// Whether the engine is a developer's own directory that sync must not touch.
+    pub fn is_local(&self) -> bool {
+        self.config.local_engine_path.is_some()
     }
 
     fn manifest_path(&self) -> PathBuf {
         manifest::manifest_path(self.repo_path())
     }
 
-    /// Ensure a checkout exists locally, cloning when missing.
+    /// Ensure a checkout exists locally, cloning when missing or left half-done by an
+    /// interrupted clone.
+    ///
+    /// A `local_engine_path` is used as-is: no git commands that change it run and no
+    /// manifest is written into it.
     pub async fn prepare(&self) -> Result<ResourceMetadata> {
         let repo_path = self.repo_path();
-        if !repo_path.exists() {
+        if self.is_local() {
+            if !repo_path.is_dir() {
+                anyhow::bail!(
+                    "local_engine_path {} is not a directory",
+                    repo_path.display()
+                );
+            }
+            info!("using local engine at {}", repo_path.display());
+            return Ok(self.capture_local_metadata().await);
+        }
+        if !self.checkout_is_valid().await {
+            self.ensure_branch_exists().await?;
             info!("cloning engine repository into {}", repo_path.display());
-            self.clone_repo().await?;
+            self.clone_repo(None).await?;
         }
 
         let metadata = self.capture_metadata().await?;
@@ -56,7 +104,11 @@ impl ResourceSync {
 
     /// Spawn a background task that fetches updates, sending events to the provided channel.
     pub async fn run(self, sender: mpsc::Sender<SyncEvent>) -> Result<()> {
-        if let Err(err) = self.update_repo().await {
+        if self.is_local() {
+            // The developer manages their own checkout; there is nothing to fetch.
+            return Ok(());
+        }
+        if let Err(err) = self.update_repo(&sender).await {
             let _ = sender.send(SyncEvent::Error(err)).await;
             return Ok(());
         }
@@ -84,59 +136,114 @@ impl ResourceSync {
         Ok(())
     }
 
-    async fn clone_repo(&self) -> Result<()> {
+    /// Confirms `repo_branch` exists on `repo_url`, listing the available branches
+    /// when it does not. Successful checks are cached for the lifetime of `self`.
+    pub async fn ensure_branch_exists(&self) -> Result<()> {
+        self.branch_verified
+            .get_or_try_init(|| async {
+                let branch = &self.config.repo_branch;
+                let branches = remote_branches(self.git_command(), &self.config.repo_url).await?;
+                if branches.iter().any(|name| name == branch) {
+                    return Ok(());
+                }
+                let available = if branches.is_empty() {
+                    "none".to_string()
+                } else {
+                    branches.join(", ")
+                };
+                anyhow::bail!(
+                    "branch '{branch}' not found on {}; available branches: {available}",
+                    self.config.repo_url
+                )
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Whether the managed checkout is a usable git working copy. A directory without
+    /// `.git`, or where `git rev-parse` fails, is what an interrupted clone leaves behind.
+    async fn checkout_is_valid(&self) -> bool {
+        let repo_path = self.repo_path();
+        if !repo_path.exists() {
+            return false;
+        }
+        let valid = repo_path.join(".git").exists()
+            && self
+                .capture(&["rev-parse", "--verify", "HEAD"])
+                .await
+                .is_ok();
+        if !valid {
+            warn!("discarding partial checkout at {}", repo_path.display());
+        }
+        valid
+    }
+
+    /// `git` with the configured SSH command and environment. Terminal prompts are off, so
+    /// missing credentials fail fast instead of waiting on input the UI never forwards.
+    fn git_command(&self) -> Command {
+        let mut command = Command::new("git");
+        command.env("GIT_TERMINAL_PROMPT", "0");
+        match &self.config.git_ssh_command {
+            Some(ssh) => {
+                command.env("GIT_SSH_COMMAND", ssh);
+            }
+            None if std::env::var_os("GIT_SSH_COMMAND").is_none() => {
+                command.env("GIT_SSH_COMMAND", "ssh -o BatchMode=yes");
+            }
+            None => {}
+        }
+        command.envs(&self.config.git_env);
+        command
+    }
+
+    /// Clones into a sibling `<repo>.partial` directory and only swaps it in once the
+    /// clone succeeded, so an interrupted clone never leaves a half-written checkout at
+    /// the repo path.
+    async fn clone_repo(&self, progress: Option<&mpsc::Sender<SyncEvent>>) -> Result<()> {
         let repo_path = self.repo_path();
         if let Some(parent) = repo_path.parent() {
             tokio::fs::create_dir_all(parent)
                 .await
                 .context("failed to create cache directory")?;
         }
+        let staging = sibling_path(&repo_path, "partial");
+        remove_dir_if_present(&staging).await?;
 
-        let status = Command::new("git")
-            .arg("clone")
-            .arg("--depth")
-            .arg("1")
-            .arg("--branch")
-            .arg(&self.config.repo_branch)
-            .arg(&self.config.repo_url)
-            .arg(&repo_path)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
-            .await
-            .context("failed to execute git clone")?;
+        let mut command = self.git_command();
+        command
+            .args(self.clone_args(progress.is_some()))
+            .arg(&staging);
+        run_git(command, "git clone", progress).await?;
 
-        if !status.success() {
-            anyhow::bail!("git clone exited with {}", status);
+        let stale = sibling_path(&repo_path, "stale");
+        remove_dir_if_present(&stale).await?;
+        if repo_path.exists() {
+            tokio::fs::rename(&repo_path, &stale)
+                .await
+                .with_context(|| format!("failed to move aside {}", repo_path.display()))?;
         }
-
-        Ok(())
+        tokio::fs::rename(&staging, &repo_path)
+            .await
+            .with_context(|| format!("failed to move clone into {}", repo_path.display()))?;
+        remove_dir_if_present(&stale).await
     }
 
-    async fn update_repo(&self) -> Result<()> {
-        if !self.repo_path().exists() {
-            self.clone_repo().await?;
+    async fn update_repo(&self, progress: &mpsc::Sender<SyncEvent>) -> Result<()> {
+        if !self.checkout_is_valid().await {
+            self.ensure_branch_exists().await?;
+            self.clone_repo(Some(progress)).await?;
             return Ok(());
         }
 
-        let status = Command::new("git")
-            .arg("fetch")
-            .arg("--depth")
-            .arg("1")
-            .arg("origin")
-            .arg(&self.config.repo_branch)
-            .current_dir(self.repo_path())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
-            .await
-            .context("failed to execute git fetch")?;
-
-        if !status.success() {
-            anyhow::bail!("git fetch exited with {}", status);
-        }
+        let shallow = self.repo_path().join(".git").join("shallow").exists();
+        let mut command = self.git_command();
+        command
+            .args(self.fetch_args(shallow, true))
+            .current_dir(self.repo_path());
+        run_git(command, "git fetch", Some(progress)).await?;
 
-        let status = Command::new("git")
+        let status = self
+            .git_command()
             .arg("reset")
             .arg("--hard")
             .arg(format!("origin/{}", self.config.repo_branch))
@@ -154,19 +261,88 @@ impl ResourceSync {
         Ok(())
     }
 
+    /// `git clone` arguments up to (not including) the target directory.
+    fn clone_args(&self, progress: bool) -> Vec<String> {
+        let mut args = vec!["clone".to_string()];
+        if progress {
+            args.push("--progress".to_string());
+        }
+        args.extend(self.depth_args());
+        args.extend([
+            "--branch".to_string(),
+            self.config.repo_branch.clone(),
+            self.config.repo_url.clone(),
+        ]);
+        args
+    }
+
+    /// `git fetch` arguments; a full-history config unshallows an existing shallow checkout.
+    fn fetch_args(&self, shallow_checkout: bool, progress: bool) -> Vec<String> {
+        let mut args = vec!["fetch".to_string()];
+        if progress {
+            args.push("--progress".to_string());
+        }
+        if self.config.clone_depth.is_none() && shallow_checkout {
+            args.push("--unshallow".to_string());
+        }
+        args.extend(self.depth_args());
+        args.extend(["origin".to_string(), self.config.repo_branch.clone()]);
+        args
+    }
+
+    fn depth_args(&self) -> Vec<String> {
+        match self.config.clone_depth {
+            Some(depth) => vec!["--depth".to_string(), depth.to_string()],
+            None => Vec::new(),
+        }
+    }
+
     async fn capture_metadata(&self) -> Result<ResourceMetadata> {
         let commit = self.capture(&["rev-parse", "HEAD"]).await?;
         let updated_at = self.capture(&["log", "-1", "--format=%cI"]).await?;
         let commit = commit.trim().to_string();
         let updated_at = DateTime::parse_from_rfc3339(updated_at.trim())?.with_timezone(&Utc);
+        let branch = self.config.repo_branch.clone();
         Ok(ResourceMetadata {
             commit: Some(commit),
             updated_at: Some(updated_at),
+            ref_spec: Some(format!("refs/heads/{branch}")),
+            branch: Some(branch),
         })
     }
 
+    /// Metadata for a `local_engine_path`: the checked-out commit when it is a git
+    /// working copy, otherwise `local` with no timestamp or branch.
+    async fn capture_local_metadata(&self) -> ResourceMetadata {
+        let Ok(commit) = self.capture(&["rev-parse", "HEAD"]).await else {
+            return ResourceMetadata {
+                commit: Some("local".to_string()),
+                ..ResourceMetadata::default()
+            };
+        };
+        let updated_at = self
+            .capture(&["log", "-1", "--format=%cI"])
+            .await
+            .ok()
+            .and_then(|value| DateTime::parse_from_rfc3339(value.trim()).ok())
+            .map(|value| value.with_timezone(&Utc));
+        let branch = self
+            .capture(&["rev-parse", "--abbrev-ref", "HEAD"])
+            .await
+            .ok()
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| branch != "HEAD");
+        ResourceMetadata {
+            commit: Some(commit.trim().to_string()),
+            updated_at,
+            ref_spec: branch.as_ref().map(|branch| format!("refs/heads/{branch}")),
+            branch,
+        }
+    }
+
     async fn capture(&self, args: &[&str]) -> Result<String> {
-        let output = Command::new("git")
+        let output = self
+            .git_command()
             .args(args)
             .current_dir(self.repo_path())
             .output()
@@ -188,3 +364,424 @@ impl ResourceSync {
         metadata.persist(self.manifest_path())
     }
 }
+
+/// `<path>.<suffix>` next to `path`, e.g. `engine.partial`.
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{suffix}"));
+    path.with_file_name(name)
+}
+
+async fn remove_dir_if_present(path: &Path) -> Result<()> {
+    match tokio::fs::remove_dir_all(path).await {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            Err(err).with_context(|| format!("failed to remove {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Runs a git transfer command. With a `progress` sender, stderr is parsed into
+/// [`SyncEvent::Progress`] updates instead of being written over the UI; without one it
+/// goes straight to the terminal.
+async fn run_git(
+    mut command: Command,
+    label: &str,
+    progress: Option<&mpsc::Sender<SyncEvent>>,
+) -> Result<()> {
+    let Some(sender) = progress else {
+        let status = command
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .with_context(|| format!("failed to execute {label}"))?;
+        if !status.success() {
+            anyhow::bail!("{label} exited with {status}");
+        }
+        return Ok(());
+    };
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to execute {label}"))?;
+    let mut stderr = child.stderr.take().context("git stderr was not captured")?;
+    let mut pending = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let mut last_update = None;
+    let mut last_line = String::new();
+    loop {
+        let read = stderr
+            .read(&mut chunk)
+            .await
+            .with_context(|| format!("failed to read {label} output"))?;
+        if read == 0 {
+            break;
+        }
+        pending.extend_from_slice(&chunk[..read]);
+        // Git redraws progress in place with `\r`, so both bytes end a line.
+        while let Some(end) = pending
+            .iter()
+            .position(|byte| matches!(byte, b'\r' | b'\n'))
+        {
+            let raw: Vec<u8> = pending.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&raw).trim().to_string();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(update) = parse_progress(&line) {
+                if last_update.as_ref() != Some(&update) {
+                    let (stage, percent) = update.clone();
+                    let _ = sender.send(SyncEvent::Progress { stage, percent }).await;
+                    last_update = Some(update);
+                }
+            }
+            last_line = line;
+        }
+    }
+
+    let status = child
+        .wait()
+        .await
+        .with_context(|| format!("failed to wait for {label}"))?;
+    if !status.success() {
+        if is_auth_failure(&last_line) {
+            anyhow::bail!("{label} could not authenticate: {last_line}; {AUTH_HINT}");
+        }
+        anyhow::bail!("{label} exited with {status}: {last_line}");
+    }
+    Ok(())
+}
+
+/// Appended to authentication errors so they say what to change.
+const AUTH_HINT: &str =
+    "check access to repo_url, or set git_ssh_command / [git_env] in the config";
+
+/// Whether git's error output means credentials were missing or rejected.
+fn is_auth_failure(stderr: &str) -> bool {
+    const MARKERS: [&str; 6] = [
+        "Permission denied",
+        "Authentication failed",
+        "could not read Username",
+        "terminal prompts disabled",
+        "Host key verification failed",
+        "Could not read from remote repository",
+    ];
+    MARKERS.iter().any(|marker| stderr.contains(marker))
+}
+
+/// Parses one git progress line such as `Receiving objects:  45% (10/22)` into its stage
+/// label and percentage; `remote:` prefixes are dropped.
+fn parse_progress(line: &str) -> Option<(String, u8)> {
+    let line = line.trim();
+    let line = line.strip_prefix("remote:").unwrap_or(line).trim_start();
+    let (stage, rest) = line.split_once(':')?;
+    let (percent, _) = rest.split_once('%')?;
+    let percent = percent.trim().parse::<u8>().ok()?;
+    Some((stage.trim().to_string(), percent.min(100)))
+}
+
+/// Lists branch names advertised by `url` via `git ls-remote --heads`, run with `git`.
+async fn remote_branches(mut git: Command, url: &str) -> Result<Vec<String>> {
+    let output = git
+        .arg("ls-remote")
+        .arg("--heads")
+        .arg(url)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .context("failed to execute git ls-remote")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim();
+        if is_auth_failure(stderr) {
+            anyhow::bail!("git ls-remote {url} could not authenticate: {stderr}; {AUTH_HINT}");
+        }
+        anyhow::bail!("git ls-remote {url} failed: {stderr}");
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter_map(|line| line.split('\t').nth(1))
+        .filter_map(|reference| reference.strip_prefix("refs/heads/"))
+        .map(str::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .expect("run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn bare_repo_fixture(root: &Path) -> PathBuf {
+        let work = root.join("work");
+        std::fs::create_dir_all(&work).unwrap();
+        git(&work, &["init", "-q", "-b", "main"]);
+        git(&work, &["commit", "-q", "--allow-empty", "-m", "init"]);
+        git(&work, &["branch", "release"]);
+        let bare = root.join("engine.git");
+        git(root, &["clone", "-q", "--bare", "work", "engine.git"]);
+        bare
+    }
+
+    fn sync_for(url: &Path, branch: &str) -> ResourceSync {
+        ResourceSync::new(AppConfig {
+            repo_url: url.to_string_lossy().into_owned(),
+            repo_branch: branch.to_string(),
+            ..AppConfig::default()
+        })
+    }
+
+    #[test]
+    fn depth_args_follow_clone_depth() {
+        let sync = |clone_depth| {
+            ResourceSync::new(AppConfig {
+                repo_url: "https://example.com/18xx.git".to_string(),
+                repo_branch: "master".to_string(),
+                clone_depth,
+                ..AppConfig::default()
+            })
+        };
+
+        let shallow = sync(Some(50));
+        assert_eq!(
+            shallow.clone_args(false),
+            [
+                "clone",
+                "--depth",
+                "50",
+                "--branch",
+                "master",
+                "https://example.com/18xx.git"
+            ]
+        );
+        assert_eq!(
+            shallow.fetch_args(true, false),
+            ["fetch", "--depth", "50", "origin", "master"]
+        );
+
+        let full = sync(None);
+        assert_eq!(
+            full.clone_args(false),
+            [
+                "clone",
+                "--branch",
+                "master",
+                "https://example.com/18xx.git"
+            ]
+        );
+        assert_eq!(full.fetch_args(false, false), ["fetch", "origin", "master"]);
+        assert_eq!(
+            full.fetch_args(true, false),
+            ["fetch", "--unshallow", "origin", "master"]
+        );
+        assert_eq!(full.clone_args(true)[..2], ["clone", "--progress"]);
+    }
+
+    #[test]
+    fn git_commands_carry_ssh_and_credential_env() {
+        let envs = |sync: &ResourceSync| -> Vec<(String, String)> {
+            let command = sync.git_command();
+            let mut envs: Vec<_> = command
+                .as_std()
+                .get_envs()
+                .filter_map(|(key, value)| {
+                    Some((key.to_str()?.to_string(), value?.to_str()?.to_string()))
+                })
+                .collect();
+            envs.sort();
+            envs
+        };
+        let sync = ResourceSync::new(AppConfig {
+            git_ssh_command: Some("ssh -i /keys/deploy".to_string()),
+            git_env: HashMap::from([("GIT_ASKPASS".to_string(), "/bin/askpass".to_string())]),
+            ..AppConfig::default()
+        });
+        assert_eq!(
+            envs(&sync),
+            [
+                ("GIT_ASKPASS".to_string(), "/bin/askpass".to_string()),
+                (
+                    "GIT_SSH_COMMAND".to_string(),
+                    "ssh -i /keys/deploy".to_string()
+                ),
+                ("GIT_TERMINAL_PROMPT".to_string(), "0".to_string()),
+            ]
+        );
+
+        let default = envs(&ResourceSync::new(AppConfig::default()));
+        assert!(default.contains(&("GIT_TERMINAL_PROMPT".to_string(), "0".to_string())));
+    }
+
+    #[test]
+    fn recognises_authentication_failures() {
+        assert!(is_auth_failure(
+            "git@github.com: Permission denied (publickey)."
+        ));
+        assert!(is_auth_failure(
+            "fatal: could not read Username for 'https://github.com': terminal prompts disabled"
+        ));
+        assert!(!is_auth_failure(
+            "fatal: repository 'https://example.com/x.git' not found"
+        ));
+    }
+
+    #[test]
+    fn parses_git_progress_lines() {
+        assert_eq!(
+            parse_progress("Receiving objects:  45% (10/22), 1.20 MiB | 2.00 MiB/s"),
+            Some(("Receiving objects".to_string(), 45))
+        );
+        assert_eq!(
+            parse_progress("remote: Compressing objects: 100% (5/5), done."),
+            Some(("Compressing objects".to_string(), 100))
+        );
+        assert_eq!(parse_progress("Cloning into '/tmp/engine'..."), None);
+        assert_eq!(
+            parse_progress("remote: Enumerating objects: 5, done."),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn run_reports_clone_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let bare = bare_repo_fixture(dir.path());
+        let sync = ResourceSync::new(AppConfig {
+            cache_root: dir.path().join("cache"),
+            ..sync_for(&bare, "main").config
+        });
+        let (sender, mut receiver) = mpsc::channel(64);
+        sync.run(sender).await.unwrap();
+
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+        assert!(
+            matches!(events.last(), Some(SyncEvent::Success { .. })),
+            "{events:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn full_clone_captures_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let bare = bare_repo_fixture(dir.path());
+        let sync = ResourceSync::new(AppConfig {
+            cache_root: dir.path().join("cache"),
+            clone_depth: None,
+            ..sync_for(&bare, "main").config
+        });
+
+        let metadata = sync.prepare().await.unwrap();
+        assert!(!sync.repo_path().join(".git").join("shallow").exists());
+        assert_eq!(metadata.branch.as_deref(), Some("main"));
+        assert_eq!(metadata.commit.map(|commit| commit.len()), Some(40));
+    }
+
+    #[tokio::test]
+    async fn partial_checkout_without_git_dir_is_recloned() {
+        let dir = tempfile::tempdir().unwrap();
+        let bare = bare_repo_fixture(dir.path());
+        let sync = ResourceSync::new(AppConfig {
+            cache_root: dir.path().join("cache"),
+            ..sync_for(&bare, "main").config
+        });
+        // What an interrupted clone leaves: the directory, some files, no `.git`.
+        let repo_path = sync.repo_path();
+        std::fs::create_dir_all(&repo_path).unwrap();
+        std::fs::write(repo_path.join("README.md"), "half").unwrap();
+
+        let metadata = sync.prepare().await.unwrap();
+        assert!(repo_path.join(".git").is_dir());
+        assert!(!repo_path.join("README.md").exists());
+        assert!(!sibling_path(&repo_path, "partial").exists());
+        assert!(!sibling_path(&repo_path, "stale").exists());
+        assert_eq!(metadata.commit.map(|commit| commit.len()), Some(40));
+    }
+
+    #[tokio::test]
+    async fn local_engine_path_is_used_without_git_sync() {
+        let dir = tempfile::tempdir().unwrap();
+        let local = dir.path().join("engine");
+        std::fs::create_dir_all(&local).unwrap();
+        let sync = ResourceSync::new(AppConfig {
+            cache_root: dir.path().join("cache"),
+            local_engine_path: Some(local.clone()),
+            repo_url: dir
+                .path()
+                .join("missing.git")
+                .to_string_lossy()
+                .into_owned(),
+            ..AppConfig::default()
+        });
+        assert_eq!(sync.repo_path(), local);
+
+        let metadata = sync.prepare().await.unwrap();
+        assert_eq!(metadata.commit.as_deref(), Some("local"));
+        assert!(metadata.branch.is_none());
+        assert!(!manifest::manifest_path(&local).exists());
+
+        git(&local, &["init", "-q", "-b", "dev"]);
+        git(&local, &["commit", "-q", "--allow-empty", "-m", "wip"]);
+        let metadata = sync.prepare().await.unwrap();
+        assert_eq!(metadata.commit.map(|commit| commit.len()), Some(40));
+        assert_eq!(metadata.branch.as_deref(), Some("dev"));
+        assert!(metadata.updated_at.is_some());
+
+        let (sender, mut receiver) = mpsc::channel(8);
+        sync.run(sender).await.unwrap();
+        assert!(receiver.recv().await.is_none());
+        assert!(!dir.path().join("cache").exists());
+    }
+
+    #[tokio::test]
+    async fn missing_local_engine_path_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let sync = ResourceSync::new(AppConfig {
+            local_engine_path: Some(dir.path().join("nowhere")),
+            ..AppConfig::default()
+        });
+        let err = sync.prepare().await.unwrap_err().to_string();
+        assert!(err.contains("is not a directory"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn validates_branch_against_remote() {
+        let dir = tempfile::tempdir().unwrap();
+        let bare = bare_repo_fixture(dir.path());
+
+        let git = Command::new("git");
+        let mut branches = remote_branches(git, &bare.to_string_lossy()).await.unwrap();
+        branches.sort();
+        assert_eq!(branches, ["main", "release"]);
+
+        let sync = sync_for(&bare, "release");
+        sync.ensure_branch_exists().await.unwrap();
+        assert!(sync.branch_verified.initialized());
+
+        let err = sync_for(&bare, "master")
+            .ensure_branch_exists()
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("branch 'master' not found"), "{err}");
+        assert!(err.contains("main, release"), "{err}");
+    }
+}
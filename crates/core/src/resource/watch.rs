@@ -0,0 +1,134 @@
+//! Filesystem watcher that reports edits to an engine checkout's game definitions.
+//!
+//! Editors and `git checkout` touch many files at once, so raw notifications are
+//! coalesced until the directory has been quiet for the debounce window and then
+//! reported as a single [`SyncEvent::EngineChanged`].
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, warn};
+
+use crate::resource::SyncEvent;
+
+/// Quiet period after the last change before a reload is requested.
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `lib/engine/game` under an engine root until dropped.
+pub struct EngineWatcher {
+    // Dropping the watcher stops the OS notifications and closes the raw channel.
+    _watcher: RecommendedWatcher,
+    task: JoinHandle<()>,
+}
+
+impl EngineWatcher {
+    /// Starts watching `engine_root`'s game directory, sending debounced
+    /// [`SyncEvent::EngineChanged`] events to `sender`. Must be called inside a Tokio
+    /// runtime.
+    pub fn spawn(
+        engine_root: &Path,
+        debounce: Duration,
+        sender: mpsc::Sender<SyncEvent>,
+    ) -> Result<Self> {
+        let game_root = engine_root.join("lib").join("engine").join("game");
+        let (raw_tx, raw_rx) = mpsc::unbounded_channel();
+        let root = game_root.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) if matches!(event.kind, EventKind::Access(_)) => {}
+                Ok(event) => {
+                    let _ = raw_tx.send(changed_folders(&root, &event.paths));
+                }
+                Err(err) => warn!(?err, "engine watch error"),
+            })
+            .context("failed to create engine watcher")?;
+        watcher
+            .watch(&game_root, RecursiveMode::Recursive)
+            .with_context(|| format!("failed to watch {}", game_root.display()))?;
+        debug!(path = %game_root.display(), "watching engine games");
+
+        let task = tokio::spawn(debounce_changes(raw_rx, debounce, sender));
+        Ok(Self {
+            _watcher: watcher,
+            task,
+        })
+    }
+}
+
+impl Drop for EngineWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Game folders (`g_1889`, ...) that contain any of `paths`.
+fn changed_folders(game_root: &Path, paths: &[PathBuf]) -> BTreeSet<String> {
+    paths
+        .iter()
+        .filter_map(|path| path.strip_prefix(game_root).ok()?.components().next())
+        .map(|folder| folder.as_os_str().to_string_lossy().into_owned())
+        .collect()
+}
+
+async fn debounce_changes(
+    mut raw_rx: mpsc::UnboundedReceiver<BTreeSet<String>>,
+    debounce: Duration,
+    sender: mpsc::Sender<SyncEvent>,
+) {
+    while let Some(mut folders) = raw_rx.recv().await {
+        loop {
+            match tokio::time::timeout(debounce, raw_rx.recv()).await {
+                Ok(Some(more)) => folders.extend(more),
+                Ok(None) => return,
+                Err(_) => break,
+            }
+        }
+        let event = SyncEvent::EngineChanged {
+            folders: folders.into_iter().collect(),
+        };
+        if sender.send(event).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn file_changes_are_reported_once_per_burst() {
+        let dir = tempfile::tempdir().unwrap();
+        let game_dir = dir.path().join("lib/engine/game/g_sample");
+        std::fs::create_dir_all(&game_dir).unwrap();
+        let (sender, mut receiver) = mpsc::channel(8);
+        let watcher = EngineWatcher::spawn(dir.path(), Duration::from_millis(100), sender).unwrap();
+
+        for round in 0..3 {
+            std::fs::write(game_dir.join("meta.rb"), format!("# edit {round}\n")).unwrap();
+        }
+        let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .expect("change reported")
+            .expect("channel open");
+        match event {
+            SyncEvent::EngineChanged { folders } => assert_eq!(folders, ["g_sample"]),
+            other => panic!("unexpected event {other:?}"),
+        }
+        let extra = tokio::time::timeout(Duration::from_millis(300), receiver.recv()).await;
+        assert!(extra.is_err(), "burst should be debounced: {extra:?}");
+
+        drop(watcher);
+        let closed = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await;
+        assert!(
+            matches!(closed, Ok(None)),
+            "watcher should release the sender"
+        );
+    }
+}
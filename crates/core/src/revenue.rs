@@ -0,0 +1,144 @@
+//! Payout arithmetic shared by frontends.
+//!
+//! Amounts are whole currency units. Per-share payouts round down, matching how most
+//! 18xx titles pay shareholders, so the UI presets and the payout summary always agree.
+
+use std::collections::BTreeMap;
+
+/// Share percentages offered as quick presets (10% through 60%).
+pub const PRESET_PERCENTAGES: [i32; 6] = [10, 20, 30, 40, 50, 60];
+
+/// Amount a holding of `pct` percent receives when `total` is paid out, rounded down.
+/// Non-positive totals pay nothing.
+pub fn dividend_per_share(total: i32, pct: i32) -> i32 {
+    if total <= 0 || pct <= 0 {
+        return 0;
+    }
+    ((total as i64 * pct as i64) / 100) as i32
+}
+
+/// Certificate-by-certificate split of a dividend once share ownership is recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DividendBreakdown {
+    /// Holder of the president's certificate.
+    pub president: String,
+    /// What the president's whole holding receives; the 20% certificate pays double.
+    pub president_payout: i32,
+    /// What each ordinary share pays.
+    pub per_share: i32,
+    /// Every other owner with their payout, largest holding first (ties by name).
+    pub others: Vec<(String, i32)>,
+}
+
+/// Splits `total` by certificate: each `share`-percent certificate pays its rounded-down
+/// part and `president`'s certificate pays twice that. `None` when the president holds
+/// no shares.
+pub fn dividend_breakdown(
+    total: i32,
+    share: u8,
+    shares: &BTreeMap<String, u8>,
+    president: &str,
+) -> Option<DividendBreakdown> {
+    if share == 0 {
+        return None;
+    }
+    let per_share = dividend_per_share(total, i32::from(share));
+    let payout = |pct: u8| per_share * i32::from(pct / share);
+    let president_pct = shares.get(president).copied().filter(|pct| *pct > 0)?;
+    let mut others: Vec<(&String, u8)> = shares
+        .iter()
+        .filter(|(owner, pct)| owner.as_str() != president && **pct > 0)
+        .map(|(owner, pct)| (owner, *pct))
+        .collect();
+    others.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    Some(DividendBreakdown {
+        president: president.to_string(),
+        president_payout: payout(president_pct),
+        per_share,
+        others: others
+            .into_iter()
+            .map(|(owner, pct)| (owner.clone(), payout(pct)))
+            .collect(),
+    })
+}
+
+/// Payout for every preset percentage, in ascending order.
+pub fn preset_payouts(total: i32) -> Vec<(i32, i32)> {
+    PRESET_PERCENTAGES
+        .iter()
+        .map(|&pct| (pct, dividend_per_share(total, pct)))
+        .collect()
+}
+
+/// Splits `total` for a half-withhold as `(treasury, shareholders)`.
+///
+/// Shareholders get half the revenue rounded down to a multiple of 10 so each of the ten
+/// shares receives a whole amount; the treasury keeps the rest.
+pub fn withhold_split(total: i32) -> (i32, i32) {
+    if total <= 0 {
+        return (0, 0);
+    }
+    let shareholders = total / 2 / 10 * 10;
+    (total - shareholders, shareholders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_share_payouts_round_down() {
+        assert_eq!(dividend_per_share(95, 10), 9);
+        assert_eq!(dividend_per_share(95, 50), 47);
+        assert_eq!(dividend_per_share(100, 60), 60);
+        assert_eq!(dividend_per_share(0, 10), 0);
+        assert_eq!(dividend_per_share(-40, 10), 0);
+        assert_eq!(
+            preset_payouts(95),
+            [(10, 9), (20, 19), (30, 28), (40, 38), (50, 47), (60, 57)]
+        );
+    }
+
+    #[test]
+    fn president_certificate_pays_double_the_share() {
+        let shares = BTreeMap::from([
+            ("Bob".to_string(), 10),
+            ("Alice".to_string(), 20),
+            ("Cara".to_string(), 30),
+        ]);
+        // 95 pays 9 a share; the 20% certificate pays 18, not 20% of 95 (19). Alice holds
+        // it even though Cara owns more.
+        let breakdown = dividend_breakdown(95, 10, &shares, "Alice").expect("owners recorded");
+        assert_eq!(breakdown.per_share, 9);
+        assert_eq!(
+            (breakdown.president.as_str(), breakdown.president_payout),
+            ("Alice", 18)
+        );
+        assert_eq!(
+            breakdown.others,
+            [("Cara".to_string(), 27), ("Bob".to_string(), 9)]
+        );
+
+        let only_president = BTreeMap::from([("Dan".to_string(), 20)]);
+        let breakdown = dividend_breakdown(120, 10, &only_president, "Dan").expect("president");
+        assert_eq!((breakdown.president_payout, breakdown.per_share), (24, 12));
+        assert!(breakdown.others.is_empty());
+        // Games with 5% shares pay per 5% certificate.
+        let breakdown = dividend_breakdown(120, 5, &only_president, "Dan").expect("president");
+        assert_eq!((breakdown.president_payout, breakdown.per_share), (24, 6));
+
+        assert_eq!(dividend_breakdown(95, 10, &BTreeMap::new(), "Eve"), None);
+        assert_eq!(
+            dividend_breakdown(95, 10, &BTreeMap::from([("Eve".to_string(), 0)]), "Eve"),
+            None
+        );
+    }
+
+    #[test]
+    fn withhold_split_keeps_share_payouts_whole() {
+        assert_eq!(withhold_split(95), (55, 40));
+        assert_eq!(withhold_split(100), (50, 50));
+        assert_eq!(withhold_split(15), (15, 0));
+        assert_eq!(withhold_split(0), (0, 0));
+    }
+}
@@ -1,12 +1,14 @@
 //! Save-game persistence scaffolding.
 
+#[cfg(any(test, feature = "testing"))]
+use std::collections::BTreeMap;
 use std::{
     fs,
     path::{Path, PathBuf},
 };
 
 use anyhow::{anyhow, Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::warn;
@@ -16,6 +18,9 @@ use crate::models::GameInfo;
 /// Root directory under `~/.config` used for save files.
 pub const DEFAULT_SAVE_DIR: &str = "18tui/saves";
 
+/// Subdirectory of the save root where unreadable saves are moved.
+pub const QUARANTINE_DIR: &str = "corrupt";
+
 /// Metadata describing a persisted session.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaveEntry {
@@ -27,6 +32,16 @@ pub struct SaveEntry {
     pub name: String,
     /// Timestamp when the save was last updated.
     pub updated_at: DateTime<Utc>,
+    /// Timestamp of the first write; `None` for saves made before it was recorded.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl SaveEntry {
+    /// Time between the first and the latest write of the save.
+    pub fn play_duration(&self) -> Option<TimeDelta> {
+        self.created_at.map(|created| self.updated_at - created)
+    }
 }
 
 /// Serialized representation of a save file.
@@ -36,6 +51,8 @@ pub struct SavePayload {
     name: String,
     saved_at: DateTime<Utc>,
     #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
     state: Value,
     #[serde(default)]
     history: Vec<Value>,
@@ -55,6 +72,7 @@ impl SavePayload {
             game_id: game.id.clone(),
             name: display_name,
             saved_at,
+            created_at: Some(saved_at),
             state,
             history: Vec::new(),
             history_index: 0,
@@ -68,6 +86,11 @@ impl SavePayload {
         self.state
     }
 
+    /// When the save was first written, if the file records it.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
     /// Borrow the stored game state without consuming the payload.
     pub fn state(&self) -> &Value {
         &self.state
@@ -78,6 +101,11 @@ impl SavePayload {
         self.history.len()
     }
 
+    /// Snapshot at `index`, oldest first, without moving the active index.
+    pub fn history_state(&self, index: usize) -> Option<&Value> {
+        self.history.get(index)
+    }
+
     /// Index of the currently active history snapshot.
     pub fn history_index(&self) -> usize {
         self.history_index
@@ -128,57 +156,227 @@ impl SavePayload {
     }
 }
 
+/// Storage backend behind [`SaveManager`]: whole-file reads and writes keyed by path.
+pub trait SaveStore: Send + Sync {
+    /// Whether anything is stored at or below `path`.
+    fn exists(&self, path: &Path) -> bool;
+    /// `.json` files directly inside `dir`, in no particular order.
+    fn list(&self, dir: &Path) -> Result<Vec<PathBuf>>;
+    /// Full contents of the file at `path`.
+    fn read(&self, path: &Path) -> Result<String>;
+    /// Replaces the file at `path`, creating missing parent directories.
+    fn write(&self, path: &Path, contents: &[u8]) -> Result<()>;
+    /// Moves a file, creating the target's parent directory.
+    fn rename(&self, from: &Path, to: &Path) -> Result<()>;
+}
+
+/// Default store backed by the filesystem.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileStore;
+
+impl SaveStore for FileStore {
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn list(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir).context("failed to read save directory")? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            files.push(entry.path());
+        }
+        Ok(files)
+    }
+
+    fn read(&self, path: &Path) -> Result<String> {
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        fs::rename(from, to)
+            .with_context(|| format!("failed to move {} to {}", from.display(), to.display()))
+    }
+}
+
+/// In-memory store for tests of save history without disk IO.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    files: parking_lot::Mutex<BTreeMap<PathBuf, String>>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl SaveStore for MemoryStore {
+    fn exists(&self, path: &Path) -> bool {
+        self.files.lock().keys().any(|file| file.starts_with(path))
+    }
+
+    fn list(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        Ok(self
+            .files
+            .lock()
+            .keys()
+            .filter(|file| file.parent() == Some(dir))
+            .filter(|file| file.extension().and_then(|ext| ext.to_str()) == Some("json"))
+            .cloned()
+            .collect())
+    }
+
+    fn read(&self, path: &Path) -> Result<String> {
+        self.files
+            .lock()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| anyhow!("failed to read {}", path.display()))
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        let text = String::from_utf8(contents.to_vec())
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        self.files.lock().insert(path.to_path_buf(), text);
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let mut files = self.files.lock();
+        let text = files
+            .remove(from)
+            .ok_or_else(|| anyhow!("failed to move {} to {}", from.display(), to.display()))?;
+        files.insert(to.to_path_buf(), text);
+        Ok(())
+    }
+}
+
 /// Manager responsible for loading and writing save files.
 pub struct SaveManager {
     root: PathBuf,
+    read_only: bool,
+    store: Box<dyn SaveStore>,
 }
 
 impl SaveManager {
     /// Create a new manager rooted at the provided directory.
     pub fn new(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self::with_store(root, FileStore)
+    }
+
+    /// Create a manager that keeps its saves in `store` instead of on disk.
+    pub fn with_store(root: impl Into<PathBuf>, store: impl SaveStore + 'static) -> Self {
+        Self {
+            root: root.into(),
+            read_only: false,
+            store: Box::new(store),
+        }
+    }
+
+    /// Manager backed by a fresh [`MemoryStore`].
+    #[cfg(any(test, feature = "testing"))]
+    pub fn in_memory() -> Self {
+        Self::with_store("/saves", MemoryStore::default())
     }
 
-    /// Default location under the user's config directory.
-    pub fn default_root() -> PathBuf {
-        dirs::config_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join(DEFAULT_SAVE_DIR)
+    /// Refuse every write (used while another instance holds the lock); reads still work.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Whether writes are currently refused.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn ensure_writable(&self) -> Result<()> {
+        if self.read_only {
+            Err(anyhow!(
+                "saves are read-only while another instance is running"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `saves` under `data_dir` when one is configured, otherwise the default location
+    /// under the user's config directory.
+    pub fn default_root(data_dir: Option<&Path>) -> PathBuf {
+        match data_dir {
+            Some(dir) => dir.join("saves"),
+            None => dirs::config_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(DEFAULT_SAVE_DIR),
+        }
     }
 
     /// Return all known saves sorted by timestamp (most recent first).
     pub fn entries(&self) -> Result<Vec<SaveEntry>> {
-        if !self.root.exists() {
+        if !self.store.exists(&self.root) {
             return Ok(Vec::new());
         }
 
         let mut entries = Vec::new();
-        for entry in fs::read_dir(&self.root).context("failed to read save directory")? {
-            let entry = entry?;
-            if !entry.file_type()?.is_file() {
-                continue;
-            }
-            if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
-                continue;
-            }
-
-            match self.read_payload(entry.path()) {
+        for path in self.save_files()? {
+            match self.read_payload(&path) {
                 Ok(payload) => entries.push(SaveEntry {
-                    path: entry.path(),
+                    path,
                     game_id: payload.game_id,
                     name: payload.name,
                     updated_at: payload.saved_at,
+                    created_at: payload.created_at,
                 }),
                 Err(err) => {
-                    warn!("Failed to read save {:?}: {err}", entry.path());
+                    warn!("Failed to read save {:?}: {err}", path);
                 }
             }
         }
 
-        entries.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.updated_at));
         Ok(entries)
     }
 
+    /// List save files that cannot be parsed, with the reason for each.
+    pub fn diagnose(&self) -> Result<Vec<(PathBuf, String)>> {
+        if !self.store.exists(&self.root) {
+            return Ok(Vec::new());
+        }
+        let mut corrupt = Vec::new();
+        for path in self.save_files()? {
+            if let Err(err) = self.read_payload(&path) {
+                corrupt.push((path, format!("{err:#}")));
+            }
+        }
+        corrupt.sort();
+        Ok(corrupt)
+    }
+
+    /// Move a save file into the `corrupt/` subdirectory so it no longer shows up
+    /// in listings; returns the new location.
+    pub fn quarantine(&self, path: &Path) -> Result<PathBuf> {
+        self.ensure_writable()?;
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("invalid save path {}", path.display()))?;
+        let target = self.root.join(QUARANTINE_DIR).join(file_name);
+        self.store.rename(path, &target)?;
+        Ok(target)
+    }
+
     /// Save the current game selection to disk and return the resulting entry.
     pub fn create_save(
         &self,
@@ -186,13 +384,7 @@ impl SaveManager {
         name: Option<&str>,
         state: Value,
     ) -> Result<SaveEntry> {
-        if let Some(parent) = self.root.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("failed to create {}", parent.display()))?;
-        }
-        fs::create_dir_all(&self.root)
-            .with_context(|| format!("failed to create {}", self.root.display()))?;
-
+        self.ensure_writable()?;
         let payload = SavePayload::new(game, name, state);
         let file_name = format!(
             "{}_{}.json",
@@ -207,6 +399,7 @@ impl SaveManager {
             game_id: payload.game_id,
             name: payload.name,
             updated_at: payload.saved_at,
+            created_at: payload.created_at,
         })
     }
 
@@ -225,6 +418,7 @@ impl SaveManager {
             game_id: payload.game_id,
             name: payload.name,
             updated_at: payload.saved_at,
+            created_at: payload.created_at,
         })
     }
 
@@ -249,6 +443,7 @@ impl SaveManager {
             game_id: payload.game_id.clone(),
             name: payload.name.clone(),
             updated_at: payload.saved_at,
+            created_at: payload.created_at,
         })
     }
 
@@ -269,30 +464,31 @@ impl SaveManager {
             game_id: payload.game_id.clone(),
             name: payload.name.clone(),
             updated_at: payload.saved_at,
+            created_at: payload.created_at,
         };
         Ok((updated_entry, payload))
     }
 
+    fn save_files(&self) -> Result<Vec<PathBuf>> {
+        self.store.list(&self.root)
+    }
+
     fn write_payload(&self, path: &Path, payload: &SavePayload) -> Result<()> {
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("failed to create {}", parent.display()))?;
-        }
+        self.ensure_writable()?;
         let serialised = serde_json::to_vec_pretty(payload)?;
-        fs::write(path, serialised).with_context(|| format!("failed to write {}", path.display()))
+        self.store.write(path, &serialised)
     }
 
     fn read_payload(&self, path: impl AsRef<Path>) -> Result<SavePayload> {
         let path = path.as_ref();
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("failed to read {}", path.display()))?;
+        let content = self.store.read(path)?;
         let payload = serde_json::from_str(&content)
             .with_context(|| format!("failed to parse {}", path.display()))?;
         Ok(payload)
     }
 }
 
-fn sanitize_component(input: &str) -> String {
+pub(crate) fn sanitize_component(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     for ch in input.chars() {
         if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_') {
@@ -363,6 +559,106 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_only_manager_refuses_writes() -> Result<()> {
+        let dir = tempdir()?;
+        let mut manager = SaveManager::new(dir.path());
+        let entry = manager.create_save(&sample_game(), Some("Locked"), json!({"state": 1}))?;
+
+        manager.set_read_only(true);
+        assert!(manager
+            .create_save(&sample_game(), None, json!({}))
+            .is_err());
+        assert!(manager.update_save(&entry, json!({"state": 2})).is_err());
+        assert!(manager.quarantine(&entry.path).is_err());
+        assert_eq!(manager.load(&entry)?.state()["state"], json!(1));
+        Ok(())
+    }
+
+    #[test]
+    fn created_at_survives_updates_and_history_moves() -> Result<()> {
+        let dir = tempdir()?;
+        let manager = SaveManager::new(dir.path());
+        let entry = manager.create_save(&sample_game(), Some("Long game"), json!({"or": 1}))?;
+        let created = entry.created_at.expect("new saves record created_at");
+        assert_eq!(created, entry.updated_at);
+
+        let updated = manager.update_save(&entry, json!({"or": 2}))?;
+        let (moved, payload) = manager.set_history_index(&updated, 0)?;
+        assert_eq!(updated.created_at, Some(created));
+        assert_eq!(moved.created_at, Some(created));
+        assert_eq!(payload.created_at(), Some(created));
+        assert_eq!(manager.entries()?[0].created_at, Some(created));
+        assert_eq!(moved.play_duration(), Some(moved.updated_at - created));
+
+        let legacy = dir.path().join("legacy.json");
+        fs::write(
+            &legacy,
+            r#"{"game_id": "sample", "name": "Old", "saved_at": "2024-01-01T00:00:00Z"}"#,
+        )?;
+        let old = manager
+            .entries()?
+            .into_iter()
+            .find(|entry| entry.path == legacy)
+            .expect("legacy save listed");
+        assert_eq!(old.created_at, None);
+        assert_eq!(old.play_duration(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn diagnose_and_quarantine_corrupt_saves() -> Result<()> {
+        let dir = tempdir()?;
+        let manager = SaveManager::new(dir.path());
+        manager.create_save(&sample_game(), Some("Good"), json!({}))?;
+        let broken = dir.path().join("broken.json");
+        fs::write(&broken, "{ \"game_id\": \"sample\", ")?;
+
+        assert_eq!(manager.entries()?.len(), 1);
+        let corrupt = manager.diagnose()?;
+        assert_eq!(corrupt.len(), 1);
+        assert_eq!(corrupt[0].0, broken);
+        assert!(corrupt[0].1.contains("failed to parse"));
+
+        let moved = manager.quarantine(&broken)?;
+        assert_eq!(moved, dir.path().join(QUARANTINE_DIR).join("broken.json"));
+        assert!(moved.exists() && !broken.exists());
+        assert!(manager.diagnose()?.is_empty());
+        assert_eq!(manager.entries()?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn in_memory_store_tracks_history_without_disk() -> Result<()> {
+        let manager = SaveManager::in_memory();
+        assert!(manager.entries()?.is_empty());
+        let entry = manager.create_save(&sample_game(), Some("Memory"), json!({"or": 1}))?;
+        let entry = manager.update_save(&entry, json!({"or": 2}))?;
+        let entry = manager.update_save(&entry, json!({"or": 3}))?;
+        assert!(!entry.path.exists());
+
+        let (entry, payload) = manager.set_history_index(&entry, 0)?;
+        assert_eq!(
+            (payload.history_len(), payload.state()["or"].clone()),
+            (3, json!(1))
+        );
+        // A new state after undo drops the redo tail.
+        let entry = manager.update_save(&entry, json!({"or": 4}))?;
+        let payload = manager.load(&entry)?;
+        assert_eq!(payload.history_len(), 2);
+        assert_eq!(payload.history_state(1), Some(&json!({"or": 4})));
+        assert!(manager.set_history_index(&entry, 2).is_err());
+
+        assert_eq!(manager.entries()?.len(), 1);
+        let moved = manager.quarantine(&entry.path)?;
+        assert!(manager.entries()?.is_empty());
+        assert_eq!(
+            moved.parent().and_then(Path::file_name),
+            Some(QUARANTINE_DIR.as_ref())
+        );
+        Ok(())
+    }
+
     #[test]
     fn sanitize_creates_safe_filenames() {
         let name = sanitize_component("Hello World!* 18??");
@@ -1,10 +1,11 @@
 #![allow(missing_docs)]
 
-use std::{collections::HashMap, fs, path::PathBuf, process::Stdio};
+use std::{collections::HashMap, env, ffi::OsStr, path::PathBuf, process::Stdio, sync::Arc};
 
 use anyhow::{anyhow, Context, Result};
 use chrono::Utc;
 use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use regex::Regex;
 use serde::Deserialize;
 use serde_json::Value;
@@ -12,7 +13,10 @@ use tokio::process::Command;
 
 use crate::{
     models::GameInfo,
-    resource::loader::{ensure_game_compatible, extract_module_name},
+    resource::loader::{
+        ensure_game_compatible, entity_source_files, extract_module_name, read_entity_sources,
+        read_source,
+    },
 };
 
 use super::models::{Corporation, GameSession, MarketCell, TrainPoolEntry, TrainType};
@@ -64,11 +68,25 @@ require base_path if File.exist?(base_path)
 
 entities_path = File.expand_path(File.join(root, 'lib', 'engine', 'game', folder, 'entities.rb'))
 game_path = File.expand_path(File.join(root, 'lib', 'engine', 'game', folder, 'game.rb'))
+entity_files = ENV.fetch('GAME_ENTITY_FILES', entities_path).split(File::PATH_SEPARATOR)
 
-unless Engine::Game.const_defined?(module_name.to_sym)
-  load entities_path if File.exist?(entities_path)
-  load game_path if File.exist?(game_path)
+def game_const?(module_name, *names)
+  names.reduce(Engine::Game.const_get(module_name)) do |mod, name|
+    return false unless mod.const_defined?(name, false)
+    mod.const_get(name, false)
+  end
+  true
+rescue NameError
+  false
+end
+
+# entities.rb comes first and usually pulls its split files in with require_relative;
+# the rest are loaded only while CORPORATIONS is still missing.
+entity_files.each do |path|
+  break if game_const?(module_name, :Entities, :CORPORATIONS)
+  load path if File.exist?(path)
 end
+load game_path if File.exist?(game_path) && !game_const?(module_name, :Game, :MARKET)
 
 def convert(obj)
   case obj
@@ -83,36 +101,115 @@ def convert(obj)
   end
 end
 
+# JSON objects do not keep key order, so hash-shaped markets travel as ordered
+# [name, rows] pairs under 'named'.
+def convert_market(market)
+  return convert(market) unless market.is_a?(Hash)
+
+  { 'named' => market.map { |name, rows| [name.to_s, convert(rows)] } }
+end
+
 game_module = Engine::Game.const_get(module_name)
 
+# Variant tables such as MARKET_1D/MARKET_2D, or hashes of named markets.
+markets = game_module::Game.constants.map(&:to_s).sort.each_with_object({}) do |name, acc|
+  next unless name =~ /\AMARKET_[A-Z0-9_]+\z/
+  value = game_module::Game.const_get(name)
+  next unless value.is_a?(Array) || (value.is_a?(Hash) && value.values.all? { |v| v.is_a?(Array) })
+  acc[name] = convert_market(value)
+end
+
+# A few games key PHASES by name; list those in the hash's own order.
+phases = game_module::Game::PHASES
+if phases.is_a?(Hash)
+  phases = phases.map do |name, attrs|
+    attrs.is_a?(Hash) ? { 'name' => name.to_s }.merge(attrs) : name.to_s
+  end
+end
+
 data = {
   'corporations' => convert(game_module::Entities::CORPORATIONS),
-  'market' => convert(game_module::Game::MARKET),
+  'market' => convert_market(game_module::Game::MARKET),
+  'markets' => markets,
   'trains' => convert(game_module::Game::TRAINS),
-  'phases' => convert(game_module::Game::PHASES)
+  'phases' => convert(phases)
 }
 
 puts JSON.dump(data)
 "#;
 
+/// Interpreter used for session extraction; resolved through `PATH`.
+pub const RUBY_PROGRAM: &str = "ruby";
+
+/// Runs `<program> --version` and returns its first output line, or `None` when the
+/// interpreter is missing or exits unsuccessfully.
+pub async fn probe_ruby(program: impl AsRef<OsStr>) -> Option<String> {
+    let output = Command::new(program)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    Some(version)
+}
+
 /// Loads fully-detailed game sessions by delegating metadata extraction to Ruby.
+///
+/// Extracted engine data is cached per game id and shared between clones, so reopening a
+/// game skips Ruby until [`SessionLoader::invalidate`] drops the entry.
 #[derive(Debug, Clone)]
 pub struct SessionLoader {
     root_path: PathBuf,
+    market_variant: Option<String>,
+    cache: Arc<Mutex<HashMap<String, Arc<RawSession>>>>,
 }
 
 impl SessionLoader {
     pub fn new(root_path: impl Into<PathBuf>) -> Self {
         Self {
             root_path: root_path.into(),
+            market_variant: None,
+            cache: Arc::default(),
         }
     }
 
+    /// Points the loader at another engine checkout; cached sessions from the old one
+    /// are dropped.
     pub fn with_root(&mut self, root_path: impl Into<PathBuf>) {
         self.root_path = root_path.into();
+        self.cache.lock().clear();
+    }
+
+    /// Forgets the cached extraction for `game_id` so the next load runs Ruby again.
+    /// Returns whether anything was cached.
+    pub fn invalidate(&self, game_id: &str) -> bool {
+        self.cache.lock().remove(game_id).is_some()
+    }
+
+    /// Prefers the named market table (a `MARKET_*` constant or a key of a hash-shaped
+    /// `MARKET`) when a game defines more than one; `None` uses the default table.
+    pub fn set_market_variant(&mut self, variant: Option<String>) {
+        self.market_variant = variant.filter(|name| !name.trim().is_empty());
     }
 
     pub async fn load(&self, info: &GameInfo) -> Result<GameSession> {
+        let cached = self.cache.lock().get(&info.id).cloned();
+        if let Some(raw) = cached {
+            return self.build_session(info.clone(), &raw);
+        }
+
         let base_path = self
             .root_path
             .join("lib")
@@ -129,27 +226,40 @@ impl SessionLoader {
             Err(reason) => return Err(anyhow!("{}", reason)),
         }
 
-        let entities_path = base_path.join("entities.rb");
-        let entities_content = fs::read_to_string(&entities_path)
-            .with_context(|| format!("failed to read {}", entities_path.display()))?;
-        let module_name = extract_module_name(&entities_content)
+        let entity_files = entity_source_files(&base_path);
+        let entities_content = read_entity_sources(&entity_files)?;
+        let game_path = base_path.join("game.rb");
+        let game_content = read_source(&game_path)?;
+        let module_name = extract_module_name(&entities_content, &game_content)
             .ok_or_else(|| anyhow!("unable to determine module name for {}", info.folder))?;
 
-        let raw = self.fetch_raw_session(&info.folder, &module_name).await?;
+        let raw = self
+            .fetch_raw_session(&info.folder, &module_name, &entity_files)
+            .await?;
         let session = self.build_session(info.clone(), &raw)?;
+        self.cache.lock().insert(info.id.clone(), Arc::new(raw));
         Ok(session)
     }
 
-    async fn fetch_raw_session(&self, folder: &str, module: &str) -> Result<RawSession> {
-        let mut command = Command::new("ruby");
+    async fn fetch_raw_session(
+        &self,
+        folder: &str,
+        module: &str,
+        entity_files: &[PathBuf],
+    ) -> Result<RawSession> {
+        let entity_files =
+            env::join_paths(entity_files).context("entity file path contains a separator")?;
+        let mut command = Command::new(RUBY_PROGRAM);
         command.arg("-e").arg(RUBY_SESSION_SCRIPT);
         command
             .env("ENGINE_ROOT", &self.root_path)
             .env("GAME_FOLDER", folder)
             .env("GAME_MODULE", module)
+            .env("GAME_ENTITY_FILES", entity_files)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .current_dir(&self.root_path);
+            .current_dir(&self.root_path)
+            .kill_on_drop(true);
 
         let output = command
             .output()
@@ -173,18 +283,21 @@ impl SessionLoader {
             .corporations
             .iter()
             .map(|corp| {
-                Corporation::new(
+                let mut corporation = Corporation::new(
                     corp.sym.clone().unwrap_or_else(|| "?".to_string()),
                     corp.name
                         .clone()
                         .unwrap_or_else(|| corp.sym.clone().unwrap_or_else(|| "?".to_string())),
                     normalize_color(corp.color.clone()),
                     normalize_color(corp.text_color.clone()),
-                )
+                );
+                corporation.kind = corp.kind.clone();
+                corporation
             })
             .collect::<Vec<_>>();
 
-        let market = normalize_market(&raw.market);
+        let (market_name, raw_market) = select_market(raw, self.market_variant.as_deref());
+        let market = normalize_market(&raw_market);
         let market_cells = collect_market_cells(&market);
         let market_index = market_cells
             .iter()
@@ -214,12 +327,13 @@ impl SessionLoader {
             })
             .collect();
 
-        let phases = raw.phases.clone();
+        let phases = normalize_phases(&raw.phases);
 
         Ok(GameSession {
             info,
             corporations,
             market,
+            market_name,
             market_cells,
             market_index,
             par_cells,
@@ -231,12 +345,84 @@ impl SessionLoader {
     }
 }
 
+/// PHASES as a list. A hash keyed by phase name becomes one entry per key, with the key
+/// as `name`. The extractor already sends those as a list in engine order; a hash that
+/// still reaches Rust comes out in alphabetical key order, since serde_json's map is
+/// sorted.
+fn normalize_phases(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Array(phases) => phases.clone(),
+        Value::Object(phases) => phases
+            .iter()
+            .map(|(name, attrs)| match attrs {
+                Value::Object(attrs) => {
+                    let mut phase = attrs.clone();
+                    phase
+                        .entry("name")
+                        .or_insert_with(|| Value::String(name.clone()));
+                    Value::Object(phase)
+                }
+                _ => Value::String(name.clone()),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn normalize_color(input: Option<String>) -> Option<String> {
     input
         .map(|value| value.trim().trim_start_matches(':').to_string())
         .filter(|s| !s.is_empty())
 }
 
+/// Chooses the market table for a session, returning the variant name when it is not
+/// the plain `MARKET` grid. Hash-shaped markets use the preferred key or their first entry
+/// in the game's own order.
+fn select_market(raw: &RawSession, preferred: Option<&str>) -> (Option<String>, Vec<Vec<Value>>) {
+    if let Some(key) = preferred {
+        if let Some(value) = raw.markets.get(key) {
+            let (inner, rows) = market_rows(value, None);
+            return (Some(inner.unwrap_or_else(|| key.to_string())), rows);
+        }
+    }
+    market_rows(&raw.market, preferred)
+}
+
+fn market_rows(value: &Value, preferred: Option<&str>) -> (Option<String>, Vec<Vec<Value>>) {
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_array) => (
+            None,
+            items
+                .iter()
+                .filter_map(|row| row.as_array().cloned())
+                .collect(),
+        ),
+        // One-dimensional markets are a single row of prices.
+        Value::Array(items) => (None, vec![items.clone()]),
+        // Hash-shaped markets arrive as ordered `[name, rows]` pairs under `named`.
+        Value::Object(map) => {
+            let named: Vec<(&str, &Value)> = map
+                .get("named")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|pair| match pair.as_array()?.as_slice() {
+                    [name, rows] => Some((name.as_str()?, rows)),
+                    _ => None,
+                })
+                .collect();
+            let entry = preferred
+                .and_then(|key| named.iter().find(|(name, _)| *name == key))
+                .or_else(|| named.first());
+            match entry {
+                Some((name, inner)) => (Some(name.to_string()), market_rows(inner, None).1),
+                None => (None, Vec::new()),
+            }
+        }
+        _ => (None, Vec::new()),
+    }
+}
+
 fn normalize_market(raw: &[Vec<Value>]) -> Vec<Vec<String>> {
     raw.iter()
         .map(|row| row.iter().map(value_to_string).collect())
@@ -258,45 +444,90 @@ fn value_to_string(value: &Value) -> String {
     }
 }
 
-fn collect_market_cells(rows: &[Vec<String>]) -> Vec<MarketCell> {
+pub(crate) fn collect_market_cells(rows: &[Vec<String>]) -> Vec<MarketCell> {
     let mut cells = Vec::new();
     for (row_index, row) in rows.iter().enumerate() {
         for (col_index, raw) in row.iter().enumerate() {
             if raw.trim().is_empty() {
                 continue;
             }
-            let numeric = RAW_NUMBER_RE
-                .captures(raw)
-                .and_then(|cap| cap.get(1))
-                .and_then(|m| m.as_str().parse::<i32>().ok());
-            let is_par = raw.contains('p') || raw.contains('P');
+            if is_market_header(raw) {
+                cells.push(MarketCell {
+                    row: row_index,
+                    col: col_index,
+                    value: None,
+                    raw: raw.clone(),
+                    is_par: false,
+                    flags: Vec::new(),
+                    is_header: true,
+                });
+                continue;
+            }
+            let (numeric, flags) = parse_market_value(raw);
+            let is_par = flags.iter().any(|flag| flag.eq_ignore_ascii_case(&'p'));
             cells.push(MarketCell {
                 row: row_index,
                 col: col_index,
                 value: numeric,
                 raw: raw.clone(),
                 is_par,
+                flags,
+                is_header: false,
             });
         }
     }
     cells
 }
 
-fn available_par_cells_from(cells: &[MarketCell]) -> Vec<MarketCell> {
-    let par_cells: Vec<MarketCell> = cells.iter().cloned().filter(|cell| cell.is_par).collect();
+/// Label cells some engines put in their market data: letters (and spaces) only, so no
+/// price and no flag codes.
+fn is_market_header(raw: &str) -> bool {
+    raw.chars().any(char::is_alphabetic)
+        && raw
+            .chars()
+            .all(|ch| ch.is_alphabetic() || ch.is_whitespace())
+}
+
+pub(crate) fn available_par_cells_from(cells: &[MarketCell]) -> Vec<MarketCell> {
+    let par_cells: Vec<MarketCell> = cells.iter().filter(|cell| cell.is_par).cloned().collect();
     if par_cells.is_empty() {
-        cells.to_vec()
+        cells
+            .iter()
+            .filter(|cell| !cell.is_header)
+            .cloned()
+            .collect()
     } else {
         par_cells
     }
 }
 
-static RAW_NUMBER_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(\d+)").expect("failed to compile market numeric regex"));
+/// Splits a raw market string into its leading numeric value and letter flags.
+///
+/// Decimal prices are rounded to the nearest whole unit; cells that do not start with a
+/// number (e.g. `x2` multipliers) have no value but still report their flags.
+pub(crate) fn parse_market_value(raw: &str) -> (Option<i32>, Vec<char>) {
+    let value = RAW_NUMBER_RE
+        .captures(raw)
+        .and_then(|cap| cap.get(1))
+        .and_then(|m| m.as_str().parse::<f64>().ok())
+        .map(|number| number.round() as i32);
+    let mut flags = Vec::new();
+    for ch in raw.chars().filter(|ch| ch.is_ascii_alphabetic()) {
+        if !flags.contains(&ch) {
+            flags.push(ch);
+        }
+    }
+    (value, flags)
+}
+
+static RAW_NUMBER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*([-+]?\d+(?:\.\d+)?)").expect("failed to compile market numeric regex")
+});
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -387,6 +618,260 @@ GAME_RULES_URL = "https://example.com"
         assert_eq!(session.par_cells.len(), 1);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn loads_entities_split_across_files() -> Result<()> {
+        let temp = tempdir()?;
+        let root = temp.path();
+        let game_dir = root.join("lib/engine/game/g_split");
+        fs::create_dir_all(game_dir.join("entities"))?;
+        fs::write(
+            root.join("lib/engine.rb"),
+            "module Engine\n  module Game\n  end\nend\n",
+        )?;
+        fs::write(game_dir.join("meta.rb"), "GAME_TITLE = \"Split\"\n")?;
+        fs::write(
+            game_dir.join("entities.rb"),
+            "require_relative 'entities/corporations'\n",
+        )?;
+        fs::write(
+            game_dir.join("entities/corporations.rb"),
+            r#"
+module Engine
+  module Game
+    module G18Split
+      module Entities
+        CORPORATIONS = [
+          { sym: 'A', name: 'Alpha', color: 'red' },
+          { sym: 'B', name: 'Beta', color: 'blue' }
+        ]
+      end
+    end
+  end
+end
+"#,
+        )?;
+        fs::write(
+            game_dir.join("game.rb"),
+            r#"
+module Engine
+  module Game
+    module G18Split
+      module Game
+        MARKET = [['100p', '110']]
+        TRAINS = [{ name: '2', distance: 2, price: 100, num: 3 }]
+        PHASES = [{ name: '2', train_limit: 4 }]
+      end
+    end
+  end
+end
+"#,
+        )?;
+
+        let loader = SessionLoader::new(root);
+        let info = GameInfo {
+            id: "split".to_string(),
+            title: "Split".to_string(),
+            subtitle: None,
+            folder: "g_split".to_string(),
+            designer: None,
+            location: None,
+            rules_url: None,
+            commit: None,
+            updated_at: None,
+        };
+
+        let session = loader.load(&info).await?;
+        let syms: Vec<_> = session
+            .corporations
+            .iter()
+            .map(|corp| corp.sym.as_str())
+            .collect();
+        assert_eq!(syms, ["A", "B"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalidate_forces_re_extraction() -> Result<()> {
+        let temp = tempdir()?;
+        let loader = SessionLoader::new(temp.path());
+        let info = GameInfo {
+            id: "cached".to_string(),
+            title: "Cached".to_string(),
+            subtitle: None,
+            folder: "g_cached".to_string(),
+            designer: None,
+            location: None,
+            rules_url: None,
+            commit: None,
+            updated_at: None,
+        };
+        let raw = raw_session(serde_json::json!({
+            "corporations": [{ "sym": "A", "name": "Alpha" }],
+            "market": [["100p", "110"]],
+        }));
+        loader.cache.lock().insert(info.id.clone(), Arc::new(raw));
+
+        // Served from the cache even though the engine has no such game folder.
+        let session = loader.clone().load(&info).await?;
+        assert_eq!(session.corporations.len(), 1);
+        assert_eq!(session.par_cells.len(), 1);
+
+        assert!(loader.invalidate(&info.id));
+        assert!(!loader.invalidate(&info.id));
+        let err = loader.load(&info).await.unwrap_err();
+        assert!(
+            err.to_string().contains("game directory missing"),
+            "{err:#}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parses_market_values_and_flags() {
+        assert_eq!(parse_market_value("100p"), (Some(100), vec!['p']));
+        assert_eq!(parse_market_value("70y"), (Some(70), vec!['y']));
+        assert_eq!(parse_market_value("0c"), (Some(0), vec!['c']));
+        assert_eq!(parse_market_value("350e"), (Some(350), vec!['e']));
+        assert_eq!(parse_market_value("12.5"), (Some(13), vec![]));
+        assert_eq!(parse_market_value("-10"), (Some(-10), vec![]));
+        assert_eq!(parse_market_value("x2"), (None, vec!['x']));
+
+        let cells = collect_market_cells(&[vec!["75".to_string(), "82pb".to_string()]]);
+        assert!(!cells[0].is_par);
+        assert!(cells[1].is_par);
+        assert_eq!(cells[1].value, Some(82));
+        assert_eq!(cells[1].flags, vec!['p', 'b']);
+    }
+
+    #[test]
+    fn label_rows_are_headers_not_cells() {
+        let market: Vec<Vec<String>> = [vec!["Par", "Low", "High"], vec!["100", "x2", "120y"]]
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        let cells = collect_market_cells(&market);
+        let headers: Vec<_> = cells
+            .iter()
+            .filter(|cell| cell.is_header)
+            .map(|cell| cell.raw.as_str())
+            .collect();
+        assert_eq!(headers, ["Par", "Low", "High"]);
+        // "Par" holds a `p` but is no par cell; `x2` is a flagged cell, not a label.
+        assert!(!cells[0].is_par && cells[0].flags.is_empty());
+        assert!(!cells[4].is_header);
+        assert_eq!((cells[5].value, cells[5].is_header), (Some(120), false));
+
+        let par: Vec<_> = available_par_cells_from(&cells)
+            .iter()
+            .map(|cell| (cell.row, cell.col))
+            .collect();
+        assert_eq!(par, [(1, 0), (1, 1), (1, 2)]);
+    }
+
+    #[tokio::test]
+    async fn probe_reports_missing_ruby() {
+        let temp = tempdir().expect("tempdir");
+        assert_eq!(probe_ruby(temp.path().join("no-such-ruby")).await, None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_reads_version_from_stub() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempdir()?;
+        let stub = temp.path().join("ruby");
+        fs::write(&stub, "#!/bin/sh\necho 'ruby 3.2.2 (stub)'\n")?;
+        fs::set_permissions(&stub, fs::Permissions::from_mode(0o755))?;
+        assert_eq!(
+            probe_ruby(&stub).await.as_deref(),
+            Some("ruby 3.2.2 (stub)")
+        );
+
+        let failing = temp.path().join("broken-ruby");
+        fs::write(&failing, "#!/bin/sh\nexit 1\n")?;
+        fs::set_permissions(&failing, fs::Permissions::from_mode(0o755))?;
+        assert_eq!(probe_ruby(&failing).await, None);
+        Ok(())
+    }
+
+    fn raw_session(value: Value) -> RawSession {
+        serde_json::from_value(value).expect("raw session")
+    }
+
+    #[test]
+    fn phases_accept_arrays_and_hashes() {
+        let names = |phases: Value| {
+            let raw = raw_session(serde_json::json!({ "phases": phases }));
+            let mut session = GameSession::fixture();
+            session.phases = normalize_phases(&raw.phases);
+            let game = crate::play::Game::new(session, 2);
+            (0..game.phase_count())
+                .map(|idx| game.phases[idx].name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let array = serde_json::json!([
+            { "name": "2", "operating_rounds": 1 },
+            { "name": "3", "operating_rounds": 2 },
+        ]);
+        assert_eq!(names(array), ["2", "3"]);
+        let hash = serde_json::json!({
+            "Yellow": { "operating_rounds": 1, "train_limit": 4 },
+            "Green": { "operating_rounds": 2 },
+            "Brown": "unused",
+        });
+        // The game's order is lost here; the extractor is what keeps it.
+        assert_eq!(names(hash), ["Brown", "Green", "Yellow"]);
+        assert!(normalize_phases(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn selects_flat_and_one_dimensional_markets() {
+        let grid = raw_session(serde_json::json!({
+            "market": [["100p", "110"], ["90", "100"]],
+        }));
+        let (name, rows) = select_market(&grid, None);
+        assert_eq!(name, None);
+        assert_eq!(rows.len(), 2);
+
+        let line = raw_session(serde_json::json!({
+            "market": ["0c", "10", "20p"],
+            "markets": { "MARKET_2D": [["100p"], ["90"]] },
+        }));
+        let (name, rows) = select_market(&line, None);
+        assert_eq!(name, None);
+        assert_eq!(normalize_market(&rows), vec![vec!["0c", "10", "20p"]]);
+
+        let (name, rows) = select_market(&line, Some("MARKET_2D"));
+        assert_eq!(name.as_deref(), Some("MARKET_2D"));
+        assert_eq!(rows.len(), 2);
+
+        let (name, rows) = select_market(&line, Some("MARKET_MISSING"));
+        assert_eq!(name, None);
+        assert_eq!(rows[0].len(), 3);
+    }
+
+    #[test]
+    fn selects_keyed_markets() {
+        let keyed = raw_session(serde_json::json!({
+            "market": {
+                "named": [
+                    ["standard", [["120p", "130"]]],
+                    ["basic", [["100p"], ["90"]]],
+                ],
+            },
+        }));
+        // The first entry is the game's first, not the alphabetically first.
+        let (name, rows) = select_market(&keyed, None);
+        assert_eq!(name.as_deref(), Some("standard"));
+        assert_eq!(normalize_market(&rows), vec![vec!["120p", "130"]]);
+
+        let (name, rows) = select_market(&keyed, Some("basic"));
+        assert_eq!(name.as_deref(), Some("basic"));
+        assert_eq!(rows.len(), 2);
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -394,11 +879,13 @@ struct RawSession {
     #[serde(default)]
     corporations: Vec<RawCorporation>,
     #[serde(default)]
-    market: Vec<Vec<Value>>,
+    market: Value,
+    #[serde(default)]
+    markets: serde_json::Map<String, Value>,
     #[serde(default)]
     trains: Vec<RawTrain>,
     #[serde(default)]
-    phases: Vec<Value>,
+    phases: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -407,6 +894,8 @@ struct RawCorporation {
     name: Option<String>,
     color: Option<String>,
     text_color: Option<String>,
+    #[serde(rename = "type", default)]
+    kind: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -5,8 +5,8 @@
 pub mod loader;
 mod models;
 
-pub use loader::SessionLoader;
+pub use loader::{probe_ruby, SessionLoader, RUBY_PROGRAM};
 pub use models::{
-    Corporation, CorporationTrain, GameSession, MarketCell, MarketPosition, TrainPoolEntry,
-    TrainType,
+    Corporation, CorporationTrain, GameSession, MarketCell, MarketPosition, SessionExport,
+    TrainPoolEntry, TrainType,
 };
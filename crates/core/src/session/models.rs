@@ -1,13 +1,17 @@
 #![allow(missing_docs)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
 
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::de;
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::models::GameInfo;
+use crate::session::loader::parse_market_value;
 
 /// Corporation data mirrored from the Ruby UI with runtime state fields.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,14 +20,26 @@ pub struct Corporation {
     pub name: String,
     pub color: Option<String>,
     pub text_color: Option<String>,
+    /// Engine corporation type (`major`, `minor`, ...) when the entity declares one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub par_value: Option<i32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub market_position: Option<MarketPosition>,
+    /// Market cell the corporation was parred at; the share price moves away from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub par_cell: Option<MarketPosition>,
     #[serde(default)]
     pub trains: Vec<CorporationTrain>,
     #[serde(default)]
     pub last_revenue: i32,
+    /// Percentage held per owner (player name), for splitting dividends.
+    #[serde(default)]
+    pub shares: BTreeMap<String, u8>,
+    /// Owner holding the president's certificate, once one has been bought.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub president: Option<String>,
 }
 
 impl Corporation {
@@ -38,12 +54,34 @@ impl Corporation {
             name,
             color,
             text_color,
+            kind: None,
             par_value: None,
             market_position: None,
+            par_cell: None,
             trains: Vec::new(),
             last_revenue: 0,
+            shares: BTreeMap::new(),
+            president: None,
         }
     }
+
+    /// Clears runtime state (par, price, trains, revenue, shares) while keeping identity
+    /// and colors.
+    /// Returns the trains that were held so callers can hand them back to the pool.
+    pub fn reset(&mut self) -> Vec<CorporationTrain> {
+        self.par_value = None;
+        self.market_position = None;
+        self.par_cell = None;
+        self.last_revenue = 0;
+        self.shares.clear();
+        self.president = None;
+        std::mem::take(&mut self.trains)
+    }
+
+    /// Sets `last_revenue` to the sum of the trains' latest runs.
+    pub fn recompute_revenue(&mut self) {
+        self.last_revenue = self.trains.iter().map(|train| train.last_revenue).sum();
+    }
 }
 
 /// Train instance assigned to a corporation.
@@ -67,6 +105,32 @@ pub struct MarketPosition {
     pub raw: String,
 }
 
+impl MarketPosition {
+    /// Numeric share price: the stored `value`, or the number parsed out of `raw` when the
+    /// value was never filled in (older saves, hand-edited positions).
+    pub fn numeric_value(&self) -> Option<i32> {
+        self.value.or_else(|| {
+            let digits: String = self
+                .raw
+                .chars()
+                .filter(|ch| !ch.is_ascii_alphabetic())
+                .collect();
+            parse_market_value(&digits).0
+        })
+    }
+}
+
+impl From<&MarketCell> for MarketPosition {
+    fn from(cell: &MarketCell) -> Self {
+        MarketPosition {
+            row: cell.row,
+            col: cell.col,
+            value: cell.value,
+            raw: cell.raw.clone(),
+        }
+    }
+}
+
 /// Extracted market cell information used for navigation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketCell {
@@ -75,6 +139,13 @@ pub struct MarketCell {
     pub value: Option<i32>,
     pub raw: String,
     pub is_par: bool,
+    /// Letter codes attached to the cell (zone colors, par marker, etc.) in source order.
+    #[serde(default)]
+    pub flags: Vec<char>,
+    /// A text label from the engine data (e.g. a column heading) rather than a price;
+    /// never selectable.
+    #[serde(default)]
+    pub is_header: bool,
 }
 
 /// Train definition sourced from the engine.
@@ -101,6 +172,9 @@ pub struct GameSession {
     pub info: GameInfo,
     pub corporations: Vec<Corporation>,
     pub market: Vec<Vec<String>>,
+    /// Market table variant used when it was not the default `MARKET` grid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub market_name: Option<String>,
     pub market_cells: Vec<MarketCell>,
     #[serde(
         serialize_with = "serialize_market_index",
@@ -115,8 +189,129 @@ pub struct GameSession {
 }
 
 impl GameSession {
+    /// Playable cell at `(row, col)`; blanks and header labels have none.
     pub fn market_cell(&self, row: usize, col: usize) -> Option<&MarketCell> {
-        self.market_index.get(&(row, col))
+        self.market_index
+            .get(&(row, col))
+            .filter(|cell| !cell.is_header)
+    }
+
+    /// Whether `(row, col)` holds a header label rather than a price.
+    pub fn is_market_header(&self, row: usize, col: usize) -> bool {
+        self.market_index
+            .get(&(row, col))
+            .is_some_and(|cell| cell.is_header)
+    }
+
+    /// Writes the whole session as pretty JSON to `<dir>/<game>_session_<timestamp>.json`
+    /// so extraction bugs can be reproduced without the engine checkout.
+    pub fn export_to(&self, dir: &Path, engine_commit: Option<&str>) -> Result<PathBuf> {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+        let export = SessionExport {
+            exported_at: Utc::now(),
+            engine_commit: engine_commit.map(str::to_string),
+            session: self.clone(),
+        };
+        let file_name = format!(
+            "{}_session_{}.json",
+            crate::save::sanitize_component(&self.info.id),
+            export.exported_at.format("%Y%m%d%H%M%S")
+        );
+        let path = dir.join(file_name);
+        let json = serde_json::to_string_pretty(&export)?;
+        fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+/// Raw session dump for bug reports, kept apart from play-state saves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionExport {
+    pub exported_at: DateTime<Utc>,
+    /// Engine checkout the session was extracted from, when known.
+    pub engine_commit: Option<String>,
+    pub session: GameSession,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl GameSession {
+    /// Deterministic session built without Ruby, for exercising play logic in tests.
+    ///
+    /// Two corporations (PRR, NYC), a ragged three-row market with par cells at `(0, 0)`
+    /// and `(1, 0)`, `2` and `3` trains, and a single phase `2` with one operating round.
+    pub fn fixture() -> Self {
+        Self::fixture_with_market(&[
+            &["100p", "110", "120", "130"],
+            &["90p", "100", "110"],
+            &["80", "90y", "100o"],
+        ])
+    }
+
+    /// [`GameSession::fixture`] with its market replaced by `rows`.
+    ///
+    /// Cells, index and par cells are derived the way the loader derives them, so blank
+    /// entries leave holes, letter-only entries become headers and a market without `p`
+    /// flags offers every cell for par.
+    pub fn fixture_with_market(rows: &[&[&str]]) -> Self {
+        let market: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        let market_cells = crate::session::loader::collect_market_cells(&market);
+        let market_index = market_cells
+            .iter()
+            .cloned()
+            .map(|cell| ((cell.row, cell.col), cell))
+            .collect();
+        let par_cells = crate::session::loader::available_par_cells_from(&market_cells);
+        let train_type = |name: &str, distance: i64, price: i64, total: i64| TrainType {
+            name: name.to_string(),
+            distance: serde_json::Value::from(distance),
+            price: Some(price),
+            total,
+            rusts_on: serde_json::Value::Null,
+            obsolete_on: serde_json::Value::Null,
+        };
+        let train_types = vec![train_type("2", 2, 80, 3), train_type("3", 3, 180, 2)];
+        let train_pool = train_types
+            .iter()
+            .map(|ty| TrainPoolEntry {
+                name: ty.name.clone(),
+                remaining: ty.total,
+            })
+            .collect();
+
+        GameSession {
+            info: GameInfo {
+                id: "sample".to_string(),
+                title: "Sample".to_string(),
+                subtitle: None,
+                folder: "g_sample".to_string(),
+                designer: None,
+                location: None,
+                rules_url: None,
+                commit: None,
+                updated_at: None,
+            },
+            corporations: vec![
+                Corporation::new("PRR".to_string(), "Pennsylvania".to_string(), None, None),
+                Corporation::new(
+                    "NYC".to_string(),
+                    "New York Central".to_string(),
+                    None,
+                    None,
+                ),
+            ],
+            market,
+            market_name: None,
+            market_cells,
+            market_index,
+            par_cells,
+            train_types,
+            train_pool,
+            phases: vec![serde_json::json!({ "name": "2", "operating_rounds": 1 })],
+            loaded_at: Utc::now(),
+        }
     }
 }
 
@@ -160,3 +355,83 @@ where
     }
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_is_self_consistent() {
+        let session = GameSession::fixture();
+        assert_eq!(session.market_cells.len(), 10);
+        assert_eq!(session.market_index.len(), session.market_cells.len());
+        let par: Vec<_> = session
+            .par_cells
+            .iter()
+            .map(|cell| (cell.row, cell.col))
+            .collect();
+        assert_eq!(par, [(0, 0), (1, 0)]);
+        let cell = session.market_cell(2, 1).expect("cell");
+        assert_eq!((cell.value, cell.flags.as_slice()), (Some(90), &['y'][..]));
+
+        let round_trip: GameSession =
+            serde_json::from_value(serde_json::to_value(&session).unwrap()).unwrap();
+        assert_eq!(round_trip.market_index.len(), 10);
+    }
+
+    #[test]
+    fn session_round_trips_through_json() {
+        let session = GameSession::fixture();
+        let json = serde_json::to_value(&session).unwrap();
+        let round_trip: GameSession = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&round_trip).unwrap(), json);
+        let par = round_trip.market_cell(1, 0).expect("par cell");
+        assert_eq!((par.raw.as_str(), par.is_par), ("90p", true));
+    }
+
+    #[test]
+    fn export_writes_session_with_engine_commit() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let session = GameSession::fixture();
+        let path = session.export_to(&dir.path().join("exports"), Some("abc1234"))?;
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(
+            name.starts_with(&format!("{}_session_", session.info.id)),
+            "{name}"
+        );
+
+        let export: SessionExport = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        assert_eq!(export.engine_commit.as_deref(), Some("abc1234"));
+        assert_eq!(
+            export.session.corporations.len(),
+            session.corporations.len()
+        );
+        assert_eq!(export.session.train_types.len(), session.train_types.len());
+        Ok(())
+    }
+
+    #[test]
+    fn numeric_value_prefers_stored_value() {
+        let position = MarketPosition {
+            row: 0,
+            col: 0,
+            value: Some(110),
+            raw: "100p".to_string(),
+        };
+        assert_eq!(position.numeric_value(), Some(110));
+    }
+
+    #[test]
+    fn numeric_value_falls_back_to_raw() {
+        let position = |raw: &str| MarketPosition {
+            row: 0,
+            col: 0,
+            value: None,
+            raw: raw.to_string(),
+        };
+        assert_eq!(position("90y").numeric_value(), Some(90));
+        assert_eq!(position("p75").numeric_value(), Some(75));
+        assert_eq!(position(" 12.5 ").numeric_value(), Some(13));
+        assert_eq!(position("closed").numeric_value(), None);
+    }
+}
@@ -7,44 +7,62 @@
 
 use std::{
     cmp,
-    collections::{HashMap, HashSet},
-    env, fs, io, path::PathBuf, thread, time::Duration,
+    collections::{HashMap, HashSet, VecDeque},
+    env, fs,
+    io::{self, Write},
+    mem,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
 };
 
 use anyhow::{anyhow, Context, Result};
-use chrono::Local;
+use chrono::{DateTime, Local, TimeDelta};
 use crossterm::{
+    cursor,
     event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
+    },
 };
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Wrap},
     Frame, Terminal,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, to_value, Value};
-use tokio::{spawn, sync::mpsc};
-use tracing::{debug, error, info};
+use tokio::{spawn, sync::mpsc, task::JoinHandle};
+use tracing::{debug, error, info, warn};
 use tui18_core::{
-    manifest::ResourceMetadata,
-    models::GameInfo,
-    resource::{ResourceLoader, SyncEvent},
-    save::{SaveEntry, SaveManager},
+    config::{AppConfig, ClipboardMode, FailureAlert, MarketTokens, RecentEngines},
+    currency::CurrencyFormat,
+    lock,
+    manifest::{self, ResourceMetadata},
+    models::{GameInfo, GamesDiff},
+    play::{
+        Game, OperatingRound, PhaseInfo, RevenueAction, RevenueError, RevenueOutcome, ShareError,
+        SHARE,
+    },
+    resource::{EngineWatcher, ResourceLoader, SkippedGame, SyncEvent, WATCH_DEBOUNCE},
+    revenue,
+    save::{SaveEntry, SaveManager, SavePayload},
     session::{
-        Corporation, CorporationTrain, GameSession, MarketCell, MarketPosition, SessionLoader,
-        TrainType,
+        Corporation, CorporationTrain, GameSession, MarketPosition, SessionLoader, TrainType,
     },
 };
 
-use crate::block_font;
+use crate::{block_font, clipboard, opener};
 
 /// Keyboard polling cadence; slower ticks mean fewer background UI updates.
 const TICK_RATE: Duration = Duration::from_millis(250);
+/// Two presses of the same market key this close together (two ticks) count as a
+/// double press.
+const DOUBLE_KEY_WINDOW: Duration = TICK_RATE.saturating_mul(2);
 /// Hard limit to keep save names within file-system friendly bounds.
 const MAX_SAVE_NAME_LEN: usize = 64;
 
@@ -296,6 +314,11 @@ fn parse_hex_color(input: &str) -> Option<Color> {
     }
 }
 
+/// Hex (`#rrggbb`, `#rgb`) or named (`red`, `lightblue`) color from config or engine data.
+fn parse_color(input: &str) -> Option<Color> {
+    parse_hex_color(input).or_else(|| input.trim().parse::<Color>().ok())
+}
+
 /// Produces a readable foreground color for the given background using simple luminance math.
 fn contrast_color(color: &Color, fallback: Color) -> Color {
     match color {
@@ -329,8 +352,9 @@ enum Screen {
 }
 
 /// The section of the play screen that currently owns focus.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 enum PlayMode {
+    #[default]
     Idle,
     ParSelect,
     PriceSelect,
@@ -338,18 +362,120 @@ enum PlayMode {
     TrainRun,
 }
 
+/// What `1`-`6` enter in the revenue view (`%` switches).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum RevenuePresetMode {
+    /// 10%-60% of the corporation's last revenue.
+    #[default]
+    Percent,
+    /// Literal tens: $10-$60.
+    Absolute,
+}
+
+/// How the corporation list labels each corporation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CorpNameDisplay {
+    /// Symbol followed by the full name.
+    #[default]
+    Full,
+    /// Symbol only, for narrow terminals.
+    Abbreviated,
+}
+
+impl CorpNameDisplay {
+    fn from_config(abbreviate: bool) -> Self {
+        if abbreviate {
+            Self::Abbreviated
+        } else {
+            Self::Full
+        }
+    }
+
+    fn toggled(self) -> Self {
+        match self {
+            Self::Full => Self::Abbreviated,
+            Self::Abbreviated => Self::Full,
+        }
+    }
+}
+
 /// Which train list is under the cursor when adjusting rosters.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 enum TrainFocus {
     Owned,
+    #[default]
     Pool,
 }
 
+/// Market grid sizing and colors from the config, applied on top of auto-sizing.
+#[derive(Debug, Clone, Default)]
+struct MarketOptions {
+    /// Floor for the cell width; 0 keeps pure auto-sizing.
+    min_cell_width: usize,
+    /// Blank columns drawn after each cell.
+    cell_spacing: usize,
+    tokens: MarketTokens,
+    /// Zone letter (lowercase) to color overrides from `market_palette`.
+    palette: HashMap<char, Color>,
+}
+
+impl MarketOptions {
+    fn from_config(config: &AppConfig) -> Self {
+        let palette = config
+            .market_palette
+            .iter()
+            .filter_map(|(zone, color)| {
+                let mut letters = zone.trim().chars();
+                let code = match (letters.next(), letters.next()) {
+                    (Some(code), None) if code.is_ascii_alphabetic() => code.to_ascii_lowercase(),
+                    _ => {
+                        warn!(
+                            zone,
+                            "Ignoring market_palette entry: zone must be one letter"
+                        );
+                        return None;
+                    }
+                };
+                match parse_color(color) {
+                    Some(color) => Some((code, color)),
+                    None => {
+                        warn!(zone, color, "Ignoring market_palette entry: unknown color");
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self {
+            min_cell_width: config.market_cell_width,
+            cell_spacing: config.market_cell_spacing,
+            tokens: config.market_tokens,
+            palette,
+        }
+    }
+
+    /// Cell width and visible column count for a market whose longest label is `longest`
+    /// characters, drawn `inner_width` columns wide with `total_cols` columns of data.
+    fn layout(&self, longest: usize, inner_width: usize, total_cols: usize) -> (usize, usize) {
+        let cell_width = cmp::max(4, longest + 2).max(self.min_cell_width);
+        let stride = cell_width + self.cell_spacing;
+        let view_cols = if inner_width == 0 {
+            1
+        } else {
+            cmp::min(total_cols.max(1), cmp::max(1, inner_width.div_ceil(stride)))
+        };
+        (cell_width, view_cols)
+    }
+}
+
 /// Cursor bookkeeping for the train purchase modal.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TrainPurchaseModal {
+    /// Position within the filtered list, not the full available list.
     cursor: usize,
     offset: usize,
+    /// Typed train-name prefix narrowing the list.
+    #[serde(default)]
+    query: String,
 }
 
 /// Captures the temporary input buffer when the user renames a save.
@@ -432,41 +558,148 @@ impl NamePromptModal {
     }
 }
 
-/// How a corporation handles revenue from a train run.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-enum RevenueAction {
-    Dividend,
-    Withhold,
+/// Actions that require an explicit yes/no before they mutate the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfirmAction {
+    ResetCorporation,
+    ClearMarketState,
+    RemoveCustomPhase,
 }
 
-/// Snapshot of a stock movement after resolving a run.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct RevenueOutcome {
-    corp_sym: String,
-    total: i32,
-    price_label: String,
-    moved: bool,
-    action: RevenueAction,
+/// Text input for an ad-hoc phase, entered as `<name> [operating rounds]`.
+#[derive(Debug, Clone, Default)]
+struct PhasePromptModal {
+    input: String,
 }
 
-/// Failures that can occur when adjusting stock prices.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-enum RevenueError {
-    NoCorporation,
-    NoMarketPosition,
+impl PhasePromptModal {
+    /// Splits the input into a phase name and OR count; a trailing number is the count.
+    fn parse(&self, default_rounds: usize) -> Option<(String, usize)> {
+        let mut tokens: Vec<&str> = self.input.split_whitespace().collect();
+        let rounds = match tokens.last().and_then(|token| token.parse::<usize>().ok()) {
+            Some(rounds) if tokens.len() > 1 => {
+                tokens.pop();
+                rounds
+            }
+            _ => default_rounds,
+        };
+        if tokens.is_empty() {
+            return None;
+        }
+        Some((tokens.join(" "), rounds.max(1)))
+    }
 }
 
-impl std::fmt::Display for RevenueError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RevenueError::NoCorporation => write!(f, "No corporation selected"),
-            RevenueError::NoMarketPosition => {
-                write!(f, "Set par price before adjusting stock price")
-            }
+/// Text input naming a shareholder of the selected corporation; `-name` sells a share.
+#[derive(Debug, Clone, Default)]
+struct SharePromptModal {
+    input: String,
+}
+
+impl SharePromptModal {
+    /// The owner's name and whether the entry sells (`-name`) rather than buys.
+    fn parse(&self) -> Option<(String, bool)> {
+        let input = self.input.trim();
+        let (owner, sell) = match input.strip_prefix('-') {
+            Some(rest) => (rest.trim(), true),
+            None => (input, false),
+        };
+        (!owner.is_empty()).then(|| (owner.to_string(), sell))
+    }
+}
+
+/// Scrollable phase list for jumping straight to a phase.
+#[derive(Debug, Clone, Default)]
+struct PhasePickerModal {
+    cursor: usize,
+    offset: usize,
+}
+
+/// Yes/no overlay shown before applying a destructive action.
+#[derive(Debug, Clone)]
+struct ConfirmModal {
+    action: ConfirmAction,
+    message: String,
+}
+
+impl ConfirmModal {
+    /// `Some(true)` to accept (y/Enter), `Some(false)` to cancel (n/Esc), `None` for keys
+    /// the modal ignores.
+    fn answer(key: &KeyEvent) -> Option<bool> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => Some(true),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => Some(false),
+            _ => None,
         }
     }
 }
 
+/// Menu overlay listing recently used engine checkouts (`e`).
+#[derive(Debug, Clone)]
+struct EngineSwitcher {
+    /// Engine root and the commit from its manifest, newest first.
+    entries: Vec<(PathBuf, Option<String>)>,
+    cursor: usize,
+}
+
+/// Scrollable plain-text overlay: the debug inspector's engine `Value` dump or the
+/// screen-reader summary.
+#[derive(Debug, Clone)]
+struct InspectorModal {
+    title: String,
+    lines: Vec<String>,
+    scroll: usize,
+}
+
+/// One value that differs between two compared saves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComparisonRow {
+    /// Corporation symbol, or empty for game-wide values such as the phase.
+    sym: String,
+    field: &'static str,
+    left: String,
+    right: String,
+}
+
+/// Side-by-side differences between the marked save and the highlighted one.
+#[derive(Debug, Clone)]
+struct SaveComparison {
+    left_name: String,
+    right_name: String,
+    rows: Vec<ComparisonRow>,
+    scroll: usize,
+}
+
+/// Read-only walk through a save's history snapshots (`R` on the play screen).
+struct ReplaySession {
+    payload: SavePayload,
+    index: usize,
+    playing: bool,
+    /// Ticks between snapshots while playing.
+    step_ticks: u32,
+    countdown: u32,
+    /// Live state set aside for the duration of the replay.
+    live: PlayState,
+}
+
+impl ReplaySession {
+    fn last_index(&self) -> usize {
+        self.payload.history_len().saturating_sub(1)
+    }
+
+    fn status_line(&self) -> String {
+        let marker = if self.playing { "▶" } else { "⏸" };
+        format!(
+            "Replay {}/{} {marker} {}ms/step - Space play/pause, h/l step, +/- speed, Esc exit",
+            self.index + 1,
+            self.payload.history_len(),
+            u64::from(self.step_ticks) * 250
+        )
+    }
+}
+
+const MAX_REPLAY_STEP_TICKS: u32 = 64;
+
 /// Tracks per-train revenue entry including cursor position and pending digits.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TrainRunState {
@@ -584,89 +817,91 @@ impl TrainRunState {
     }
 }
 
-impl PhaseInfo {
-    /// Normalizes the various PHASES Ruby data shapes into a `PhaseInfo`.
-    fn from_value(value: &Value) -> Self {
-        match value {
-            Value::String(name) => PhaseInfo {
-                name: name.clone(),
-                operating_rounds: 2,
-                raw: value.clone(),
-            },
-            Value::Object(map) => {
-                let name = map
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("?")
-                    .to_string();
-                let operating_rounds = map
-                    .get("operating_rounds")
-                    .and_then(|v| v.as_u64())
-                    .map(|v| v as usize)
-                    .unwrap_or(2);
-                PhaseInfo {
-                    name,
-                    operating_rounds: operating_rounds.max(1),
-                    raw: value.clone(),
-                }
-            }
-            _ => PhaseInfo {
-                name: "?".to_string(),
-                operating_rounds: 2,
-                raw: value.clone(),
-            },
-        }
-    }
-}
-
-impl OperatingRound {
-    /// Pre-allocates a revenue slot for every corporation during the OR setup.
-    fn new(corporations: usize) -> Self {
-        OperatingRound {
-            revenues: vec![0; corporations],
-        }
-    }
-}
-
-/// Simplified phase metadata we need to drive the UI chips.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct PhaseInfo {
-    name: String,
-    operating_rounds: usize,
-    raw: Value,
-}
-
-/// Holds per-corporation revenue values for a single OR in the current phase.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct OperatingRound {
-    revenues: Vec<i32>,
-}
-
 /// Internal event enum fed by the input thread and background loader.
 enum AppEvent {
     Input(Event),
     Tick,
-    SessionLoaded(Result<GameSession>),
+    /// Result of a background load, tagged with the load generation that produced it.
+    SessionLoaded(u64, Box<Result<GameSession>>),
+    /// SIGINT/SIGTERM arrived; save and leave the loop so the terminal is restored.
+    Shutdown,
 }
 
+const SPINNER_FRAMES: [&str; 8] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
+
+/// Status shown at startup and once a message times out (`status_timeout_ticks`).
+const READY_STATUS: &str = "Ready";
+
+const NO_CORPORATIONS_MESSAGE: &str =
+    "This game has no corporation data the extractor understands; market and phases only";
+
+const RUBY_MISSING_MESSAGE: &str =
+    "Ruby not found: install Ruby (e.g. `apt install ruby` or `brew install ruby`) to load sessions";
+
 /// High-level application state for the Rust TUI.
 pub struct Tui18App {
+    config: AppConfig,
     loader: ResourceLoader,
     metadata: ResourceMetadata,
     state: UiState,
     save_manager: SaveManager,
     saves: Vec<SaveEntry>,
+    corrupt_saves: Vec<(PathBuf, String)>,
+    corrupt_overlay: Option<usize>,
+    /// Save marked with `m` on the Continue screen as the left side of a comparison.
+    compare_mark: Option<SaveEntry>,
+    comparison: Option<SaveComparison>,
+    /// Game folders discovery rejected, with reasons, for the Browse report.
+    skipped_games: Vec<SkippedGame>,
+    /// Scroll offset while the skipped-games report is open.
+    skipped_overlay: Option<usize>,
+    engine_switcher: Option<EngineSwitcher>,
+    /// Set once an engine is picked in the switcher, so a sync of the configured checkout
+    /// that finishes later leaves the picked engine active.
+    engine_switched: bool,
+    /// Watches the active engine for edits and follows engine switches.
+    engine_watcher: Option<EngineWatcher>,
+    /// Channel the engine watcher reports on; `None` when watching is off.
+    watch_tx: Option<mpsc::Sender<SyncEvent>>,
     session_loader: SessionLoader,
     screen: Screen,
     play_state: Option<PlayState>,
     pending_session: bool,
+    session_generation: u64,
+    session_task: Option<JoinHandle<()>>,
+    ruby_available: bool,
+    load_started: Option<Instant>,
+    spinner_frame: usize,
+    terminal_title: Option<String>,
+    status_flash: bool,
+    pending_bell: bool,
+    /// OSC 52 sequence queued for the next frame.
+    pending_clipboard: Option<String>,
+    idle_ticks: u32,
     event_tx: Option<mpsc::Sender<AppEvent>>,
     sync_rx: Option<mpsc::Receiver<SyncEvent>>,
+    /// Latest git transfer stage and percentage while a sync is running.
+    sync_progress: Option<(String, u8)>,
     name_prompt: Option<NamePromptModal>,
+    phase_prompt: Option<PhasePromptModal>,
+    share_prompt: Option<SharePromptModal>,
+    phase_picker: Option<PhasePickerModal>,
+    /// Enables the raw data inspector (`--debug`).
+    debug: bool,
+    inspector: Option<InspectorModal>,
+    confirm: Option<ConfirmModal>,
+    replay: Option<ReplaySession>,
     pending_game: Option<GameInfo>,
     pending_save_name: Option<String>,
     pending_save_state: Option<Value>,
+    /// The in-flight load re-extracts the open game (`r`) rather than starting one.
+    refreshing_session: bool,
     active_save: Option<SaveEntry>,
+    /// Money formatter built from the currency settings.
+    currency: CurrencyFormat,
+    market_options: MarketOptions,
+    /// Full names or symbols only in the corporation list (`v`).
+    corp_names: CorpNameDisplay,
     theme: Theme,
     theme_status: Option<String>,
 }
@@ -674,28 +909,70 @@ pub struct Tui18App {
 impl Tui18App {
     /// Constructs the UI facade; call `run` after wiring dependencies.
     pub fn new(
+        config: AppConfig,
         loader: ResourceLoader,
         metadata: ResourceMetadata,
         session_loader: SessionLoader,
     ) -> Self {
         let (theme, theme_status) = load_theme();
+        let currency = config.currency();
+        let market_options = MarketOptions::from_config(&config);
+        let corp_names = CorpNameDisplay::from_config(config.abbreviate_corporations);
+        let save_manager = SaveManager::new(SaveManager::default_root(config.data_dir()));
+        let state = UiState {
+            game_cap: config.max_games,
+            ..UiState::default()
+        };
         Self {
+            config,
             loader,
             metadata,
-            state: UiState::default(),
-            save_manager: SaveManager::new(SaveManager::default_root()),
+            state,
+            save_manager,
             saves: Vec::new(),
+            corrupt_saves: Vec::new(),
+            corrupt_overlay: None,
+            compare_mark: None,
+            comparison: None,
+            skipped_games: Vec::new(),
+            skipped_overlay: None,
+            engine_switcher: None,
+            engine_switched: false,
+            engine_watcher: None,
+            watch_tx: None,
             session_loader,
             screen: Screen::Menu,
             play_state: None,
             pending_session: false,
+            session_generation: 0,
+            session_task: None,
+            ruby_available: true,
+            load_started: None,
+            spinner_frame: 0,
+            terminal_title: None,
+            status_flash: false,
+            pending_bell: false,
+            pending_clipboard: None,
+            idle_ticks: 0,
             event_tx: None,
             sync_rx: None,
+            sync_progress: None,
             name_prompt: None,
+            phase_prompt: None,
+            share_prompt: None,
+            phase_picker: None,
+            debug: false,
+            inspector: None,
+            confirm: None,
+            replay: None,
             pending_game: None,
             pending_save_name: None,
             pending_save_state: None,
+            refreshing_session: false,
             active_save: None,
+            currency,
+            market_options,
+            corp_names,
             theme,
             theme_status: Some(theme_status),
         }
@@ -712,13 +989,12 @@ impl Tui18App {
         }
         self.state.set_status(status);
         if let Err(err) = self.refresh_saves() {
-            self.state
-                .set_status(format!("Failed to load saves: {err}"));
-        } else if let Some(entry) = self.saves.first() {
-            if self.state.select_game(&entry.game_id) {
-                self.state
-                    .set_status(format!("Restored saved selection: {}", entry.name));
-            }
+            self.set_error_status(format!("Failed to load saves: {err}"));
+        } else {
+            self.restore_last_selection();
+        }
+        if let Err(err) = self.remember_engine(&self.loader.root_path()) {
+            warn!(?err, "Failed to record the engine in the recent list");
         }
 
         let mut stdout = io::stdout();
@@ -731,11 +1007,23 @@ impl Tui18App {
 
         let (event_tx, mut event_rx) = mpsc::channel::<AppEvent>(128);
         spawn_input_thread(event_tx.clone());
+        spawn_signal_task(event_tx.clone());
         self.event_tx = Some(event_tx.clone());
 
         let mut sync_rx = self.sync_rx.take();
 
         loop {
+            self.sync_terminal_title();
+            if mem::take(&mut self.pending_bell) {
+                let mut stdout = io::stdout();
+                let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+            }
+            if let Some(sequence) = self.pending_clipboard.take() {
+                let mut stdout = io::stdout();
+                let _ = stdout
+                    .write_all(sequence.as_bytes())
+                    .and_then(|_| stdout.flush());
+            }
             terminal.draw(|frame| self.draw(frame))?;
             if self.state.should_quit {
                 break;
@@ -772,6 +1060,10 @@ impl Tui18App {
             }
         }
 
+        if self.terminal_title.is_some() {
+            // An empty title lets the terminal fall back to its own default.
+            let _ = execute!(terminal.backend_mut(), SetTitle(""));
+        }
         restore_terminal(&mut terminal)?;
         self.event_tx = None;
         Ok(())
@@ -782,41 +1074,225 @@ impl Tui18App {
         self.sync_rx = Some(receiver);
     }
 
+    /// Watches the active engine's game definitions and reports edits on `sender`. Must
+    /// be called inside a Tokio runtime.
+    pub fn watch_engine(&mut self, sender: mpsc::Sender<SyncEvent>) {
+        self.watch_tx = Some(sender);
+        self.restart_engine_watcher();
+    }
+
+    /// Points the engine watcher at the loader's current root, if watching is on.
+    fn restart_engine_watcher(&mut self) {
+        let Some(sender) = self.watch_tx.clone() else {
+            return;
+        };
+        // Stop reporting the old engine before the new watcher starts.
+        self.engine_watcher = None;
+        match EngineWatcher::spawn(&self.loader.root_path(), WATCH_DEBOUNCE, sender) {
+            Ok(watcher) => self.engine_watcher = Some(watcher),
+            Err(err) => warn!("Engine watch disabled: {err:#}"),
+        }
+    }
+
+    /// Turns on contributor tooling such as the raw data inspector.
+    pub fn set_debug(&mut self, enabled: bool) {
+        self.debug = enabled;
+    }
+
+    /// Records whether a Ruby interpreter was found; without one sessions cannot load.
+    pub fn set_ruby_available(&mut self, available: bool) {
+        self.ruby_available = available;
+    }
+
+    /// Browse and play without writing saves, for when another instance holds the lock.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.save_manager.set_read_only(read_only);
+        if read_only {
+            self.state.set_status(
+                "Read-only: another 18tui is running; changes are not saved".to_string(),
+            );
+        }
+    }
+
+    /// Routine confirmation (cursor moves, view toggles, filter echo); dropped when
+    /// `verbose_status` is off.
+    fn set_info_status(&mut self, message: String) {
+        if self.config.verbose_status {
+            self.state.set_info(message);
+        }
+    }
+
+    /// Reports a failed action: sets the status and, if configured, rings the bell or
+    /// flashes the status bar until the next tick.
+    fn set_error_status(&mut self, message: String) {
+        self.state.set_error(message);
+        match self.config.failure_alert {
+            FailureAlert::Off => {}
+            FailureAlert::Bell => self.pending_bell = true,
+            FailureAlert::Flash => self.status_flash = true,
+        }
+    }
+
+    /// Window title for the current screen: the game title while a session is active.
+    fn desired_terminal_title(&self) -> String {
+        match (&self.screen, &self.play_state) {
+            (Screen::Play, Some(state)) => format!("18TUI — {}", state.game.session.info.title),
+            _ => "18TUI".to_string(),
+        }
+    }
+
+    /// Updates the terminal window title when it changed; disabled via `terminal_title`.
+    fn sync_terminal_title(&mut self) {
+        if !self.config.terminal_title {
+            return;
+        }
+        let title = self.desired_terminal_title();
+        if self.terminal_title.as_deref() == Some(title.as_str()) {
+            return;
+        }
+        if let Err(err) = execute!(io::stdout(), SetTitle(&title)) {
+            debug!(?err, "Failed to set terminal title");
+        }
+        self.terminal_title = Some(title);
+    }
+
     /// Pulls fresh manifest data from the resource loader and reapplies filters.
     fn reload_games(&mut self) -> Result<()> {
         let games = self.loader.games()?;
+        self.skipped_games = self.loader.skipped_games();
         self.state.set_games(games);
         self.state.apply_filter();
         info!(total = self.state.all_games.len(), "Games reloaded");
         Ok(())
     }
 
-    /// Periodic housekeeping work; currently only updates the filter hint.
+    /// Periodic housekeeping work: advances the loading spinner and updates the filter hint.
     fn handle_tick(&mut self) {
+        self.status_flash = false;
+        if self.pending_session {
+            self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+        }
         if self.state.mode == Mode::Filter {
+            self.set_info_status(format!("Filter: {}", self.state.filter));
+        }
+        // A running load or sync keeps its message up for as long as the spinner or gauge.
+        let busy = self.pending_session || self.sync_progress.is_some();
+        let timeout = if busy {
+            0
+        } else {
+            self.config.status_timeout_ticks
+        };
+        self.state.tick_status(timeout);
+        self.idle_ticks = self.idle_ticks.saturating_add(1);
+        let interval = self.config.autosave_idle_ticks;
+        if interval > 0 && self.idle_ticks == interval {
+            self.autosave_idle_session();
+        }
+        if self.replay.as_ref().is_some_and(|replay| replay.playing) {
+            self.advance_replay();
+        }
+    }
+
+    /// Safety-net save once input has been idle: commits pending typed values and
+    /// writes the session so a crash cannot lose them.
+    fn autosave_idle_session(&mut self) {
+        if self.screen != Screen::Play || self.replay.is_some() {
+            return;
+        }
+        let Some(mut state) = self.play_state.take() else {
+            return;
+        };
+        if !state.commit_pending_input() {
+            self.play_state = Some(state);
+            return;
+        }
+        debug!("Committed pending input on idle auto-save");
+        if self.store_play_state(state).is_ok() {
             self.state
-                .set_status(format!("Filter: {}", self.state.filter));
+                .set_success("Pending input committed and saved".to_string());
         }
     }
 
+    /// Puts the live game back if a replay is showing and writes any half-entered input,
+    /// so a signal-driven exit loses nothing.
+    fn flush_before_exit(&mut self) {
+        self.stop_replay();
+        self.autosave_idle_session();
+    }
+
     /// Reacts to repo sync progress, refreshing local caches on success.
     fn handle_sync_event(&mut self, event: SyncEvent) {
+        if !matches!(event, SyncEvent::Progress { .. }) {
+            self.sync_progress = None;
+        }
         match event {
+            SyncEvent::Progress { stage, percent } => {
+                self.sync_progress = Some((stage, percent));
+            }
             SyncEvent::Success { path, metadata } => {
                 info!(path = %path.display(), commit = metadata.commit.as_deref().unwrap_or("unknown"), "Sync succeeded");
+                if self.engine_switched && self.loader.root_path() != path {
+                    info!(active = %self.loader.root_path().display(), "Keeping the switched engine");
+                    return;
+                }
                 self.loader.refresh(path.clone(), metadata.clone());
                 self.session_loader.with_root(path);
+                let previous = self.state.all_games.clone();
                 if let Err(err) = self.reload_games() {
                     error!(?err, "Reload after sync failed");
-                    self.state.set_status(format!("Reload failed: {err}"));
+                    self.set_error_status(format!("Reload failed: {err}"));
                 } else {
-                    self.state.set_status("Resources refreshed".to_string());
+                    let mut message = match metadata.branch_switch(&self.metadata) {
+                        Some((old, new)) => {
+                            info!(old, new, "Engine branch switched");
+                            format!("Resources refreshed (branch {old} → {new})")
+                        }
+                        None => "Resources refreshed".to_string(),
+                    };
+                    // The first clone has nothing to compare against.
+                    let diff = GamesDiff::between(&previous, &self.state.all_games);
+                    if !previous.is_empty() && !diff.is_empty() {
+                        info!(
+                            added = diff.added.len(),
+                            removed = diff.removed.len(),
+                            renamed = diff.renamed.len(),
+                            "Games list changed"
+                        );
+                        message.push_str(&format!(": {}", diff.summary()));
+                    }
+                    self.state.set_status(message);
                 }
                 self.metadata = metadata;
             }
+            SyncEvent::EngineChanged { folders } => {
+                info!(?folders, "Engine files changed");
+                self.loader.invalidate();
+                for game in &self.state.all_games {
+                    if folders.is_empty() || folders.contains(&game.folder) {
+                        self.session_loader.invalidate(&game.id);
+                    }
+                }
+                match self.reload_games() {
+                    Ok(()) => {
+                        let changed = if folders.is_empty() {
+                            "Engine games changed".to_string()
+                        } else {
+                            format!("Engine changed ({})", folders.join(", "))
+                        };
+                        self.state.set_status(format!(
+                            "{changed}; reloaded {} games",
+                            self.state.all_games.len()
+                        ));
+                    }
+                    Err(err) => {
+                        error!(?err, "Reload after engine change failed");
+                        self.set_error_status(format!("Reload failed: {err}"));
+                    }
+                }
+            }
             SyncEvent::Error(err) => {
                 error!(?err, "Background sync failed");
-                self.state.set_status(format!("Sync failed: {err}"));
+                self.set_error_status(format!("Sync failed: {err}"));
             }
         }
     }
@@ -825,14 +1301,50 @@ impl Tui18App {
     fn process_app_event(&mut self, maybe_event: Option<AppEvent>) -> bool {
         match maybe_event {
             Some(AppEvent::Input(event)) => {
+                self.idle_ticks = 0;
                 if self.name_prompt.is_some() {
                     if let Event::Key(key) = event {
                         if let Err(err) = self.handle_name_prompt_key(key) {
-                            self.state.set_status(format!("Error: {err}"));
+                            self.set_error_status(format!("Error: {err}"));
+                        }
+                    }
+                } else if self.phase_prompt.is_some() {
+                    if let Event::Key(key) = event {
+                        if let Err(err) = self.handle_phase_prompt_key(key) {
+                            self.set_error_status(format!("Error: {err}"));
+                        }
+                    }
+                } else if self.share_prompt.is_some() {
+                    if let Event::Key(key) = event {
+                        if let Err(err) = self.handle_share_prompt_key(key) {
+                            self.set_error_status(format!("Error: {err}"));
+                        }
+                    }
+                } else if self.phase_picker.is_some() {
+                    if let Event::Key(key) = event {
+                        if let Err(err) = self.handle_phase_picker_key(key) {
+                            self.set_error_status(format!("Error: {err}"));
+                        }
+                    }
+                } else if self.inspector.is_some() {
+                    if let Event::Key(key) = event {
+                        self.handle_inspector_key(key);
+                    }
+                } else if self.confirm.is_some() {
+                    if let Event::Key(key) = event {
+                        if let Err(err) = self.handle_confirm_key(key) {
+                            self.set_error_status(format!("Error: {err}"));
+                        }
+                    }
+                } else if self.replay.is_some() {
+                    if let Event::Key(key) = event {
+                        if let Err(err) = self.handle_replay_key(key) {
+                            self.stop_replay();
+                            self.set_error_status(format!("Replay failed: {err}"));
                         }
                     }
                 } else if let Err(err) = self.handle_input(event) {
-                    self.state.set_status(format!("Error: {err}"));
+                    self.set_error_status(format!("Error: {err}"));
                 }
                 true
             }
@@ -840,19 +1352,35 @@ impl Tui18App {
                 self.handle_tick();
                 true
             }
-            Some(AppEvent::SessionLoaded(result)) => {
+            Some(AppEvent::Shutdown) => {
+                info!("Shutdown signal received");
+                self.flush_before_exit();
+                false
+            }
+            Some(AppEvent::SessionLoaded(generation, result)) => {
+                if generation != self.session_generation {
+                    debug!(generation, "Discarding result of cancelled session load");
+                    return true;
+                }
                 self.pending_session = false;
-                match result {
+                self.session_task = None;
+                self.reset_load_timer();
+                if mem::take(&mut self.refreshing_session) {
+                    self.finish_session_refresh(*result);
+                    return true;
+                }
+                match *result {
                     Ok(session) => {
                         info!(game_id = %session.info.id, title = %session.info.title, "Session loaded");
                         let saved_state = self.pending_save_state.take();
                         let base_session = session;
-                        let play_state = if let Some(raw) = saved_state {
+                        let mut play_state = if let Some(raw) = saved_state {
                             match from_value::<PlayState>(raw) {
                                 Ok(mut state) => {
-                                    state.session.info = base_session.info.clone();
-                                    state.session.loaded_at = base_session.loaded_at;
+                                    state.game.session.info = base_session.info.clone();
+                                    state.game.session.loaded_at = base_session.loaded_at;
                                     state.ensure_token_map();
+                                    state.clamp_restored_view();
                                     state
                                 }
                                 Err(err) => {
@@ -860,30 +1388,55 @@ impl Tui18App {
                                         ?err,
                                         "Failed to restore saved play state; using fresh session"
                                     );
-                                    PlayState::new(base_session)
+                                    PlayState::new(
+                                        base_session,
+                                        self.config.default_operating_rounds,
+                                    )
                                 }
                             }
                         } else {
-                            PlayState::new(base_session)
+                            PlayState::new(base_session, self.config.default_operating_rounds)
                         };
+                        if self.config.pin_title_banner {
+                            play_state.set_title_banner_pinned(true);
+                        }
+                        self.apply_play_config(&mut play_state);
+                        let suspect = play_state.game.suspect_round_phases();
+                        if !suspect.is_empty() {
+                            warn!(phases = ?suspect, "Engine phases without a usable OR count");
+                        }
+                        let rounds_note = suspect_rounds_note(&suspect);
                         let save_result = self.initialize_new_session_save(&play_state);
+                        let has_corporations = play_state.has_corporations();
                         self.screen = Screen::Play;
                         self.play_state = Some(play_state);
                         match save_result {
-                            Ok(Some(message)) => self.state.set_status(message),
-                            Ok(None) => self.state.set_status("Session loaded".to_string()),
+                            Ok(_) if !has_corporations => {
+                                warn!("Session has no corporations");
+                                self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
+                            }
+                            Ok(Some(message)) => match rounds_note {
+                                Some(note) => self.state.set_status(format!("{message}; {note}")),
+                                None => self.state.set_status(message),
+                            },
+                            Ok(None) => match rounds_note {
+                                Some(note) => {
+                                    self.state.set_status(format!("Session loaded; {note}"))
+                                }
+                                None => self.state.set_success("Session loaded".to_string()),
+                            },
                             Err(err) => {
                                 error!(?err, "Failed to prepare save for new session");
-                                self.state
-                                    .set_status(format!("Session started but save failed: {err}"));
+                                self.set_error_status(format!(
+                                    "Session started but save failed: {err}"
+                                ));
                             }
                         }
                     }
                     Err(err) => {
                         error!(?err, "Session load failed");
                         self.screen = Screen::Browse;
-                        self.state
-                            .set_status(format!("Failed to load session: {err}"));
+                        self.set_error_status(format!("Failed to load session: {err}"));
                     }
                 }
                 true
@@ -892,34 +1445,207 @@ impl Tui18App {
         }
     }
 
+    /// Preselects the game of the most recently written save. With `select_last_played`
+    /// the newest save is read from disk rather than taken from the in-memory list.
+    fn restore_last_selection(&mut self) {
+        let entry = if self.config.select_last_played {
+            self.save_manager.latest().unwrap_or_else(|err| {
+                warn!(?err, "Failed to find the most recent save");
+                None
+            })
+        } else {
+            self.saves.first().cloned()
+        };
+        let Some(entry) = entry else {
+            return;
+        };
+        if self.state.select_game(&entry.game_id) {
+            self.state
+                .set_status(format!("Restored saved selection: {}", entry.name));
+        }
+    }
+
     fn refresh_saves(&mut self) -> Result<()> {
         self.saves = self.save_manager.entries()?;
+        self.corrupt_saves = self.save_manager.diagnose()?;
+        let mut recent: Vec<String> = Vec::new();
+        for entry in &self.saves {
+            if !recent.contains(&entry.game_id) {
+                recent.push(entry.game_id.clone());
+            }
+        }
+        self.state.set_recent_games(recent);
         Ok(())
     }
 
-    fn load_save_entry(&mut self, entry: SaveEntry) -> Result<()> {
-        let game_id = entry.game_id.clone();
-        if !self.state.select_game(&game_id) {
-            return Err(anyhow!("Saved game {} not available", game_id));
+    fn handle_corrupt_overlay_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(cursor) = self.corrupt_overlay else {
+            return Ok(());
+        };
+        let last = self.corrupt_saves.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') => self.corrupt_overlay = None,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.corrupt_overlay = Some((cursor + 1).min(last));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.corrupt_overlay = Some(cursor.saturating_sub(1));
+            }
+            KeyCode::Char('m') | KeyCode::Enter => {
+                let Some((path, _)) = self.corrupt_saves.get(cursor).cloned() else {
+                    return Ok(());
+                };
+                let moved = self
+                    .save_manager
+                    .quarantine(&path)
+                    .context("quarantine corrupt save")?;
+                info!(from = %path.display(), to = %moved.display(), "Corrupt save quarantined");
+                self.refresh_saves()?;
+                self.state
+                    .set_status(format!("Moved to {}", moved.display()));
+                self.corrupt_overlay = if self.corrupt_saves.is_empty() {
+                    None
+                } else {
+                    Some(cursor.min(self.corrupt_saves.len() - 1))
+                };
+            }
+            _ => {}
         }
-        let payload = self.save_manager.load(&entry)?;
-        self.pending_save_state = Some(payload.into_state());
-        self.active_save = Some(entry);
-        self.screen = Screen::Browse;
-        self.start_session_load();
         Ok(())
     }
 
-    fn initialize_new_session_save(&mut self, state: &PlayState) -> Result<Option<String>> {
-        let Some(name) = self.pending_save_name.take() else {
-            self.pending_game = None;
-            self.pending_save_state = None;
-            return Ok(None);
-        };
-        let game = self
+    /// Puts `engine` at the front of the recent engines file; read-only instances leave
+    /// it alone.
+    fn remember_engine(&self, engine: &Path) -> Result<()> {
+        if self.save_manager.is_read_only() {
+            return Ok(());
+        }
+        let path = self.config.recent_engines_path();
+        let mut recent = RecentEngines::load(&path)?;
+        recent.record(engine);
+        recent.save(&path)
+    }
+
+    fn open_engine_switcher(&mut self) {
+        let recent =
+            RecentEngines::load(&self.config.recent_engines_path()).unwrap_or_else(|err| {
+                warn!(?err, "Failed to read recent engines");
+                RecentEngines::default()
+            });
+        let current = self.loader.root_path();
+        let entries: Vec<(PathBuf, Option<String>)> = recent
+            .paths
+            .into_iter()
+            .map(|path| {
+                let commit = ResourceMetadata::load(manifest::manifest_path(&path))
+                    .ok()
+                    .flatten()
+                    .and_then(|metadata| metadata.commit);
+                (path, commit)
+            })
+            .collect();
+        let cursor = entries
+            .iter()
+            .position(|(path, _)| *path == current)
+            .unwrap_or(0);
+        self.engine_switcher = Some(EngineSwitcher { entries, cursor });
+    }
+
+    fn handle_engine_switcher_key(&mut self, key: KeyEvent) {
+        let Some(switcher) = self.engine_switcher.as_mut() else {
+            return;
+        };
+        let last = switcher.entries.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('e') | KeyCode::Char('q') => self.engine_switcher = None,
+            KeyCode::Char('j') | KeyCode::Down => switcher.cursor = (switcher.cursor + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => switcher.cursor = switcher.cursor.saturating_sub(1),
+            KeyCode::Enter => {
+                let Some((path, _)) = switcher.entries.get(switcher.cursor).cloned() else {
+                    return;
+                };
+                self.engine_switcher = None;
+                self.switch_engine(path);
+            }
+            _ => {}
+        }
+    }
+
+    /// Points the game list and session loading at another engine checkout and reloads
+    /// the games from it.
+    fn switch_engine(&mut self, path: PathBuf) {
+        if !path.is_dir() {
+            self.set_error_status(format!("Engine checkout missing at {}", path.display()));
+            return;
+        }
+        let metadata = ResourceMetadata::load(manifest::manifest_path(&path))
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        self.loader.refresh(&path, metadata.clone());
+        self.session_loader.with_root(&path);
+        self.metadata = metadata;
+        self.engine_switched = true;
+        self.restart_engine_watcher();
+        if let Err(err) = self.reload_games() {
+            self.set_error_status(format!(
+                "Failed to load games from {}: {err}",
+                path.display()
+            ));
+            return;
+        }
+        if let Err(err) = self.remember_engine(&path) {
+            warn!(?err, "Failed to record the engine in the recent list");
+        }
+        self.screen = Screen::Browse;
+        self.state.mode = Mode::Browse;
+        self.state.set_status(format!(
+            "Switched engine to {} ({} games)",
+            path.display(),
+            self.state.all_games.len()
+        ));
+    }
+
+    fn handle_skipped_overlay_key(&mut self, key: KeyEvent) {
+        let Some(offset) = self.skipped_overlay else {
+            return;
+        };
+        let last = self.skipped_games.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('q') => self.skipped_overlay = None,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.skipped_overlay = Some((offset + 1).min(last));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.skipped_overlay = Some(offset.saturating_sub(1));
+            }
+            _ => {}
+        }
+    }
+
+    fn load_save_entry(&mut self, entry: SaveEntry) -> Result<()> {
+        let game_id = entry.game_id.clone();
+        if !self.state.select_game(&game_id) {
+            return Err(anyhow!("Saved game {} not available", game_id));
+        }
+        let payload = self.save_manager.load(&entry)?;
+        self.pending_save_state = Some(payload.into_state());
+        self.active_save = Some(entry);
+        self.screen = Screen::Browse;
+        self.start_session_load();
+        Ok(())
+    }
+
+    fn initialize_new_session_save(&mut self, state: &PlayState) -> Result<Option<String>> {
+        let Some(name) = self.pending_save_name.take() else {
+            self.pending_game = None;
+            self.pending_save_state = None;
+            return Ok(None);
+        };
+        let game = self
             .pending_game
             .take()
-            .unwrap_or_else(|| state.session.info.clone());
+            .unwrap_or_else(|| state.game.session.info.clone());
         let payload = to_value(state).context("serialize play state for save creation")?;
         let entry = self
             .save_manager
@@ -933,10 +1659,16 @@ impl Tui18App {
         self.pending_save_state = None;
         Ok(Some(format!(
             "Started {} as {}",
-            state.session.info.title, entry.name
+            state.game.session.info.title, entry.name
         )))
     }
 
+    /// Copies config-driven settings that saves do not carry onto `state`; every
+    /// deserialized `PlayState` needs this, not only the first one a session load builds.
+    fn apply_play_config(&self, state: &mut PlayState) {
+        state.focus_follows_revenue = self.config.focus_follows_revenue;
+    }
+
     fn apply_history_step(&mut self, delta: isize) -> Result<()> {
         let Some(active) = self.active_save.clone() else {
             self.state
@@ -974,8 +1706,10 @@ impl Tui18App {
                 .set_status("History entry has no recorded session state".to_string());
             return Ok(());
         }
-        let play_state: PlayState =
+        let mut play_state: PlayState =
             from_value(state_value).context("deserialize play state from history entry")?;
+        play_state.clamp_restored_view();
+        self.apply_play_config(&mut play_state);
         self.active_save = Some(updated_entry.clone());
         if let Some(entry) = self
             .saves
@@ -983,7 +1717,8 @@ impl Tui18App {
             .find(|entry| entry.path == updated_entry.path)
         {
             *entry = updated_entry;
-            self.saves.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            self.saves
+                .sort_by_key(|entry| cmp::Reverse(entry.updated_at));
         }
         self.play_state = Some(play_state);
         let position = updated_payload.history_index() + 1;
@@ -996,7 +1731,156 @@ impl Tui18App {
         Ok(())
     }
 
+    /// Steps through the active save's history from the oldest snapshot without writing
+    /// to it; the live state is set aside and comes back when the replay ends.
+    fn start_replay(&mut self) -> Result<()> {
+        let Some(active) = self.active_save.clone() else {
+            self.state
+                .set_status("Replay unavailable: no save loaded".to_string());
+            return Ok(());
+        };
+        let payload = self
+            .save_manager
+            .load(&active)
+            .context("load save payload for replay")?;
+        if payload.history_len() <= 1 {
+            self.state
+                .set_status("Nothing to replay: this save has a single history entry".to_string());
+            return Ok(());
+        }
+        let Some(live) = self.play_state.take() else {
+            return Ok(());
+        };
+        let step_ticks = self
+            .config
+            .replay_step_ticks
+            .clamp(1, MAX_REPLAY_STEP_TICKS);
+        info!(save = %active.name, entries = payload.history_len(), "Replay started");
+        self.replay = Some(ReplaySession {
+            payload,
+            index: 0,
+            playing: true,
+            step_ticks,
+            countdown: step_ticks,
+            live,
+        });
+        self.show_replay_frame()
+    }
+
+    /// Loads the snapshot at the replay index into `play_state`.
+    fn show_replay_frame(&mut self) -> Result<()> {
+        let Some(replay) = self.replay.as_mut() else {
+            return Ok(());
+        };
+        let value = replay
+            .payload
+            .history_state(replay.index)
+            .cloned()
+            .unwrap_or(Value::Null);
+        let mut state: PlayState = if value.is_null() {
+            replay.live.clone()
+        } else {
+            from_value(value).context("deserialize play state from history entry")?
+        };
+        state.clamp_restored_view();
+        replay.countdown = replay.step_ticks;
+        let status = replay.status_line();
+        self.apply_play_config(&mut state);
+        self.play_state = Some(state);
+        self.state.set_status(status);
+        Ok(())
+    }
+
+    fn seek_replay(&mut self, index: usize) -> Result<()> {
+        let Some(replay) = self.replay.as_mut() else {
+            return Ok(());
+        };
+        let index = index.min(replay.last_index());
+        if index == replay.index {
+            return Ok(());
+        }
+        replay.index = index;
+        self.show_replay_frame()
+    }
+
+    fn advance_replay(&mut self) {
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+        replay.countdown = replay.countdown.saturating_sub(1);
+        if replay.countdown > 0 {
+            return;
+        }
+        if replay.index >= replay.last_index() {
+            replay.playing = false;
+            self.state
+                .set_status("Replay finished - Space to restart, Esc to exit".to_string());
+            return;
+        }
+        let next = replay.index + 1;
+        if let Err(err) = self.seek_replay(next) {
+            self.stop_replay();
+            self.set_error_status(format!("Replay failed: {err}"));
+        }
+    }
+
+    fn stop_replay(&mut self) {
+        if let Some(replay) = self.replay.take() {
+            self.play_state = Some(replay.live);
+            self.state
+                .set_status("Replay ended; back to the live game".to_string());
+        }
+    }
+
+    fn handle_replay_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(replay) = self.replay.as_mut() else {
+            return Ok(());
+        };
+        let index = replay.index;
+        let last = replay.last_index();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('R') => self.stop_replay(),
+            KeyCode::Char('q') if key.modifiers.is_empty() => self.state.should_quit = true,
+            KeyCode::Char(' ') => {
+                replay.playing = !replay.playing;
+                replay.countdown = replay.step_ticks;
+                if replay.playing && index == last {
+                    replay.index = 0;
+                    return self.show_replay_frame();
+                }
+                let status = replay.status_line();
+                self.state.set_status(status);
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                replay.playing = false;
+                self.seek_replay(index + 1)?;
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                replay.playing = false;
+                self.seek_replay(index.saturating_sub(1))?;
+            }
+            KeyCode::Char('g') | KeyCode::Home => self.seek_replay(0)?,
+            KeyCode::Char('G') | KeyCode::End => self.seek_replay(last)?,
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                replay.step_ticks = (replay.step_ticks / 2).max(1);
+                let status = replay.status_line();
+                self.state.set_status(status);
+            }
+            KeyCode::Char('-') => {
+                replay.step_ticks = (replay.step_ticks * 2).min(MAX_REPLAY_STEP_TICKS);
+                let status = replay.status_line();
+                self.state.set_status(status);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn persist_active_session(&mut self, state: &PlayState) -> Result<()> {
+        // Read-only sessions are announced once instead of failing every auto-save.
+        if self.save_manager.is_read_only() {
+            return Ok(());
+        }
         let Some(active) = self.active_save.clone() else {
             return Ok(());
         };
@@ -1012,7 +1896,8 @@ impl Tui18App {
             .find(|entry| entry.path == updated.path)
         {
             *entry = updated;
-            self.saves.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            self.saves
+                .sort_by_key(|entry| cmp::Reverse(entry.updated_at));
         }
         Ok(())
     }
@@ -1021,33 +1906,181 @@ impl Tui18App {
         if self.pending_session {
             return;
         }
+        if !self.ruby_available {
+            self.pending_game = None;
+            self.pending_save_name = None;
+            self.pending_save_state = None;
+            self.set_error_status(RUBY_MISSING_MESSAGE.to_string());
+            return;
+        }
         let Some(game) = self.state.current_game().cloned() else {
             self.state.set_status("No game selected".to_string());
             return;
         };
         self.pending_game = Some(game.clone());
+        self.spawn_session_load(game);
+    }
+
+    /// Re-extracts the open game from the engine, skipping the session cache, and hands
+    /// the result to `finish_session_refresh` so the game in progress keeps its state.
+    fn refresh_active_session(&mut self) {
+        if self.pending_session {
+            return;
+        }
+        if !self.ruby_available {
+            self.set_error_status(RUBY_MISSING_MESSAGE.to_string());
+            return;
+        }
+        let Some(game) = self
+            .play_state
+            .as_ref()
+            .map(|state| state.game.session.info.clone())
+        else {
+            return;
+        };
+        self.session_loader.invalidate(&game.id);
+        if self.spawn_session_load(game.clone()) {
+            self.refreshing_session = true;
+            self.state.set_status(format!(
+                "Refreshing {} from the engine… (Esc to cancel)",
+                game.display_name()
+            ));
+        }
+    }
+
+    /// Swaps re-extracted engine data into the open game and saves it.
+    fn finish_session_refresh(&mut self, result: Result<GameSession>) {
+        let session = match result {
+            Ok(session) => session,
+            Err(err) => {
+                error!(?err, "Session refresh failed");
+                self.set_error_status(format!("Refresh failed: {err}"));
+                return;
+            }
+        };
+        let Some(mut state) = self.play_state.take() else {
+            return;
+        };
+        if state.game.session.info.id != session.info.id {
+            self.play_state = Some(state);
+            return;
+        }
+        info!(game_id = %session.info.id, "Session refreshed");
+        let title = session.info.display_name();
+        state.game.refresh_engine_data(session);
+        state.ensure_token_map();
+        state.clamp_restored_view();
+        let saved = self.persist_active_session(&state);
+        self.play_state = Some(state);
+        match saved {
+            Ok(()) => self
+                .state
+                .set_success(format!("Refreshed {title} from the engine")),
+            Err(err) => {
+                error!(?err, "Failed to save refreshed session");
+                self.set_error_status(format!("Refreshed {title} but save failed: {err}"));
+            }
+        }
+    }
+
+    /// Starts loading `game` in the background; the result arrives as
+    /// `AppEvent::SessionLoaded`. Returns `false` when no load could be started.
+    fn spawn_session_load(&mut self, game: GameInfo) -> bool {
         let Some(sender) = self.event_tx.clone() else {
             self.state
                 .set_status("Internal error: event channel unavailable".to_string());
             error!("event_channel_missing");
-            return;
+            return false;
         };
 
         let display_name = game.display_name();
-        let game_for_load = game.clone();
         self.pending_session = true;
+        self.load_started = Some(Instant::now());
+        self.spinner_frame = 0;
+        self.session_generation += 1;
+        let generation = self.session_generation;
         info!(game_id = %game.id, title = %display_name, "Loading session");
-        self.state.set_status(format!("Loading {}…", display_name));
+        self.state
+            .set_status(format!("Loading {}… (Esc to cancel)", display_name));
         let loader = self.session_loader.clone();
-        spawn(async move {
-            let result = loader.load(&game_for_load).await;
-            let _ = sender.send(AppEvent::SessionLoaded(result)).await;
-        });
+        self.session_task = Some(spawn(async move {
+            let result = loader.load(&game).await;
+            let _ = sender
+                .send(AppEvent::SessionLoaded(generation, Box::new(result)))
+                .await;
+        }));
+        true
+    }
+
+    fn reset_load_timer(&mut self) {
+        self.load_started = None;
+        self.spinner_frame = 0;
+    }
+
+    /// Spinner frame plus elapsed seconds for the in-flight load, if any.
+    fn loading_indicator(&self) -> Option<String> {
+        if !self.pending_session {
+            return None;
+        }
+        let elapsed = self
+            .load_started
+            .map(|started| started.elapsed().as_secs())
+            .unwrap_or(0);
+        Some(format!("{} {elapsed}s", SPINNER_FRAMES[self.spinner_frame]))
+    }
+
+    /// Abandons the in-flight session load and returns to the game list. Any result
+    /// that still arrives for it is dropped because its generation no longer matches.
+    fn cancel_session_load(&mut self) -> bool {
+        if !self.pending_session {
+            return false;
+        }
+        if let Some(task) = self.session_task.take() {
+            task.abort();
+        }
+        self.session_generation += 1;
+        self.pending_session = false;
+        self.reset_load_timer();
+        if mem::take(&mut self.refreshing_session) {
+            info!("Session refresh cancelled");
+            self.state.set_status("Refresh cancelled".to_string());
+            return true;
+        }
+        self.pending_game = None;
+        self.pending_save_name = None;
+        self.pending_save_state = None;
+        self.active_save = None;
+        self.screen = Screen::Browse;
+        self.state.mode = Mode::Browse;
+        info!("Session load cancelled");
+        self.state
+            .set_status("Loading cancelled; pick another game".to_string());
+        true
+    }
+
+    /// Jumps straight to one of the numbered play views from idle: 1 corporations,
+    /// 2 market (par or price selection), 3 revenue, 4 trains.
+    fn jump_to_play_view(&mut self, state: &mut PlayState, view: u8) {
+        state.set_revenue_view(view == 3);
+        match view {
+            1 => self.set_info_status("Corporation view".to_string()),
+            2 => match state.current_corporation() {
+                Some(corp) if corp.par_value.is_some() => self.begin_price_selection(state),
+                Some(_) => self.begin_par_selection(state),
+                None => self
+                    .state
+                    .set_status(state.missing_corporation_message().to_string()),
+            },
+            3 => self.set_info_status("Revenue view enabled".to_string()),
+            4 => self.begin_train_mode(state),
+            _ => {}
+        }
     }
 
     fn begin_train_mode(&mut self, state: &mut PlayState) {
         let Some(corp_sym) = state.current_corporation().map(|corp| corp.sym.clone()) else {
-            self.state.set_status("No corporation selected".to_string());
+            self.state
+                .set_status(state.missing_corporation_message().to_string());
             return;
         };
 
@@ -1062,6 +2095,11 @@ impl Tui18App {
     }
 
     fn begin_par_selection(&mut self, state: &mut PlayState) {
+        if state.current_corporation().is_none() {
+            self.state
+                .set_status(state.missing_corporation_message().to_string());
+            return;
+        }
         let corp_sym = state.current_corporation().map(|corp| corp.sym.clone());
         debug!(?corp_sym, cursor = ?state.market_cursor(), "begin_par_selection invoked");
         if !state.enter_par_select() {
@@ -1078,10 +2116,18 @@ impl Tui18App {
                 "Par selection mode activated"
             );
             info!(sym = %corp.sym, "Entering par selection");
-            self.state
-                .set_status(format!("Select par price for {}", corp.sym));
+            let message = if state.has_marked_par_cells() {
+                format!("Select par price for {}", corp.sym)
+            } else {
+                format!(
+                    "Select par price for {} (no par cells marked; all cells selectable)",
+                    corp.sym
+                )
+            };
+            self.state.set_status(message);
         } else {
-            self.state.set_status("No corporation selected".to_string());
+            self.state
+                .set_status(state.missing_corporation_message().to_string());
         }
     }
 
@@ -1092,23 +2138,35 @@ impl Tui18App {
             self.state
                 .set_status(format!("Select stock price for {}", corp.sym));
         } else {
-            self.state.set_status("No corporation selected".to_string());
+            self.state
+                .set_status(state.missing_corporation_message().to_string());
         }
     }
 
     fn apply_par_selection(&mut self, state: &mut PlayState) {
         let cursor = state.market_cursor();
         debug!(?cursor, "apply_par_selection triggered");
+        let has_value = state
+            .game
+            .session
+            .market_cell(cursor.0, cursor.1)
+            .is_none_or(|cell| MarketPosition::from(cell).numeric_value().is_some());
+        if !has_value {
+            // Game::set_par refuses this cell too; say why instead of a generic failure.
+            debug!(?cursor, "apply_par_selection refused cell without a value");
+            self.set_error_status("Selected cell has no par value".to_string());
+            return;
+        }
         if let Some(value) = state.apply_par_selection() {
             if let Some(corp) = state.current_corporation() {
                 info!(sym = %corp.sym, value, "Par price updated");
+                let value = self.currency.format(value);
                 self.state
-                    .set_status(format!("Par for {} set to ${}", corp.sym, value));
+                    .set_success(format!("Par for {} set to {value}", corp.sym));
             }
         } else {
             debug!(?cursor, "apply_par_selection failed");
-            self.state
-                .set_status("Unable to set par price at current cell".to_string());
+            self.set_error_status("Unable to set par price at current cell".to_string());
         }
     }
 
@@ -1122,20 +2180,29 @@ impl Tui18App {
                     price_display
                 };
                 info!(sym = %corp.sym, price = %price_display, "Stock price updated");
-                self.state.set_status(format!(
+                self.state.set_success(format!(
                     "Stock price for {} set to {}",
                     corp.sym, price_display
                 ));
             }
         } else {
-            self.state
-                .set_status("Unable to set stock price at current cell".to_string());
+            self.set_error_status("Unable to set stock price at current cell".to_string());
         }
     }
 
     fn apply_train_purchase(&mut self, state: &mut PlayState, selection: usize) {
-        if state.current_corporation().is_none() {
-            self.state.set_status("No corporation selected".to_string());
+        let Some(corp) = state.current_corporation() else {
+            self.state
+                .set_status(state.missing_corporation_message().to_string());
+            return;
+        };
+        if self.config.enforce_train_limit && state.at_train_limit() {
+            let limit = state.train_limit().unwrap_or_default();
+            let phase = state.phase_label();
+            self.set_error_status(format!(
+                "{} is at the phase {phase} train limit ({limit})",
+                corp.sym
+            ));
             return;
         }
 
@@ -1154,6 +2221,7 @@ impl Tui18App {
                 // couldn't get mutable ref, restore train back to pool
                 // since purchase_available_train already decremented, add back
                 if let Some(entry) = state
+                    .game
                     .session
                     .train_pool
                     .iter_mut()
@@ -1161,22 +2229,23 @@ impl Tui18App {
                 {
                     entry.remaining += 1;
                 }
-                self.state
-                    .set_status("Unable to allocate train to corporation".to_string());
+                self.set_error_status("Unable to allocate train to corporation".to_string());
                 return;
             };
 
             corp.trains.push(train);
             let idx = corp.trains.len().saturating_sub(1);
-            PlayState::update_corporation_revenue(corp);
+            corp.recompute_revenue();
             let sym = corp.sym.clone();
             (sym, idx)
         };
 
         info!(sym = %corp_sym, train = %train_name, price, "Train purchased");
         self.state.set_status(format!(
-            "{} buys {} train for ${}",
-            corp_sym, train_name, price
+            "{} buys {} train for {}",
+            corp_sym,
+            train_name,
+            self.currency.format(price)
         ));
 
         state.focus_owned();
@@ -1186,6 +2255,14 @@ impl Tui18App {
 
     fn handle_input(&mut self, event: Event) -> Result<()> {
         if let Event::Key(ref key) = event {
+            // Errors never time out; with a timeout set they last until the next key.
+            if self.config.status_timeout_ticks > 0 {
+                self.state.clear_error_status();
+            }
+            let filtering = self.screen == Screen::Browse && self.state.mode == Mode::Filter;
+            if key.code == KeyCode::Esc && !filtering && self.cancel_session_load() {
+                return Ok(());
+            }
             if self.handle_global_shortcut(key)? {
                 return Ok(());
             }
@@ -1193,6 +2270,9 @@ impl Tui18App {
         match self.screen {
             Screen::Menu => self.handle_menu_event(event)?,
             Screen::Browse => match event {
+                Event::Key(key) if self.skipped_overlay.is_some() => {
+                    self.handle_skipped_overlay_key(key);
+                }
                 Event::Key(key) => self.handle_key(key)?,
                 Event::Resize(_, _) => {}
                 Event::Mouse(_) => {}
@@ -1205,16 +2285,28 @@ impl Tui18App {
     }
 
     fn handle_global_shortcut(&mut self, key: &KeyEvent) -> Result<bool> {
-        if key.modifiers.is_empty() {
+        let mid_entry = self.screen == Screen::Play
+            && self
+                .play_state
+                .as_ref()
+                .is_some_and(|state| !state.at_commit_boundary());
+        if key.modifiers.is_empty() && !mid_entry {
             if let KeyCode::Char('u') = key.code {
                 self.apply_history_step(-1)?;
                 return Ok(true);
             }
         }
         if key.modifiers == KeyModifiers::CONTROL {
-            if let KeyCode::Char('r') = key.code {
-                self.apply_history_step(1)?;
-                return Ok(true);
+            match key.code {
+                KeyCode::Char('r') => {
+                    self.apply_history_step(1)?;
+                    return Ok(true);
+                }
+                KeyCode::Char('y') => {
+                    self.copy_status_line();
+                    return Ok(true);
+                }
+                _ => {}
             }
         }
         Ok(false)
@@ -1222,7 +2314,12 @@ impl Tui18App {
 
     fn handle_menu_event(&mut self, event: Event) -> Result<()> {
         if let Event::Key(key) = event {
+            if self.engine_switcher.is_some() {
+                self.handle_engine_switcher_key(key);
+                return Ok(());
+            }
             match key.code {
+                KeyCode::Char('e') => self.open_engine_switcher(),
                 KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
                     self.state.should_quit = true;
                 }
@@ -1236,7 +2333,12 @@ impl Tui18App {
                     0 => {
                         self.screen = Screen::Browse;
                         self.state.mode = Mode::Browse;
-                        self.state.set_status("Select a game to start".to_string());
+                        let message = if self.ruby_available {
+                            "Select a game to start"
+                        } else {
+                            RUBY_MISSING_MESSAGE
+                        };
+                        self.state.set_status(message.to_string());
                     }
                     1 => match self.refresh_saves() {
                         Ok(_) => {
@@ -1249,13 +2351,13 @@ impl Tui18App {
                             if self.saves.is_empty() {
                                 self.state.set_status("No saves available".to_string());
                             } else {
-                                self.state
-                                    .set_status("Select a save to continue".to_string());
+                                self.state.set_status(
+                                    "Select a save to continue (o shows the file)".to_string(),
+                                );
                             }
                         }
                         Err(err) => {
-                            self.state
-                                .set_status(format!("Failed to load saves: {err}"));
+                            self.set_error_status(format!("Failed to load saves: {err}"));
                         }
                     },
                     2 => {
@@ -1271,6 +2373,10 @@ impl Tui18App {
 
     fn handle_continue_event(&mut self, event: Event) -> Result<()> {
         match event {
+            Event::Key(key) if self.corrupt_overlay.is_some() => {
+                self.handle_corrupt_overlay_key(key)?;
+            }
+            Event::Key(key) if self.comparison.is_some() => self.handle_comparison_key(key),
             Event::Key(key) => {
                 let total = self.saves.len();
                 let visible = self.state.list_height.max(1);
@@ -1285,6 +2391,11 @@ impl Tui18App {
                     KeyCode::Char('k') | KeyCode::Up => {
                         self.state.move_continue_cursor(-1, total, visible);
                     }
+                    KeyCode::Char('c') if !self.corrupt_saves.is_empty() => {
+                        self.corrupt_overlay = Some(0);
+                    }
+                    KeyCode::Char('o') => self.reveal_selected_save(),
+                    KeyCode::Char('m') => self.mark_or_compare_save(),
                     KeyCode::Enter => {
                         if total == 0 {
                             self.state.set_status("No saves available".to_string());
@@ -1292,7 +2403,7 @@ impl Tui18App {
                             self.saves.get(self.state.continue_cursor).cloned()
                         {
                             if let Err(err) = self.load_save_entry(entry) {
-                                self.state.set_status(format!("Failed to load save: {err}"));
+                                self.set_error_status(format!("Failed to load save: {err}"));
                             }
                         }
                     }
@@ -1324,8 +2435,8 @@ impl Tui18App {
             KeyCode::Char('+') => {
                 if state.train_run_add_stop() {
                     if let Some(run) = state.train_run_state() {
-                        self.state
-                            .set_status(format!("Added stop; {} total stops", run.values.len()));
+                        let stops = run.values.len();
+                        self.set_info_status(format!("Added stop; {stops} total stops"));
                     }
                 } else {
                     self.state
@@ -1339,8 +2450,7 @@ impl Tui18App {
             KeyCode::Char('-') | KeyCode::Char('_') => {
                 state.train_run_remove_stop();
                 if let Some(run) = state.train_run_state() {
-                    self.state
-                        .set_status(format!("Removed stop; {} total stops", run.values.len()));
+                    self.set_info_status(format!("Removed stop; {} total stops", run.values.len()));
                 }
             }
             KeyCode::Backspace => {
@@ -1355,12 +2465,13 @@ impl Tui18App {
                 if let Some((corp_sym, train_name, total)) = state.apply_train_run() {
                     let summary = state.operating_round_summary();
                     self.state.set_status(format!(
-                        "Run saved for {} {}: ${} ({summary})",
-                        corp_sym, train_name, total
+                        "Run saved for {} {}: {} ({summary})",
+                        corp_sym,
+                        train_name,
+                        self.currency.format(total)
                     ));
                 } else {
-                    self.state
-                        .set_status("Unable to save train run".to_string());
+                    self.set_error_status("Unable to save train run".to_string());
                 }
             }
             KeyCode::Char(ch) if key.modifiers.is_empty() && ch.is_ascii_digit() => {
@@ -1378,6 +2489,10 @@ impl Tui18App {
     }
 
     fn prompt_new_game(&mut self) {
+        if !self.ruby_available {
+            self.set_error_status(RUBY_MISSING_MESSAGE.to_string());
+            return;
+        }
         if self.pending_session {
             self.state
                 .set_status("A session is already loading".to_string());
@@ -1430,16 +2545,14 @@ impl Tui18App {
             KeyCode::Backspace => {
                 self.state.filter.pop();
                 self.state.apply_filter();
-                self.state
-                    .set_status(format!("Filter: {}", self.state.filter));
+                self.set_info_status(format!("Filter: {}", self.state.filter));
             }
-            KeyCode::Char(c) => {
-                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
-                    self.state.filter.push(c);
-                    self.state.apply_filter();
-                    self.state
-                        .set_status(format!("Filter: {}", self.state.filter));
-                }
+            KeyCode::Char(c)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                self.state.filter.push(c);
+                self.state.apply_filter();
+                self.set_info_status(format!("Filter: {}", self.state.filter));
             }
             _ => {}
         }
@@ -1472,17 +2585,53 @@ impl Tui18App {
             }
             KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 if let Err(err) = self.reload_games() {
-                    self.state.set_status(format!("Reload failed: {err}"));
+                    self.set_error_status(format!("Reload failed: {err}"));
                 } else {
                     if let Err(err) = self.refresh_saves() {
-                        self.state
-                            .set_status(format!("Reloaded but failed to read saves: {err}"));
+                        self.set_error_status(format!("Reloaded but failed to read saves: {err}"));
                     } else {
                         self.state
-                            .set_status(format!("Reloaded {} games", self.state.filtered.len()));
+                            .set_success(format!("Reloaded {} games", self.state.filtered.len()));
                     }
                 }
             }
+            KeyCode::Char('r') if key.modifiers.is_empty() => {
+                if let Some(game) = self.state.current_game().cloned() {
+                    let message = if self.session_loader.invalidate(&game.id) {
+                        "will be re-extracted when opened"
+                    } else {
+                        "has no cached session"
+                    };
+                    self.state
+                        .set_status(format!("{} {message}", game.display_name()));
+                } else {
+                    self.state.set_status("No game selected".to_string());
+                }
+            }
+            KeyCode::Char('o') if key.modifiers.is_empty() => self.open_rules_url(),
+            KeyCode::Char('A') if self.state.game_cap > 0 => {
+                self.state.toggle_show_all_games();
+                let total = self.state.all_games.len();
+                self.set_info_status(if self.state.is_capped() {
+                    format!("Showing {} of {total} games", self.state.filtered.len())
+                } else {
+                    format!("Showing all {total} games")
+                });
+            }
+            KeyCode::Char('s') if key.modifiers.is_empty() => {
+                if self.skipped_games.is_empty() {
+                    self.state.set_status("No games were skipped".to_string());
+                } else {
+                    self.skipped_overlay = Some(0);
+                }
+            }
+            KeyCode::Char('y') if key.modifiers.is_empty() => {
+                if let Some(game) = self.state.current_game().cloned() {
+                    self.copy_bug_report_line(&game);
+                } else {
+                    self.state.set_status("No game selected".to_string());
+                }
+            }
             KeyCode::Enter => {
                 self.prompt_new_game();
             }
@@ -1491,10 +2640,203 @@ impl Tui18App {
         Ok(())
     }
 
+    /// Copies `game=<id> commit=<short> at=<time>` for bug reports, falling back to
+    /// showing it in the status line when no clipboard is reachable.
+    fn copy_bug_report_line(&mut self, game: &GameInfo) {
+        let commit = game.commit.as_deref().or(self.metadata.commit.as_deref());
+        let at = game.updated_at.or(self.metadata.updated_at);
+        let line = clipboard::bug_report_line(&game.id, commit, at);
+        self.copy_to_clipboard(&line);
+    }
+
+    /// Dumps the raw `GameSession` with the engine commit to the export directory, for
+    /// reporting extraction bugs.
+    fn export_session(&mut self, session: &GameSession) {
+        let commit = session
+            .info
+            .commit
+            .as_deref()
+            .or(self.metadata.commit.as_deref());
+        let result = self
+            .config
+            .export_dir()
+            .and_then(|dir| session.export_to(&dir, commit));
+        match result {
+            Ok(path) => {
+                info!(path = %path.display(), "Exported game session");
+                self.state
+                    .set_success(format!("Session exported to {}", path.display()));
+            }
+            Err(err) => {
+                error!(?err, "Session export failed");
+                self.set_error_status(format!("Session export failed: {err:#}"));
+            }
+        }
+    }
+
+    /// Copies the current status message (`Ctrl+Y`), e.g. to paste an error into a report.
+    fn copy_status_line(&mut self) {
+        match clipboard::status_payload(&self.state.status) {
+            Some(text) => self.copy_to_clipboard(&text),
+            None => self.state.set_status("Nothing to copy".to_string()),
+        }
+    }
+
+    /// Sends `text` to the configured clipboard and confirms in the status line; when no
+    /// clipboard is reachable the text itself is shown so it can be copied by hand.
+    fn copy_to_clipboard(&mut self, text: &str) {
+        match self.config.clipboard {
+            ClipboardMode::Osc52 => {
+                self.pending_clipboard = Some(clipboard::osc52_sequence(text));
+                self.state.set_success(format!("Copied: {text}"));
+            }
+            ClipboardMode::System => match clipboard::copy_with_system_tool(text) {
+                Ok(()) => self.state.set_success(format!("Copied: {text}")),
+                Err(err) => {
+                    warn!(?err, "Clipboard copy failed");
+                    self.set_error_status(format!("Clipboard unavailable ({err}): {text}"));
+                }
+            },
+            ClipboardMode::Off => self.state.set_status(text.to_string()),
+        }
+    }
+
+    /// Opens the selected game's rulebook with the system browser.
+    fn open_rules_url(&mut self) {
+        if !self.config.open_urls {
+            self.set_error_status("Opening links is disabled (open_urls = false)".to_string());
+            return;
+        }
+        let Some(game) = self.state.current_game() else {
+            self.state.set_status("No game selected".to_string());
+            return;
+        };
+        let Some(url) = game.rules_url.clone().filter(|url| !url.trim().is_empty()) else {
+            let message = format!("No rules link for {}", game.title);
+            self.set_error_status(message);
+            return;
+        };
+        match opener::open_target(&url) {
+            Ok(()) => {
+                info!(%url, "Opened rules link");
+                self.state.set_status(format!("Opened {url}"));
+            }
+            Err(err) => {
+                error!(?err, %url, "Failed to open rules link");
+                self.set_error_status(format!("Failed to open {url}: {err}"));
+            }
+        }
+    }
+
+    /// First press marks the highlighted save; pressing again on another save opens the
+    /// comparison, and on the marked save clears the mark.
+    fn mark_or_compare_save(&mut self) {
+        let Some(entry) = self.saves.get(self.state.continue_cursor).cloned() else {
+            self.state.set_status("No saves available".to_string());
+            return;
+        };
+        let Some(marked) = self.compare_mark.take() else {
+            self.state.set_status(format!(
+                "Marked {} for comparison; highlight another save and press m",
+                entry.name
+            ));
+            self.compare_mark = Some(entry);
+            return;
+        };
+        if marked.path == entry.path {
+            self.state.set_status("Comparison mark cleared".to_string());
+            return;
+        }
+        match self.compare_saves(&marked, &entry) {
+            Ok(comparison) => {
+                self.state.set_status(format!(
+                    "{} differences between {} and {}",
+                    comparison.rows.len(),
+                    marked.name,
+                    entry.name
+                ));
+                self.comparison = Some(comparison);
+            }
+            Err(err) => {
+                error!(?err, "Failed to compare saves");
+                self.set_error_status(format!("Failed to compare saves: {err}"));
+            }
+        }
+    }
+
+    /// Reads both saves' current states without touching the live session.
+    fn compare_saves(&self, left: &SaveEntry, right: &SaveEntry) -> Result<SaveComparison> {
+        let read = |entry: &SaveEntry| -> Result<PlayState> {
+            let payload = self.save_manager.load(entry)?;
+            from_value(payload.into_state())
+                .with_context(|| format!("deserialize play state from {}", entry.name))
+        };
+        let (left_state, right_state) = (read(left)?, read(right)?);
+        Ok(SaveComparison {
+            left_name: left.name.clone(),
+            right_name: right.name.clone(),
+            rows: left_state.compare(&right_state, &self.currency),
+            scroll: 0,
+        })
+    }
+
+    fn handle_comparison_key(&mut self, key: KeyEvent) {
+        let Some(comparison) = self.comparison.as_mut() else {
+            return;
+        };
+        let last = comparison.rows.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => self.comparison = None,
+            KeyCode::Char('j') | KeyCode::Down => {
+                comparison.scroll = (comparison.scroll + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                comparison.scroll = comparison.scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Shows the highlighted save file in the system file manager.
+    fn reveal_selected_save(&mut self) {
+        let Some(path) = self
+            .saves
+            .get(self.state.continue_cursor)
+            .map(|entry| entry.path.clone())
+        else {
+            self.state.set_status("No saves available".to_string());
+            return;
+        };
+        if !self.config.open_urls {
+            self.set_error_status(format!(
+                "Opening the file manager is disabled (open_urls = false); save is at {}",
+                path.display()
+            ));
+            return;
+        }
+        match opener::reveal_file(&path) {
+            Ok(()) => {
+                info!(path = %path.display(), "Revealed save file");
+                self.state.set_status(format!("Showing {}", path.display()));
+            }
+            Err(err) => {
+                error!(?err, path = %path.display(), "Failed to reveal save file");
+                self.set_error_status(format!(
+                    "Failed to open file manager ({err}); save is at {}",
+                    path.display()
+                ));
+            }
+        }
+    }
+
     fn handle_play_event(&mut self, event: Event) -> Result<()> {
         match event {
             Event::Key(key) => self.handle_play_key(key)?,
-            Event::Resize(_, _) => {}
+            Event::Resize(_, _) => {
+                if let Some(state) = self.play_state.as_mut() {
+                    state.snap_cursor_to_valid();
+                }
+            }
             Event::Mouse(_) => {}
             Event::FocusGained | Event::FocusLost | Event::Paste(_) => {}
         }
@@ -1520,10 +2862,10 @@ impl Tui18App {
                 KeyCode::End => prompt.move_end(),
                 KeyCode::Backspace => prompt.backspace(),
                 KeyCode::Delete => prompt.delete(),
-                KeyCode::Char(ch) => {
-                    if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
-                        prompt.insert(ch);
-                    }
+                KeyCode::Char(ch)
+                    if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+                {
+                    prompt.insert(ch);
                 }
                 _ => {}
             }
@@ -1563,15 +2905,257 @@ impl Tui18App {
         Ok(())
     }
 
+    /// Opens the yes/no overlay; the action only runs once the user accepts. With
+    /// `confirm_destructive` off the action runs on `state` straight away.
+    fn request_confirmation(
+        &mut self,
+        state: &mut PlayState,
+        action: ConfirmAction,
+        message: String,
+    ) {
+        if !self.config.confirm_destructive {
+            self.run_destructive_action(state, action);
+            return;
+        }
+        self.state.set_status(format!("{message} (y/n)"));
+        self.confirm = Some(ConfirmModal { action, message });
+    }
+
+    fn handle_confirm_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(accepted) = ConfirmModal::answer(&key) else {
+            return Ok(());
+        };
+        let Some(modal) = self.confirm.take() else {
+            return Ok(());
+        };
+        if !accepted {
+            self.state.set_status("Action cancelled".to_string());
+            return Ok(());
+        }
+        self.apply_confirmed_action(modal.action)
+    }
+
+    fn apply_confirmed_action(&mut self, action: ConfirmAction) -> Result<()> {
+        let Some(mut state) = self.play_state.take() else {
+            self.state.set_status("No session loaded".to_string());
+            return Ok(());
+        };
+        self.run_destructive_action(&mut state, action);
+        self.store_play_state(state)
+    }
+
+    fn run_destructive_action(&mut self, state: &mut PlayState, action: ConfirmAction) {
+        match action {
+            ConfirmAction::ResetCorporation => match state.reset_current_corporation() {
+                Some((sym, returned)) => {
+                    info!(sym = %sym, returned, "Corporation reset");
+                    self.state
+                        .set_status(format!("{sym} reset; {returned} train(s) returned to pool"));
+                }
+                None => self
+                    .state
+                    .set_status(state.missing_corporation_message().to_string()),
+            },
+            ConfirmAction::ClearMarketState => {
+                let cleared = state.clear_all_market_state(false);
+                info!(cleared, "Market state cleared");
+                self.state.set_status(match cleared {
+                    0 => "No pars or share prices to clear".to_string(),
+                    1 => "Cleared par and share price for 1 corporation".to_string(),
+                    count => format!("Cleared pars and share prices for {count} corporations"),
+                });
+            }
+            ConfirmAction::RemoveCustomPhase => match state.remove_last_custom_phase() {
+                Some(phase) => {
+                    info!(phase = %phase.name, "Custom phase removed");
+                    self.state.set_status(format!(
+                        "Removed phase {}; now in {}",
+                        phase.name,
+                        state.phase_label()
+                    ));
+                }
+                None => self
+                    .state
+                    .set_status("No custom phase to remove".to_string()),
+            },
+        }
+    }
+
+    /// Puts an edited play state back and records it in the active save's history.
+    fn store_play_state(&mut self, state: PlayState) -> Result<()> {
+        let result = self.persist_active_session(&state);
+        self.play_state = Some(state);
+        if let Err(err) = &result {
+            error!(?err, "Auto-save failed");
+            self.set_error_status(format!("Auto-save failed: {err}"));
+        }
+        result
+    }
+
+    fn handle_phase_prompt_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(prompt) = self.phase_prompt.as_mut() else {
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.phase_prompt = None;
+                self.state.set_status("Add phase cancelled".to_string());
+            }
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Char(ch)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                prompt.input.push(ch);
+            }
+            KeyCode::Enter => {
+                let Some((name, rounds)) = prompt.parse(self.config.default_operating_rounds)
+                else {
+                    self.state.set_status("Enter a phase name".to_string());
+                    return Ok(());
+                };
+                self.phase_prompt = None;
+                let Some(mut state) = self.play_state.take() else {
+                    self.state.set_status("No session loaded".to_string());
+                    return Ok(());
+                };
+                state.add_custom_phase(name, rounds);
+                info!(phase = %state.phase_label(), rounds, "Custom phase added");
+                let label = state.phase_label();
+                self.state
+                    .set_status(format!("Added phase {label} with {rounds} OR(s)"));
+                return self.store_play_state(state);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_share_prompt_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(prompt) = self.share_prompt.as_mut() else {
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.share_prompt = None;
+                self.state.set_status("Share change cancelled".to_string());
+            }
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Char(ch)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                prompt.input.push(ch);
+            }
+            KeyCode::Enter => {
+                let Some((owner, sell)) = prompt.parse() else {
+                    self.state
+                        .set_status("Enter a shareholder name".to_string());
+                    return Ok(());
+                };
+                self.share_prompt = None;
+                let Some(mut state) = self.play_state.take() else {
+                    self.state.set_status("No session loaded".to_string());
+                    return Ok(());
+                };
+                let corp = state.corporation_index;
+                let sym = state.current_corporation().map(|corp| corp.sym.clone());
+                let sym = sym.unwrap_or_default();
+                let message = if sell {
+                    match state.game.sell_share(corp, &owner) {
+                        Ok(pct) => format!("{owner} sells a {sym} share, now holds {pct}%"),
+                        Err(ShareError::NotHeld) => format!("{owner} holds no {sym} shares"),
+                        Err(ShareError::PresidentsCertificate) => {
+                            format!("{owner} keeps the {sym} president's certificate")
+                        }
+                        Err(err) => err.to_string(),
+                    }
+                } else {
+                    match state.game.buy_share(corp, &owner) {
+                        Some(pct) => format!("{owner} now holds {pct}% of {sym}"),
+                        None => format!("{sym} has no shares left"),
+                    }
+                };
+                info!(%owner, sell, corp = %sym, "Shares changed");
+                self.state.set_status(message);
+                return self.store_play_state(state);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_phase_picker_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = self.phase_picker.as_mut() else {
+            return Ok(());
+        };
+        let count = self
+            .play_state
+            .as_ref()
+            .map(PlayState::phase_count)
+            .unwrap_or(0);
+        let last = count.saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('g') => {
+                self.phase_picker = None;
+                self.state
+                    .set_status("Phase selection cancelled".to_string());
+            }
+            KeyCode::Char('j') | KeyCode::Down => picker.cursor = (picker.cursor + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => picker.cursor = picker.cursor.saturating_sub(1),
+            KeyCode::Home => picker.cursor = 0,
+            KeyCode::End | KeyCode::Char('G') => picker.cursor = last,
+            KeyCode::Enter => {
+                let target = picker.cursor;
+                self.phase_picker = None;
+                let Some(mut state) = self.play_state.take() else {
+                    self.state.set_status("No session loaded".to_string());
+                    return Ok(());
+                };
+                state.set_phase_index(target);
+                state.ensure_revenue_cursor_visible();
+                self.state
+                    .set_status(format!("Phase changed to {}", state.phase_label()));
+                return self.store_play_state(state);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn handle_play_key(&mut self, key: KeyEvent) -> Result<()> {
         let Some(mut state) = self.play_state.take() else {
-            if matches!(key.code, KeyCode::Esc) {
+            if matches!(key.code, KeyCode::Esc) && !self.cancel_session_load() {
                 self.screen = Screen::Browse;
-                self.pending_session = false;
             }
             return Ok(());
         };
 
+        if self.debug && key.code == KeyCode::Char('I') {
+            self.open_inspector(&state);
+            self.play_state = Some(state);
+            return Ok(());
+        }
+
+        if state.mode() == PlayMode::Idle && key.code == KeyCode::Char('S') {
+            self.open_accessible_summary(&state);
+            self.play_state = Some(state);
+            return Ok(());
+        }
+
+        if state.mode() == PlayMode::Idle && key.code == KeyCode::Char('R') {
+            self.play_state = Some(state);
+            return self.start_replay();
+        }
+
+        if state.mode() == PlayMode::Idle && key.code == KeyCode::Char('r') {
+            self.play_state = Some(state);
+            self.refresh_active_session();
+            return Ok(());
+        }
+
         let mut result = match state.mode() {
             PlayMode::Idle => self.handle_play_idle_key(&mut state, key),
             PlayMode::ParSelect => self.handle_par_select_key(&mut state, key),
@@ -1581,12 +3165,11 @@ impl Tui18App {
         };
 
         if self.screen == Screen::Play {
-            if result.is_ok() {
+            if result.is_ok() && state.at_commit_boundary() {
                 if let Err(err) = self.persist_active_session(&state) {
                     let err_msg = err.to_string();
                     error!(error = %err_msg, "Auto-save failed");
-                    self.state
-                        .set_status(format!("Auto-save failed: {err_msg}"));
+                    self.set_error_status(format!("Auto-save failed: {err_msg}"));
                     result = Err(anyhow!(err_msg));
                 }
             }
@@ -1596,6 +3179,49 @@ impl Tui18App {
         result
     }
 
+    /// Shows the linear text summary of the game in a plain overlay for screen readers.
+    fn open_accessible_summary(&mut self, state: &PlayState) {
+        self.inspector = Some(InspectorModal {
+            title: "Summary".to_string(),
+            lines: state.accessible_summary(&self.currency),
+            scroll: 0,
+        });
+    }
+
+    fn open_inspector(&mut self, state: &PlayState) {
+        match state.inspect_focus() {
+            Some((title, value)) => {
+                self.inspector = Some(InspectorModal {
+                    title: format!("Inspect: {title}"),
+                    lines: value_lines(&value),
+                    scroll: 0,
+                });
+            }
+            None => self.state.set_status("Nothing to inspect here".to_string()),
+        }
+    }
+
+    fn handle_inspector_key(&mut self, key: KeyEvent) {
+        let Some(inspector) = self.inspector.as_mut() else {
+            return;
+        };
+        let last = inspector.lines.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('I') => self.inspector = None,
+            KeyCode::Char('j') | KeyCode::Down => {
+                inspector.scroll = (inspector.scroll + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                inspector.scroll = inspector.scroll.saturating_sub(1);
+            }
+            KeyCode::PageDown => inspector.scroll = (inspector.scroll + 10).min(last),
+            KeyCode::PageUp => inspector.scroll = inspector.scroll.saturating_sub(10),
+            KeyCode::Home | KeyCode::Char('g') => inspector.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => inspector.scroll = last,
+            _ => {}
+        }
+    }
+
     fn handle_play_idle_key(&mut self, state: &mut PlayState, key: KeyEvent) -> Result<()> {
         let mut hide_banner = false;
         match key.code {
@@ -1603,20 +3229,38 @@ impl Tui18App {
                 if let Err(err) = self.persist_active_session(state) {
                     let err_msg = err.to_string();
                     error!(error = %err_msg, "Auto-save failed on exit");
-                    self.state
-                        .set_status(format!("Auto-save failed: {err_msg}"));
+                    self.set_error_status(format!("Auto-save failed: {err_msg}"));
                 }
                 self.screen = Screen::Browse;
                 self.play_state = None;
                 self.pending_session = false;
                 self.state.mode = Mode::Browse;
                 info!("Play session closed");
-                self.state.set_status("Returned to game list".to_string());
+                let message = match self.active_save.as_ref().and_then(SaveEntry::play_duration) {
+                    Some(duration) => format!(
+                        "Returned to game list (played over {})",
+                        format_play_duration(duration)
+                    ),
+                    None => "Returned to game list".to_string(),
+                };
+                self.state.set_status(message);
             }
             KeyCode::Char('q') if key.modifiers.is_empty() => {
                 self.state.should_quit = true;
                 hide_banner = true;
             }
+            KeyCode::Char('J') | KeyCode::Char('K') | KeyCode::Char('H') | KeyCode::Char('L')
+                if state.revenue_view_enabled() =>
+            {
+                let (rows, cols) = match key.code {
+                    KeyCode::Char('J') => (1, 0),
+                    KeyCode::Char('K') => (-1, 0),
+                    KeyCode::Char('H') => (0, -1),
+                    _ => (0, 1),
+                };
+                state.page_revenue_cursor(rows, cols);
+                hide_banner = true;
+            }
             KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Down => {
                 if state.revenue_view_enabled() {
                     state.move_revenue_cursor(1, 0);
@@ -1633,17 +3277,13 @@ impl Tui18App {
                     state.move_corporation(-1);
                 }
             }
-            KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Left => {
-                if state.revenue_view_enabled() {
-                    state.move_revenue_cursor(0, -1);
-                    hide_banner = true;
-                }
+            KeyCode::Char('h') | KeyCode::Left if state.revenue_view_enabled() => {
+                state.move_revenue_cursor(0, -1);
+                hide_banner = true;
             }
-            KeyCode::Char('l') | KeyCode::Char('L') | KeyCode::Right => {
-                if state.revenue_view_enabled() {
-                    state.move_revenue_cursor(0, 1);
-                    hide_banner = true;
-                }
+            KeyCode::Char('l') | KeyCode::Right if state.revenue_view_enabled() => {
+                state.move_revenue_cursor(0, 1);
+                hide_banner = true;
             }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::PageDown => {
                 state.move_corporation(5)
@@ -1661,23 +3301,105 @@ impl Tui18App {
                 } else {
                     "Stock market view enabled"
                 };
-                self.state.set_status(message.to_string());
+                self.set_info_status(message.to_string());
                 hide_banner = true;
             }
             KeyCode::Char('.') | KeyCode::Char('>') => {
                 if state.advance_operating_round() {
                     let summary = state.operating_round_summary();
-                    self.state.set_status(format!("Switched to {summary}"));
+                    self.set_info_status(format!("Switched to {summary}"));
                 } else {
                     self.state
                         .set_status("Already at final operating round".to_string());
                 }
                 hide_banner = true;
             }
+            KeyCode::Char('g') if key.modifiers.is_empty() => {
+                if state.phase_count() == 0 {
+                    self.state.set_status("No phase data available".to_string());
+                } else {
+                    self.phase_picker = Some(PhasePickerModal {
+                        cursor: state.current_phase_index(),
+                        offset: 0,
+                    });
+                    self.state.set_status("Select a phase".to_string());
+                }
+                hide_banner = true;
+            }
+            KeyCode::Char('y') if key.modifiers.is_empty() => {
+                let game = state.game.session.info.clone();
+                self.copy_bug_report_line(&game);
+                hide_banner = true;
+            }
+            KeyCode::Char('E') => {
+                self.export_session(&state.game.session);
+                hide_banner = true;
+            }
+            KeyCode::Char('v') => {
+                self.corp_names = self.corp_names.toggled();
+                let message = match self.corp_names {
+                    CorpNameDisplay::Full => "Corporation list shows full names",
+                    CorpNameDisplay::Abbreviated => "Corporation list shows symbols only",
+                };
+                self.state.set_status(message.to_string());
+            }
+            KeyCode::Char('B') => {
+                let message = if state.toggle_title_banner_pin() {
+                    "Title banner pinned"
+                } else {
+                    "Title banner unpinned"
+                };
+                self.state.set_status(message.to_string());
+            }
+            KeyCode::Char('e') => {
+                if !state.has_corporations() {
+                    self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
+                } else if state.next_unparred_corporation().is_some() {
+                    let sym = state.current_corporation().map(|corp| corp.sym.clone());
+                    self.state
+                        .set_status(format!("{} needs a par", sym.unwrap_or_default()));
+                } else {
+                    self.state
+                        .set_status("Every corporation has a par".to_string());
+                }
+            }
+            KeyCode::Char('o') if key.modifiers.is_empty() => {
+                match state.current_corporation() {
+                    Some(corp) => {
+                        self.share_prompt = Some(SharePromptModal::default());
+                        self.state.set_status(format!(
+                            "{} shareholder: name buys a share, -name sells one",
+                            corp.sym
+                        ));
+                    }
+                    None => self
+                        .state
+                        .set_status(state.missing_corporation_message().to_string()),
+                }
+                hide_banner = true;
+            }
+            KeyCode::Char('}') => {
+                self.phase_prompt = Some(PhasePromptModal::default());
+                self.state
+                    .set_status("New phase: name and optional OR count".to_string());
+                hide_banner = true;
+            }
+            KeyCode::Char('{') => {
+                match state.game.phases.iter().rev().find(|phase| phase.custom) {
+                    Some(phase) => {
+                        let message = format!("Remove custom phase {}?", phase.name);
+                        self.request_confirmation(state, ConfirmAction::RemoveCustomPhase, message);
+                    }
+                    None => self
+                        .state
+                        .set_status("No custom phase to remove".to_string()),
+                }
+                hide_banner = true;
+            }
             KeyCode::Char(',') | KeyCode::Char('<') => {
                 if state.retreat_operating_round() {
                     let summary = state.operating_round_summary();
-                    self.state.set_status(format!("Switched to {summary}"));
+                    self.set_info_status(format!("Switched to {summary}"));
                 } else {
                     self.state
                         .set_status("Already at first operating round".to_string());
@@ -1715,9 +3437,8 @@ impl Tui18App {
                 hide_banner = true;
             }
             KeyCode::Char('a') | KeyCode::Char('A') => {
-                if state.session.corporations.is_empty() {
-                    self.state
-                        .set_status("No corporations available for operating round".to_string());
+                if !state.has_corporations() {
+                    self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
                 } else {
                     state.add_operating_round();
                     let label = format!("OR{}", state.revenue_cursor_or + 1);
@@ -1726,67 +3447,114 @@ impl Tui18App {
                 }
                 hide_banner = true;
             }
-            KeyCode::Char('+') | KeyCode::Char('=') => {
-                if state.revenue_view_enabled() {
-                    state.adjust_current_revenue_value(10);
-                    if let Some((corp, or_idx)) = state.current_revenue_context() {
-                        let value = state.current_revenue_value().unwrap_or_default();
-                        self.state.set_status(format!(
-                            "{} {} payout increased to {}",
-                            corp.sym,
-                            format!("OR{}", or_idx + 1),
-                            format_currency(value)
-                        ));
+            KeyCode::Char('D') => {
+                if !state.has_corporations() {
+                    self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
+                } else {
+                    let source = state.revenue_cursor_or + 1;
+                    match state.add_operating_round_copying_current() {
+                        Some(index) => self.state.set_status(format!(
+                            "Added operating round OR{} copied from OR{source}",
+                            index + 1
+                        )),
+                        None => self
+                            .state
+                            .set_status("No operating round to copy".to_string()),
                     }
-                    hide_banner = true;
                 }
+                hide_banner = true;
             }
-            KeyCode::Char('-') => {
-                if state.revenue_view_enabled() {
-                    state.adjust_current_revenue_value(-10);
-                    if let Some((corp, or_idx)) = state.current_revenue_context() {
-                        let value = state.current_revenue_value().unwrap_or_default();
-                        self.state.set_status(format!(
-                            "{} {} payout reduced to {}",
-                            corp.sym,
-                            format!("OR{}", or_idx + 1),
-                            format_currency(value)
-                        ));
-                    }
-                    hide_banner = true;
+            KeyCode::F(view @ 1..=4) => {
+                self.jump_to_play_view(state, view);
+                hide_banner = true;
+            }
+            // Digits set payout percentages in the revenue view; jumps only use them outside it.
+            KeyCode::Char(c @ '1'..='4') if !state.revenue_view_enabled() => {
+                self.jump_to_play_view(state, c as u8 - b'0');
+                hide_banner = true;
+            }
+            KeyCode::Char('+' | '=' | '-' | '0' | '$' | '1'..='6')
+                if state.revenue_view_enabled() && !state.has_corporations() =>
+            {
+                self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
+                hide_banner = true;
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') if state.revenue_view_enabled() => {
+                state.adjust_current_revenue_value(10);
+                if let Some((corp, or_idx)) = state.current_revenue_context() {
+                    let value = state.current_revenue_value().unwrap_or_default();
+                    self.set_info_status(format!(
+                        "{} OR{} payout increased to {}",
+                        corp.sym,
+                        or_idx + 1,
+                        self.currency.format(value)
+                    ));
+                }
+                hide_banner = true;
+            }
+            KeyCode::Char('-') if state.revenue_view_enabled() => {
+                state.adjust_current_revenue_value(-10);
+                if let Some((corp, or_idx)) = state.current_revenue_context() {
+                    let value = state.current_revenue_value().unwrap_or_default();
+                    self.set_info_status(format!(
+                        "{} OR{} payout reduced to {}",
+                        corp.sym,
+                        or_idx + 1,
+                        self.currency.format(value)
+                    ));
                 }
+                hide_banner = true;
             }
-            KeyCode::Char('0') => {
-                if state.revenue_view_enabled() {
-                    state.set_current_revenue_value(0);
+            KeyCode::Char('0') if state.revenue_view_enabled() => {
+                state.set_current_revenue_value(0);
+                if let Some((corp, or_idx)) = state.current_revenue_context() {
+                    self.state
+                        .set_status(format!("{} OR{} payout cleared", corp.sym, or_idx + 1));
+                }
+                hide_banner = true;
+            }
+            KeyCode::Char(c) if ('1'..='6').contains(&c) && state.revenue_view_enabled() => {
+                let digit = (c as u8 - b'0') as i32;
+                if let Some(value) = state.revenue_preset_value(digit) {
+                    state.set_current_revenue_value(value);
                     if let Some((corp, or_idx)) = state.current_revenue_context() {
-                        self.state.set_status(format!(
-                            "{} {} payout cleared",
+                        let mut message = format!(
+                            "{} OR{} payout set to {}",
                             corp.sym,
-                            format!("OR{}", or_idx + 1)
-                        ));
+                            or_idx + 1,
+                            self.currency.format(value)
+                        );
+                        if state.revenue_preset_mode == RevenuePresetMode::Percent {
+                            message.push_str(&format!(" ({}%)", digit * 10));
+                        }
+                        self.state.set_status(message);
                     }
-                    hide_banner = true;
                 }
+                hide_banner = true;
             }
-            KeyCode::Char(c) if ('1'..='6').contains(&c) => {
-                if state.revenue_view_enabled() {
-                    let percent = (c as u8 - b'0') as i32 * 10;
-                    if let Some(base) = state.current_revenue_base() {
-                        let value = base * percent / 100;
-                        state.set_current_revenue_value(value);
-                        if let Some((corp, or_idx)) = state.current_revenue_context() {
-                            self.state.set_status(format!(
-                                "{} {} payout set to {} ({}%)",
-                                corp.sym,
-                                format!("OR{}", or_idx + 1),
-                                format_currency(value),
-                                percent
-                            ));
-                        }
-                    }
-                    hide_banner = true;
+            KeyCode::Char('$') if state.revenue_view_enabled() => {
+                let copied = state.copy_market_price_to_revenue();
+                if let Some((corp, or_idx)) = state.current_revenue_context() {
+                    let message = match copied {
+                        Some(price) => format!(
+                            "{} OR{} payout set to share price {}",
+                            corp.sym,
+                            or_idx + 1,
+                            self.currency.format(price)
+                        ),
+                        None => format!("{} has no share price to copy", corp.sym),
+                    };
+                    self.state.set_status(message);
                 }
+                hide_banner = true;
+            }
+            KeyCode::Char('%') if state.revenue_view_enabled() => {
+                let message = match state.toggle_revenue_preset_mode() {
+                    RevenuePresetMode::Percent => "1-6 now set 10%-60% of last revenue",
+                    RevenuePresetMode::Absolute => "1-6 now set $10-$60",
+                };
+                self.state.set_status(message.to_string());
+                hide_banner = true;
             }
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 self.begin_par_selection(state);
@@ -1796,6 +3564,25 @@ impl Tui18App {
                 self.begin_train_mode(state);
                 hide_banner = true;
             }
+            KeyCode::Char('X') => {
+                if let Some(corp) = state.current_corporation() {
+                    let message = format!("Reset {} (par, price, trains)?", corp.sym);
+                    self.request_confirmation(state, ConfirmAction::ResetCorporation, message);
+                } else {
+                    self.state
+                        .set_status(state.missing_corporation_message().to_string());
+                }
+                hide_banner = true;
+            }
+            KeyCode::Char('C') => {
+                if state.has_corporations() {
+                    let message = "Clear every par and share price (trains stay)?".to_string();
+                    self.request_confirmation(state, ConfirmAction::ClearMarketState, message);
+                } else {
+                    self.state.set_status(NO_CORPORATIONS_MESSAGE.to_string());
+                }
+                hide_banner = true;
+            }
             KeyCode::Enter => {
                 if let Some(corp) = state.current_corporation() {
                     if corp.par_value.is_some() {
@@ -1804,7 +3591,8 @@ impl Tui18App {
                         self.begin_par_selection(state);
                     }
                 } else {
-                    self.state.set_status("No corporation selected".to_string());
+                    self.state
+                        .set_status(state.missing_corporation_message().to_string());
                 }
                 hide_banner = true;
             }
@@ -1816,7 +3604,20 @@ impl Tui18App {
         Ok(())
     }
 
+    fn toggle_market_rulers(&mut self, state: &mut PlayState) {
+        let message = if state.toggle_market_rulers() {
+            "Coordinate rulers shown"
+        } else {
+            "Coordinate rulers hidden"
+        };
+        self.set_info_status(message.to_string());
+    }
+
     fn handle_par_select_key(&mut self, state: &mut PlayState, key: KeyEvent) -> Result<()> {
+        // Any other key (a cursor move included) breaks a pending double press.
+        if key.code != KeyCode::Enter {
+            state.last_market_key = None;
+        }
         match key.code {
             KeyCode::Esc => {
                 debug!(cursor = ?state.market_cursor(), "Par selection cancelled");
@@ -1829,6 +3630,13 @@ impl Tui18App {
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 self.apply_par_selection(state);
             }
+            KeyCode::Char('#') => self.toggle_market_rulers(state),
+            KeyCode::Char('0') | KeyCode::Home => {
+                state.jump_market_column(false);
+            }
+            KeyCode::Char('$') | KeyCode::End => {
+                state.jump_market_column(true);
+            }
             KeyCode::Enter => {
                 let corp_data = state
                     .current_corporation()
@@ -1843,33 +3651,62 @@ impl Tui18App {
                     par_set,
                     "Par selection enter pressed"
                 );
-                if par_set {
+                let cell = state.market_cursor();
+                let double = self.config.double_key_confirm
+                    && state.register_market_key(KeyCode::Enter, cell, Instant::now());
+                if par_set && !double {
                     debug!(?corp_sym, "Par already set; prompting for update");
-                    self.state
-                        .set_status("Par already set; press 'p' to update".to_string());
+                    let message = if self.config.double_key_confirm {
+                        "Par already set; press Enter again or 'p' to update"
+                    } else {
+                        "Par already set; press 'p' to update"
+                    };
+                    self.state.set_status(message.to_string());
                 } else {
                     self.apply_par_selection(state);
                 }
             }
-            KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Down => {
-                state.move_market_cursor(1, 0)
-            }
-            KeyCode::Char('k') | KeyCode::Char('K') | KeyCode::Up => {
-                state.move_market_cursor(-1, 0)
-            }
-            KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Left => {
-                state.move_market_cursor(0, -1)
-            }
-            KeyCode::Char('l') | KeyCode::Char('L') | KeyCode::Right => {
-                state.move_market_cursor(0, 1)
-            }
+            KeyCode::Char('j') | KeyCode::Down => state.move_market_cursor(1, 0),
+            KeyCode::Char('k') | KeyCode::Up => state.move_market_cursor(-1, 0),
+            KeyCode::Char('h') | KeyCode::Left => state.move_market_cursor(0, -1),
+            KeyCode::Char('l') | KeyCode::Right => state.move_market_cursor(0, 1),
+            KeyCode::Char('J') => state.page_market_cursor(1, 0),
+            KeyCode::Char('K') => state.page_market_cursor(-1, 0),
+            KeyCode::Char('H') => state.page_market_cursor(0, -1),
+            KeyCode::Char('L') => state.page_market_cursor(0, 1),
             _ => {}
         }
         Ok(())
     }
 
     fn handle_price_select_key(&mut self, state: &mut PlayState, key: KeyEvent) -> Result<()> {
+        // Any key other than a digit ends the typed value; Esc only clears it.
+        let typed = state.price_input.take();
+        // As in par selection, any other key breaks a pending double press.
+        if key.code != KeyCode::Enter {
+            state.last_market_key = None;
+        }
         match key.code {
+            KeyCode::Esc if typed.is_some() => {
+                self.state.set_status("Price entry cleared".to_string());
+            }
+            KeyCode::Char(ch)
+                if ch.is_ascii_digit()
+                    && key.modifiers.is_empty()
+                    && (ch != '0' || typed.is_some()) =>
+            {
+                state.price_input = typed;
+                match state.type_price_digit(ch) {
+                    Some((value, true)) => self.state.set_status(format!(
+                        "{} selected · Enter to set",
+                        self.currency.format(value)
+                    )),
+                    Some((value, false)) => self
+                        .state
+                        .set_status(format!("No market cell at {}", self.currency.format(value))),
+                    None => {}
+                }
+            }
             KeyCode::Esc => {
                 state.exit_market();
                 self.state
@@ -1881,21 +3718,24 @@ impl Tui18App {
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 self.begin_par_selection(state);
             }
-            KeyCode::Enter => {
-                self.apply_price_selection(state);
-            }
-            KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Down => {
-                state.move_market_cursor(1, 0)
-            }
-            KeyCode::Char('k') | KeyCode::Char('K') | KeyCode::Up => {
-                state.move_market_cursor(-1, 0)
+            KeyCode::Char('#') => self.toggle_market_rulers(state),
+            KeyCode::Char('0') | KeyCode::Home => {
+                state.jump_market_column(false);
             }
-            KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Left => {
-                state.move_market_cursor(0, -1)
+            KeyCode::Char('$') | KeyCode::End => {
+                state.jump_market_column(true);
             }
-            KeyCode::Char('l') | KeyCode::Char('L') | KeyCode::Right => {
-                state.move_market_cursor(0, 1)
+            KeyCode::Enter => {
+                self.apply_price_selection(state);
             }
+            KeyCode::Char('j') | KeyCode::Down => state.move_market_cursor(1, 0),
+            KeyCode::Char('k') | KeyCode::Up => state.move_market_cursor(-1, 0),
+            KeyCode::Char('h') | KeyCode::Left => state.move_market_cursor(0, -1),
+            KeyCode::Char('l') | KeyCode::Right => state.move_market_cursor(0, 1),
+            KeyCode::Char('J') => state.page_market_cursor(1, 0),
+            KeyCode::Char('K') => state.page_market_cursor(-1, 0),
+            KeyCode::Char('H') => state.page_market_cursor(0, -1),
+            KeyCode::Char('L') => state.page_market_cursor(0, 1),
             _ => {}
         }
         Ok(())
@@ -1938,10 +3778,31 @@ impl Tui18App {
                         .set_status("No owned train selected to rust".to_string());
                 }
             }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                let selected =
+                    (state.train_focus() == TrainFocus::Owned).then(|| state.owned_train_cursor());
+                match selected.and_then(|index| state.scrap_train(index, true)) {
+                    Some(train) => {
+                        state.sync_pool_cursor();
+                        if let Some(corp) = state.current_corporation() {
+                            self.state.set_status(format!(
+                                "{} returns {} train to the pool; revenue now {}",
+                                corp.sym,
+                                train.name,
+                                self.currency.format(corp.last_revenue)
+                            ));
+                        }
+                    }
+                    None => self
+                        .state
+                        .set_status("No owned train selected to return".to_string()),
+                }
+            }
             KeyCode::Char('d') | KeyCode::Char('D') => {
                 match state.apply_revenue_action(RevenueAction::Dividend) {
                     Ok(outcome) => {
-                        let payouts = share_payout_line(outcome.total);
+                        let corp = state.current_corporation();
+                        let payouts = dividend_payout_line(&self.currency, outcome.total, corp);
                         let verb = match outcome.action {
                             RevenueAction::Dividend => "pays",
                             RevenueAction::Withhold => "withholds",
@@ -1955,8 +3816,8 @@ impl Tui18App {
                             "{} {} {} dividend - price {} ({}) | {}",
                             outcome.corp_sym,
                             verb,
-                            format_currency(outcome.total),
-                            outcome.price_label,
+                            self.currency.format(outcome.total),
+                            display_price_label(&outcome.position.raw),
                             movement,
                             payouts
                         ));
@@ -1977,8 +3838,8 @@ impl Tui18App {
                         self.state.set_status(format!(
                             "{} withholds {} - price {} ({})",
                             outcome.corp_sym,
-                            format_currency(outcome.total),
-                            outcome.price_label,
+                            self.currency.format(outcome.total),
+                            display_price_label(&outcome.position.raw),
                             movement
                         ));
                     }
@@ -2036,7 +3897,14 @@ impl Tui18App {
                 }
             },
             KeyCode::Tab => {
-                state.toggle_train_focus();
+                let switched = state.toggle_train_focus();
+                if !switched {
+                    let message = match state.train_focus {
+                        TrainFocus::Pool => "No owned trains to switch to",
+                        TrainFocus::Owned => "No trains left in the pool to switch to",
+                    };
+                    self.state.set_status(message.to_string());
+                }
             }
             _ => {}
         }
@@ -2049,28 +3917,32 @@ impl Tui18App {
         key: KeyEvent,
     ) -> Result<()> {
         match key.code {
-            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('t') | KeyCode::Char('T') => {
+            // The first Esc clears a typed filter; the next one closes the modal.
+            KeyCode::Esc if state.clear_purchase_query() => {}
+            KeyCode::Esc => {
                 state.close_train_purchase_modal();
                 self.state
                     .set_status("Train purchase cancelled".to_string());
             }
-            KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Down => {
+            KeyCode::Down => {
                 state.move_purchase_modal_cursor(1);
             }
-            KeyCode::Char('k') | KeyCode::Char('K') | KeyCode::Up => {
+            KeyCode::Up => {
                 state.move_purchase_modal_cursor(-1);
             }
+            KeyCode::Backspace => {
+                state.pop_purchase_query();
+            }
+            KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                state.push_purchase_query(ch);
+            }
             KeyCode::Enter => {
-                if let Some(selection_idx) = state
-                    .train_purchase_modal
-                    .as_ref()
-                    .map(|modal| modal.cursor)
-                {
+                if let Some(selection_idx) = state.purchase_modal_selection() {
                     state.close_train_purchase_modal();
                     self.apply_train_purchase(state, selection_idx);
                 } else {
                     self.state
-                        .set_status("No train available for purchase".to_string());
+                        .set_status("No train matches the filter".to_string());
                 }
             }
             _ => {}
@@ -2088,6 +3960,22 @@ impl Tui18App {
         if let Some(prompt) = &self.name_prompt {
             self.render_name_prompt(frame, prompt);
         }
+        if let Some(prompt) = &self.phase_prompt {
+            self.render_phase_prompt(frame, prompt);
+        }
+        if let Some(prompt) = &self.share_prompt {
+            self.render_share_prompt(frame, prompt);
+        }
+        if let (Some(picker), Some(state)) = (self.phase_picker.as_mut(), self.play_state.as_ref())
+        {
+            Self::render_phase_picker(&self.theme, frame, picker, state);
+        }
+        if let Some(inspector) = &self.inspector {
+            self.render_inspector(frame, inspector);
+        }
+        if let Some(modal) = &self.confirm {
+            self.render_confirm_modal(frame, modal);
+        }
     }
 
     fn draw_menu(&mut self, frame: &mut Frame) {
@@ -2128,7 +4016,17 @@ impl Tui18App {
             .iter()
             .enumerate()
             .map(|(idx, item)| {
-                if idx == self.state.menu_cursor {
+                if idx == 0 && !self.ruby_available {
+                    let marker = if idx == self.state.menu_cursor {
+                        "▶"
+                    } else {
+                        " "
+                    };
+                    Line::from(Span::styled(
+                        format!("{marker} {item} (needs Ruby)"),
+                        Style::default().fg(self.theme.muted),
+                    ))
+                } else if idx == self.state.menu_cursor {
                     Line::from(Span::styled(
                         format!("▶ {item}"),
                         Style::default()
@@ -2145,9 +4043,59 @@ impl Tui18App {
             .collect();
 
         let menu = Paragraph::new(menu_lines)
-            .block(Block::default().borders(Borders::ALL).title("Menu"))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Menu · e engines"),
+            )
             .alignment(Alignment::Center);
         frame.render_widget(menu, menu_area);
+        if let Some(switcher) = &self.engine_switcher {
+            self.render_engine_switcher(frame, switcher);
+        }
+    }
+
+    fn render_engine_switcher(&self, frame: &mut Frame, switcher: &EngineSwitcher) {
+        let frame_area = frame.size();
+        let width = frame_area.width.saturating_sub(8).max(24);
+        let height = (switcher.entries.len().max(1) + 4)
+            .min(frame_area.height.saturating_sub(2) as usize) as u16;
+        let area = centered_rect(width, height, frame_area);
+        frame.render_widget(Clear, area);
+
+        let current = self.loader.root_path();
+        let mut lines = vec![
+            Line::from("j/k select · Enter switch · Esc close"),
+            Line::from(""),
+        ];
+        if switcher.entries.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No engines used yet",
+                Style::default().fg(self.theme.muted),
+            )));
+        }
+        for (idx, (path, commit)) in switcher.entries.iter().enumerate() {
+            let commit = match commit {
+                Some(commit) => commit.chars().take(7).collect::<String>(),
+                None => "no manifest".to_string(),
+            };
+            let marker = if *path == current { "*" } else { " " };
+            let text = format!("{marker} {} ({commit})", path.display());
+            let style = if idx == switcher.cursor {
+                Style::default()
+                    .bg(self.theme.selection_bg)
+                    .fg(self.theme.selection_fg)
+            } else {
+                Style::default().fg(self.theme.primary_fg)
+            };
+            lines.push(Line::from(Span::styled(text, style)));
+        }
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Recent Engines"),
+        );
+        frame.render_widget(paragraph, area);
     }
 
     fn draw_browse(&mut self, frame: &mut Frame) {
@@ -2157,7 +4105,7 @@ impl Tui18App {
         let banner_lines = if self.state.show_banner {
             self.state
                 .current_game()
-                .map(|game| block_font::render(&game.title))
+                .map(|game| block_font::render(game.banner_text()))
         } else {
             None
         };
@@ -2167,7 +4115,7 @@ impl Tui18App {
             constraints.push(Constraint::Length(lines.len() as u16 + 2));
         }
         constraints.push(Constraint::Min(8));
-        constraints.push(Constraint::Length(3));
+        constraints.push(Constraint::Length(self.status_height(size.width)));
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -2194,13 +4142,42 @@ impl Tui18App {
         if let (Some(lines), Some(area)) = (banner_lines.as_ref(), banner_chunk.copied()) {
             self.render_banner(frame, area, lines);
         }
+        if let Some(offset) = self.skipped_overlay {
+            self.render_skipped_overlay(frame, offset);
+        }
+    }
+
+    fn render_skipped_overlay(&self, frame: &mut Frame, offset: usize) {
+        let frame_area = frame.size();
+        let width = frame_area.width.saturating_sub(8).max(24);
+        let height = (self.skipped_games.len() * 2 + 4)
+            .min(frame_area.height.saturating_sub(2) as usize) as u16;
+        let area = centered_rect(width, height, frame_area);
+        frame.render_widget(Clear, area);
+
+        let mut lines = vec![Line::from("j/k scroll · Esc close"), Line::from("")];
+        for skipped in self.skipped_games.iter().skip(offset) {
+            lines.push(Line::from(skipped.folder.clone()));
+            lines.push(Line::from(Span::styled(
+                format!("    {}", skipped.reason),
+                Style::default().fg(self.theme.warning),
+            )));
+        }
+        let title = format!("Skipped Games ({})", self.skipped_games.len());
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
     }
 
     fn draw_continue(&mut self, frame: &mut Frame) {
         let area = frame.size();
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(5), Constraint::Length(3)])
+            .constraints([
+                Constraint::Min(5),
+                Constraint::Length(self.status_height(area.width)),
+            ])
             .split(area);
         let list_area = chunks[0];
         let status_area = chunks[1];
@@ -2232,23 +4209,146 @@ impl Tui18App {
                     Span::raw("  ")
                 };
                 let timestamp = entry.updated_at.format("%Y-%m-%d %H:%M");
-                entries.push(ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     marker,
                     Span::raw(format!("{}  [{}]", entry.name, timestamp)),
-                ])));
+                ];
+                if self
+                    .compare_mark
+                    .as_ref()
+                    .is_some_and(|mark| mark.path == entry.path)
+                {
+                    spans.push(Span::styled(
+                        "  (compare)",
+                        Style::default().fg(self.theme.accent_alt),
+                    ));
+                }
+                if absolute_idx == self.state.continue_cursor {
+                    if let Some(duration) = entry.play_duration() {
+                        spans.push(Span::styled(
+                            format!("  played over {}", format_play_duration(duration)),
+                            Style::default().fg(self.theme.muted),
+                        ));
+                    }
+                }
+                entries.push(ListItem::new(Line::from(spans)));
             }
             entries
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .title("Continue Game");
+        if !self.corrupt_saves.is_empty() {
+            block = block.title(Span::styled(
+                format!(
+                    " ⚠ {} corrupt saves (c to review) ",
+                    self.corrupt_saves.len()
+                ),
+                Style::default().fg(self.theme.warning),
+            ));
+        }
         let list = List::new(items)
             .block(block)
             .highlight_style(Style::default().bg(self.theme.selection_bg));
 
         frame.render_stateful_widget(list, list_area, &mut list_state);
         self.render_status(frame, status_area);
+
+        if let Some(cursor) = self.corrupt_overlay {
+            self.render_corrupt_overlay(frame, cursor);
+        }
+        if let Some(comparison) = &self.comparison {
+            self.render_comparison(frame, comparison);
+        }
+    }
+
+    fn render_comparison(&self, frame: &mut Frame, comparison: &SaveComparison) {
+        let frame_area = frame.size();
+        let width = frame_area.width.saturating_sub(8).max(24);
+        let height = (comparison.rows.len() as u16 + 6).min(frame_area.height.saturating_sub(2));
+        let area = centered_rect(width, height, frame_area);
+        frame.render_widget(Clear, area);
+
+        // Label column, then the two saves split evenly.
+        let label_width = 18;
+        let column = (width.saturating_sub(2) as usize).saturating_sub(label_width) / 2;
+        let clip = |text: &str| {
+            text.chars()
+                .take(column.saturating_sub(1))
+                .collect::<String>()
+        };
+        let muted = Style::default().fg(self.theme.muted);
+        let mut lines = vec![
+            Line::from(Span::styled("j/k scroll · Esc close", muted)),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "{:label_width$}{:column$}{}",
+                    "",
+                    clip(&comparison.left_name),
+                    clip(&comparison.right_name)
+                ),
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ];
+        if comparison.rows.is_empty() {
+            lines.push(Line::from("No differences"));
+        }
+        for row in comparison.rows.iter().skip(comparison.scroll) {
+            let label = format!("{} {}", row.sym, row.field);
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:label_width$}", label.trim_start()), muted),
+                Span::raw(format!("{:column$}", clip(&row.left))),
+                Span::styled(clip(&row.right), Style::default().fg(self.theme.warning)),
+            ]));
+        }
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Compare Saves"),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_corrupt_overlay(&self, frame: &mut Frame, cursor: usize) {
+        let frame_area = frame.size();
+        let width = frame_area.width.saturating_sub(8).max(24);
+        let height = (self.corrupt_saves.len() * 2 + 4)
+            .min(frame_area.height.saturating_sub(2) as usize) as u16;
+        let area = centered_rect(width, height, frame_area);
+        frame.render_widget(Clear, area);
+
+        let mut lines = vec![
+            Line::from("m/Enter move to corrupt/ · Esc close"),
+            Line::from(""),
+        ];
+        for (idx, (path, error)) in self.corrupt_saves.iter().enumerate() {
+            let pointer = if idx == cursor {
+                Span::styled("▶ ", Style::default().fg(self.theme.accent))
+            } else {
+                Span::raw("  ")
+            };
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string());
+            lines.push(Line::from(vec![pointer, Span::raw(name)]));
+            lines.push(Line::from(Span::styled(
+                format!("    {error}"),
+                Style::default().fg(self.theme.danger),
+            )));
+        }
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Corrupt Saves"),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
     }
 
     fn draw_play(&mut self, frame: &mut Frame) {
@@ -2259,7 +4359,7 @@ impl Tui18App {
                 .constraints([
                     Constraint::Percentage(60),
                     Constraint::Percentage(35),
-                    Constraint::Length(3),
+                    Constraint::Length(self.status_height(area.width)),
                 ])
                 .split(area);
 
@@ -2273,7 +4373,14 @@ impl Tui18App {
                 .split(rows[1]);
 
             if let Some(state) = self.play_state.as_mut() {
-                Self::render_play_market(&self.theme, frame, top[1], state);
+                Self::render_play_market(
+                    &self.theme,
+                    &self.currency,
+                    &self.market_options,
+                    frame,
+                    top[1],
+                    state,
+                );
             }
 
             if let Some(state) = self.play_state.as_ref() {
@@ -2285,17 +4392,30 @@ impl Tui18App {
 
             if let Some(state) = self.play_state.as_mut() {
                 if state.is_purchase_modal_active() {
-                    Self::render_train_purchase_modal(&self.theme, frame, area, state);
+                    Self::render_train_purchase_modal(
+                        &self.theme,
+                        &self.currency,
+                        self.config.distance_width,
+                        frame,
+                        area,
+                        state,
+                    );
                 }
             }
         } else {
             let block = Block::default().borders(Borders::ALL).title("Play Mode");
-            let message = if self.pending_session {
-                "Loading session…".to_string()
+            let lines = if let Some(indicator) = self.loading_indicator() {
+                vec![
+                    Line::from(format!("{indicator}  Loading session…")),
+                    Line::from(Span::styled(
+                        "Esc returns to the game list",
+                        Style::default().fg(self.theme.muted),
+                    )),
+                ]
             } else {
-                "No session loaded".to_string()
+                vec![Line::from("No session loaded")]
             };
-            let paragraph = Paragraph::new(vec![Line::from(message)])
+            let paragraph = Paragraph::new(lines)
                 .block(block)
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true });
@@ -2346,59 +4466,254 @@ impl Tui18App {
         frame.set_cursor(cursor_x, cursor_y);
     }
 
-    fn render_game_list(&mut self, frame: &mut Frame, area: Rect) {
-        self.state.list_height = area.height.saturating_sub(2) as usize;
-        self.state.clamp_cursor();
-        self.state.ensure_cursor_visible();
+    fn render_phase_picker(
+        theme: &Theme,
+        frame: &mut Frame,
+        picker: &mut PhasePickerModal,
+        state: &PlayState,
+    ) {
+        let frame_area = frame.size();
+        let len = state.phase_count();
+        let header_lines = 2usize;
+        let width = state
+            .game
+            .phases
+            .iter()
+            .map(|phase| phase.label().chars().count() + 16)
+            .max()
+            .unwrap_or(0)
+            .clamp(32, frame_area.width.saturating_sub(4).max(32) as usize)
+            as u16;
+        let height = (len + header_lines + 2).min(frame_area.height.saturating_sub(2) as usize);
+        let area = centered_rect(width, height as u16, frame_area);
+        frame.render_widget(Clear, area);
 
-        let mut list_state = ListState::default();
-        let height = area.height.saturating_sub(2) as usize;
-        let games = self.state.visible_games(height);
-        if !games.is_empty() {
-            let selected = self
-                .state
-                .cursor
-                .saturating_sub(self.state.offset)
-                .min(games.len().saturating_sub(1));
-            list_state.select(Some(selected));
+        let visible = height.saturating_sub(header_lines + 2).max(1);
+        picker.cursor = picker.cursor.min(len.saturating_sub(1));
+        if picker.cursor < picker.offset {
+            picker.offset = picker.cursor;
+        } else if picker.cursor >= picker.offset + visible {
+            picker.offset = picker.cursor + 1 - visible;
         }
-        let items: Vec<ListItem> = games
+
+        let current = state.current_phase_index();
+        let mut lines = vec![Line::from("Enter jump · Esc cancel"), Line::from("")];
+        for (idx, phase) in state
+            .game
+            .phases
             .iter()
             .enumerate()
-            .map(|(idx, game)| {
-                let global_index = self.state.offset + idx;
-                let is_selected = self.state.cursor == global_index;
-                let marker = if is_selected {
-                    Span::styled(
-                        "▶ ",
-                        Style::default()
-                            .fg(self.theme.accent)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                } else {
-                    Span::raw("  ")
-                };
-                let title = Span::styled(
-                    game.title.clone(),
-                    Style::default()
-                        .fg(self.theme.primary_fg)
-                        .add_modifier(Modifier::BOLD),
-                );
-                let subtitle = game.subtitle.as_ref().map(|s| {
-                    Span::styled(
-                        format!(" · {}", s),
-                        Style::default().fg(self.theme.muted),
-                    )
-                });
-                let mut line = vec![marker, title];
-                if let Some(sub) = subtitle {
-                    line.push(sub);
-                }
-                ListItem::new(Line::from(line))
-            })
-            .collect();
-
-        let block = Block::default().borders(Borders::ALL).title("Games");
+            .skip(picker.offset)
+            .take(visible)
+        {
+            let pointer = if idx == picker.cursor {
+                Span::styled("▶ ", Style::default().fg(theme.accent))
+            } else {
+                Span::raw("  ")
+            };
+            let text = format!("{}  ({} ORs)", phase.label(), phase.operating_rounds);
+            let style = if idx == current {
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.primary_fg)
+            };
+            lines.push(Line::from(vec![pointer, Span::styled(text, style)]));
+        }
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Go to Phase"))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_phase_prompt(&self, frame: &mut Frame, prompt: &PhasePromptModal) {
+        let frame_area = frame.size();
+        let width = 48_u16.min(frame_area.width.saturating_sub(4)).max(24);
+        let area = centered_rect(width, 6, frame_area);
+        frame.render_widget(Clear, area);
+
+        let input_line = Line::from(vec![
+            Span::styled("> ", Style::default().fg(self.theme.accent)),
+            Span::raw(prompt.input.clone()),
+        ]);
+        let helper = Line::from(vec![
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" add  "),
+            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" cancel"),
+        ]);
+        let paragraph = Paragraph::new(vec![
+            Line::from("Name and OR count, e.g. \"D 3\""),
+            input_line,
+            Line::from(""),
+            helper,
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Add Phase"))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+
+        let cursor_x = (area.x + 3 + prompt.input.chars().count() as u16)
+            .min(area.x + area.width.saturating_sub(2));
+        frame.set_cursor(cursor_x, area.y + 2);
+    }
+
+    fn render_share_prompt(&self, frame: &mut Frame, prompt: &SharePromptModal) {
+        let frame_area = frame.size();
+        let width = 48_u16.min(frame_area.width.saturating_sub(4)).max(24);
+        let area = centered_rect(width, 6, frame_area);
+        frame.render_widget(Clear, area);
+
+        let input_line = Line::from(vec![
+            Span::styled("> ", Style::default().fg(self.theme.accent)),
+            Span::raw(prompt.input.clone()),
+        ]);
+        let helper = Line::from(vec![
+            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" apply  "),
+            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" cancel"),
+        ]);
+        let paragraph = Paragraph::new(vec![
+            Line::from("Owner buys a share; \"-Owner\" sells one"),
+            input_line,
+            Line::from(""),
+            helper,
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Shares"))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+
+        let cursor_x = (area.x + 3 + prompt.input.chars().count() as u16)
+            .min(area.x + area.width.saturating_sub(2));
+        frame.set_cursor(cursor_x, area.y + 2);
+    }
+
+    fn render_inspector(&self, frame: &mut Frame, inspector: &InspectorModal) {
+        let frame_area = frame.size();
+        let width = frame_area.width.saturating_sub(8).max(24);
+        let height = (inspector.lines.len() as u16 + 4).min(frame_area.height.saturating_sub(2));
+        let area = centered_rect(width, height, frame_area);
+        frame.render_widget(Clear, area);
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "j/k scroll · Esc close",
+                Style::default().fg(self.theme.muted),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(
+            inspector
+                .lines
+                .iter()
+                .skip(inspector.scroll)
+                .map(|line| Line::from(line.clone())),
+        );
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(inspector.title.as_str()),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_confirm_modal(&self, frame: &mut Frame, modal: &ConfirmModal) {
+        let frame_area = frame.size();
+        let width =
+            (modal.message.len() as u16 + 4).clamp(24, frame_area.width.saturating_sub(4).max(24));
+        let area = centered_rect(width, 5, frame_area);
+        frame.render_widget(Clear, area);
+
+        let helper = Line::from(vec![
+            Span::styled("y", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" confirm  "),
+            Span::styled("n/Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" cancel"),
+        ]);
+        let paragraph = Paragraph::new(vec![
+            Line::from(Span::styled(
+                modal.message.clone(),
+                Style::default().fg(self.theme.warning),
+            )),
+            Line::from(""),
+            helper,
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Confirm"))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_game_list(&mut self, frame: &mut Frame, area: Rect) {
+        self.state.list_height = area.height.saturating_sub(2) as usize;
+        self.state.clamp_cursor();
+        self.state.ensure_cursor_visible();
+
+        let mut list_state = ListState::default();
+        let height = area.height.saturating_sub(2) as usize;
+        let games = self.state.visible_games(height);
+        if !games.is_empty() {
+            let selected = self
+                .state
+                .cursor
+                .saturating_sub(self.state.offset)
+                .min(games.len().saturating_sub(1));
+            list_state.select(Some(selected));
+        }
+        let items: Vec<ListItem> = games
+            .iter()
+            .enumerate()
+            .map(|(idx, game)| {
+                let global_index = self.state.offset + idx;
+                let is_selected = self.state.cursor == global_index;
+                let marker = if is_selected {
+                    Span::styled(
+                        "▶ ",
+                        Style::default()
+                            .fg(self.theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw("  ")
+                };
+                let title = Span::styled(
+                    game.title.clone(),
+                    Style::default()
+                        .fg(self.theme.primary_fg)
+                        .add_modifier(Modifier::BOLD),
+                );
+                let subtitle = game.subtitle.as_ref().map(|s| {
+                    Span::styled(
+                        format!(" · {}", s),
+                        Style::default().fg(self.theme.muted),
+                    )
+                });
+                let mut line = vec![marker, title];
+                if let Some(sub) = subtitle {
+                    line.push(sub);
+                }
+                ListItem::new(Line::from(line))
+            })
+            .collect();
+
+        let mut title = "Games".to_string();
+        let total = self.state.all_games.len();
+        if self.state.is_capped() {
+            title.push_str(&format!(
+                " · {} of {total} (A show all)",
+                self.state.filtered.len()
+            ));
+        } else if self.state.game_cap > 0 && total > self.state.game_cap {
+            title.push_str(" · all (A recent only)");
+        }
+        if !self.skipped_games.is_empty() {
+            title.push_str(&format!(
+                " · {} skipped (s to view)",
+                self.skipped_games.len()
+            ));
+        }
+        let block = Block::default().borders(Borders::ALL).title(title);
         let list = List::new(items)
             .block(block)
             .highlight_style(Style::default().bg(self.theme.selection_bg));
@@ -2426,11 +4741,11 @@ impl Tui18App {
                 lines.push(Line::from(format!("Location: {location}")));
             }
             if let Some(url) = &game.rules_url {
-                lines.push(Line::from(format!("Rules: {url}")));
+                lines.push(Line::from(format!("Rules: {url}  (o to open)")));
             }
             if let Some(commit) = &self.metadata.commit {
                 let short = commit.chars().take(7).collect::<String>();
-                lines.push(Line::from(format!("Commit: {}", short)));
+                lines.push(Line::from(format!("Commit: {}  (y to copy)", short)));
             }
             if let Some(updated) = &self.metadata.updated_at {
                 lines.push(Line::from(format!(
@@ -2452,45 +4767,11 @@ impl Tui18App {
     fn render_play_corporations(&self, frame: &mut Frame, area: Rect, state: &PlayState) {
         let block = Block::default().borders(Borders::ALL).title("Corporations");
         let items: Vec<ListItem> = state
+            .game
             .session
             .corporations
             .iter()
-            .map(|corp| {
-                let token = state.token_for(corp);
-                let par_text = corp
-                    .par_value
-                    .map(|value| format!("${value}"))
-                    .unwrap_or_else(|| "--".to_string());
-                let market_text = corp
-                    .market_position
-                    .as_ref()
-                    .map(|pos| {
-                        let sanitized = sanitize_market_text(&pos.raw);
-                        if sanitized.is_empty() {
-                            pos.raw.clone()
-                        } else {
-                            sanitized
-                        }
-                    })
-                    .unwrap_or_else(|| "--".to_string());
-                let mut spans = vec![Span::styled(
-                    format!("{:>3}", corp.sym),
-                    Style::default()
-                        .fg(self.theme.accent)
-                        .add_modifier(Modifier::BOLD),
-                )];
-                spans.push(Span::styled(
-                    format!(" [{token}] "),
-                    Style::default().fg(self.theme.accent_alt),
-                ));
-                spans.push(Span::styled(
-                    corp.name.clone(),
-                    Style::default().fg(self.theme.primary_fg),
-                ));
-                spans.push(Span::raw(format!("  P:{par_text:<4}")));
-                spans.push(Span::raw(format!(" M:{market_text:<4}")));
-                ListItem::new(Line::from(spans))
-            })
+            .map(|corp| ListItem::new(self.corporation_list_line(state, corp)))
             .collect();
 
         let mut list_state = ListState::default();
@@ -2505,19 +4786,80 @@ impl Tui18App {
         frame.render_stateful_widget(list, area, &mut list_state);
     }
 
-    fn render_play_market(theme: &Theme, frame: &mut Frame, area: Rect, state: &mut PlayState) {
+    /// One corporation list entry: symbol, token, name (unless abbreviated), par and price.
+    fn corporation_list_line(&self, state: &PlayState, corp: &Corporation) -> Line<'static> {
+        let token = state.token_for(corp);
+        let par_text = corp
+            .par_value
+            .map(|value| self.currency.format(value))
+            .unwrap_or_else(|| "--".to_string());
+        let market_text = corp
+            .market_position
+            .as_ref()
+            .map(|pos| {
+                let sanitized = sanitize_market_text(&pos.raw);
+                if sanitized.is_empty() {
+                    pos.raw.clone()
+                } else {
+                    sanitized
+                }
+            })
+            .unwrap_or_else(|| "--".to_string());
+        let mut spans = vec![Span::styled(
+            format!("{:>3}", corp.sym),
+            Style::default()
+                .fg(self.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )];
+        match self.corp_names {
+            CorpNameDisplay::Full => {
+                spans.push(Span::styled(
+                    format!(" [{token}] "),
+                    Style::default().fg(self.theme.accent_alt),
+                ));
+                spans.push(Span::styled(
+                    corp.name.clone(),
+                    Style::default().fg(self.theme.primary_fg),
+                ));
+                spans.push(Span::raw(format!("  P:{par_text:<4}")));
+            }
+            CorpNameDisplay::Abbreviated => {
+                spans.push(Span::styled(
+                    format!(" [{token}]"),
+                    Style::default().fg(self.theme.accent_alt),
+                ));
+                spans.push(Span::raw(format!(" P:{par_text:<4}")));
+            }
+        }
+        spans.push(Span::raw(format!(" M:{market_text:<4}")));
+        Line::from(spans)
+    }
+
+    fn render_play_market(
+        theme: &Theme,
+        currency: &CurrencyFormat,
+        market: &MarketOptions,
+        frame: &mut Frame,
+        area: Rect,
+        state: &mut PlayState,
+    ) {
         if state.should_show_title_banner() {
             Self::render_play_title_banner(theme, frame, area, state);
             return;
         }
 
         if state.revenue_view_enabled() {
-            Self::render_revenue_panel(theme, frame, area, state);
+            Self::render_revenue_panel(theme, currency, frame, area, state);
             return;
         }
 
-        let block = Block::default().borders(Borders::ALL).title("Stock Market");
-        let cell_width = state
+        let title = match state.game.session.market_name.as_deref() {
+            Some(name) => format!("Stock Market ({name})"),
+            None => "Stock Market".to_string(),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let longest = state
+            .game
             .session
             .market
             .iter()
@@ -2525,21 +4867,30 @@ impl Tui18App {
             .map(|value| value.len())
             .max()
             .unwrap_or(1);
-        let cell_width = cmp::max(4, cell_width + 2);
-        let inner_height = area.height.saturating_sub(2) as usize;
         let inner_width = area.width.saturating_sub(2) as usize;
-        let effective_col_width = cmp::max(1, cell_width);
-        let view_cols = if inner_width == 0 {
-            1
-        } else {
-            let total_cols = state.max_market_columns().max(1);
-            let automatic_cols = cmp::max(
-                1,
-                (inner_width + effective_col_width - 1) / effective_col_width,
-            );
-            cmp::min(total_cols, automatic_cols)
+        // Par and price selection keep the last line for a legend under the grid.
+        let legend = match state.mode() {
+            PlayMode::ParSelect => Some(state.par_legend(currency)),
+            PlayMode::PriceSelect => Some(state.price_legend(currency)),
+            _ => None,
         };
-        let view_rows = cmp::max(1, inner_height);
+        // Rulers take a header line and a row-number gutter out of the grid.
+        let gutter = state.market_ruler_gutter();
+        let header_lines = usize::from(state.market_rulers());
+        let inner_height = (area.height.saturating_sub(2) as usize)
+            .saturating_sub(usize::from(legend.is_some()) + header_lines);
+        let (cell_width, view_cols) = market.layout(
+            longest,
+            inner_width.saturating_sub(gutter),
+            state.max_market_columns(),
+        );
+        let gap = " ".repeat(market.cell_spacing);
+        let stride = cell_width + market.cell_spacing;
+        let symbol_tokens = market.tokens == MarketTokens::Symbol;
+        // Symbol tokens sit on one line under each price row; tokens that do not fit are
+        // summed up in a `+N` marker so every row has the same height.
+        let lines_per_row = if symbol_tokens { 2 } else { 1 };
+        let view_rows = cmp::max(1, inner_height / lines_per_row);
         state.set_market_view(view_rows, view_cols);
 
         let cursor = state.market_cursor();
@@ -2549,9 +4900,12 @@ impl Tui18App {
             .and_then(|corp| corp.market_position.clone());
         // Precompute which corporations have tokens in each cell so we can overlay them while rendering.
         let mut cell_tokens: HashMap<(usize, usize), Vec<&Corporation>> = HashMap::new();
-        for corp in &state.session.corporations {
+        for corp in &state.game.session.corporations {
             if let Some(pos) = &corp.market_position {
-                cell_tokens.entry((pos.row, pos.col)).or_default().push(corp);
+                cell_tokens
+                    .entry((pos.row, pos.col))
+                    .or_default()
+                    .push(corp);
             }
         }
 
@@ -2559,25 +4913,50 @@ impl Tui18App {
         let col_offset = state.market_col_offset();
         let view_rows = state.market_view_rows();
         let view_cols = state.market_view_cols();
-        let total_rows = state.session.market.len();
+        let total_rows = state.game.session.market.len();
+        let ruler_style = Style::default().fg(theme.muted);
+        let blank_gutter = || Span::raw(" ".repeat(gutter));
 
         let mut lines = Vec::new();
+        if state.market_rulers() {
+            let mut spans = vec![blank_gutter()];
+            for col_idx in col_offset..col_offset + view_cols {
+                let label = format!("{:^cell_width$}{gap}", col_idx + 1);
+                spans.push(Span::styled(label, ruler_style));
+            }
+            lines.push(Line::from(spans));
+        }
         let row_end = cmp::min(total_rows, row_offset + view_rows);
         for row_idx in row_offset..row_end {
-            let row = &state.session.market[row_idx];
+            let row = &state.game.session.market[row_idx];
             let mut spans = Vec::new();
+            if gutter > 0 {
+                let label = format!("{:>width$} ", row_idx + 1, width = gutter - 1);
+                spans.push(Span::styled(label, ruler_style));
+            }
+            // Per visible column: symbol-mode tokens that fit the cell, and how many did not.
+            let mut token_slots: Vec<(Vec<&Corporation>, usize)> = vec![(Vec::new(), 0); view_cols];
             if row.len() <= col_offset {
-                spans.push(Span::raw(" ".repeat(cell_width * view_cols)));
+                spans.push(Span::raw(" ".repeat(stride * view_cols)));
             } else {
                 let col_end = cmp::min(row.len(), col_offset + view_cols);
-                for col_idx in col_offset..col_end {
-                    let raw = &row[col_idx];
+                for (col_idx, raw) in row.iter().enumerate().take(col_end).skip(col_offset) {
                     if raw.trim().is_empty() {
-                        spans.push(Span::raw(" ".repeat(cell_width)));
+                        spans.push(Span::raw(" ".repeat(stride)));
+                        continue;
+                    }
+                    if state.game.session.is_market_header(row_idx, col_idx) {
+                        // Engine labels are drawn as column guides, never as prices.
+                        let text: String = raw.trim().chars().take(cell_width).collect();
+                        let label = format!("{text:^cell_width$}{gap}");
+                        spans.push(Span::styled(
+                            label,
+                            ruler_style.add_modifier(Modifier::ITALIC),
+                        ));
                         continue;
                     }
                     let is_par_cell = state.is_par_cell(row_idx, col_idx);
-                    let mut style = Style::default().fg(market_color(raw, theme));
+                    let mut style = Style::default().fg(market_color(raw, theme, &market.palette));
                     if play_mode == PlayMode::ParSelect && !is_par_cell {
                         style = style.add_modifier(Modifier::DIM);
                     }
@@ -2605,7 +4984,14 @@ impl Tui18App {
                     let padded = format!("{text:^width$}", text = display, width = cell_width);
                     let mut cell_spans = vec![Span::styled(padded.clone(), style)];
 
-                    if let Some(tokens) = cell_tokens.get(&(row_idx, col_idx)) {
+                    let tokens = cell_tokens.get(&(row_idx, col_idx));
+                    if let (true, Some(tokens)) = (symbol_tokens, tokens) {
+                        let labels: Vec<&str> =
+                            tokens.iter().map(|corp| corp.sym.as_str()).collect();
+                        let (shown, hidden) = fit_tokens(&labels, cell_width);
+                        token_slots[col_idx - col_offset] =
+                            (shown.into_iter().map(|idx| tokens[idx]).collect(), hidden);
+                    } else if let Some(tokens) = tokens {
                         let mut base_chars: Vec<char> = padded.chars().collect();
                         let max_tokens = cmp::min(tokens.len(), base_chars.len());
                         for (idx, corp) in tokens.iter().take(max_tokens).enumerate() {
@@ -2620,12 +5006,15 @@ impl Tui18App {
                     }
 
                     spans.extend(cell_spans);
+                    if !gap.is_empty() {
+                        spans.push(Span::raw(gap.clone()));
+                    }
                 }
 
                 let displayed = col_end.saturating_sub(col_offset);
                 if displayed < view_cols {
                     for _ in displayed..view_cols {
-                        spans.push(Span::raw(" ".repeat(cell_width)));
+                        spans.push(Span::raw(" ".repeat(stride)));
                     }
                 }
             }
@@ -2633,10 +5022,46 @@ impl Tui18App {
                 spans.push(Span::raw(""));
             }
             lines.push(Line::from(spans));
+
+            if symbol_tokens {
+                let mut spans = vec![blank_gutter()];
+                for (corps, hidden) in &token_slots {
+                    let mut used = 0;
+                    for (idx, corp) in corps.iter().enumerate() {
+                        if idx > 0 {
+                            spans.push(Span::raw(" "));
+                            used += 1;
+                        }
+                        let label: String = corp.sym.chars().take(cell_width).collect();
+                        used += label.chars().count();
+                        spans.push(Span::styled(label, corporation_style(corp, theme)));
+                    }
+                    if *hidden > 0 {
+                        let separator = if corps.is_empty() { "" } else { " " };
+                        let marker: String = format!("{separator}+{hidden}")
+                            .chars()
+                            .take(cell_width.saturating_sub(used))
+                            .collect();
+                        used += marker.chars().count();
+                        spans.push(Span::styled(marker, Style::default().fg(theme.muted)));
+                    }
+                    spans.push(Span::raw(" ".repeat(stride.saturating_sub(used))));
+                }
+                lines.push(Line::from(spans));
+            }
         }
 
-        while lines.len() < view_rows {
-            lines.push(Line::from(Span::raw(" ".repeat(cell_width * view_cols))));
+        while lines.len() < view_rows * lines_per_row + header_lines {
+            lines.push(Line::from(Span::raw(
+                " ".repeat(gutter + stride * view_cols),
+            )));
+        }
+        if let Some(legend) = legend {
+            let legend: String = legend.chars().take(inner_width).collect();
+            lines.push(Line::from(Span::styled(
+                legend,
+                Style::default().fg(theme.muted),
+            )));
         }
 
         let paragraph = Paragraph::new(lines)
@@ -2647,6 +5072,7 @@ impl Tui18App {
 
     fn render_revenue_panel(
         theme: &Theme,
+        currency: &CurrencyFormat,
         frame: &mut Frame,
         area: Rect,
         state: &mut PlayState,
@@ -2685,6 +5111,7 @@ impl Tui18App {
         }
 
         let corp_label_width = state
+            .game
             .session
             .corporations
             .iter()
@@ -2721,7 +5148,7 @@ impl Tui18App {
         let view_cols = if available_width == 0 {
             0
         } else {
-            let approx = (available_width + col_width - 1) / col_width;
+            let approx = available_width.div_ceil(col_width);
             cmp::max(1, cmp::min(total_cols, approx))
         };
         let view_rows = cmp::max(1, inner_height.saturating_sub(1));
@@ -2754,7 +5181,7 @@ impl Tui18App {
         lines.push(Line::from(header_spans));
 
         for row_idx in row_offset..row_end {
-            let corporation = &state.session.corporations[row_idx];
+            let corporation = &state.game.session.corporations[row_idx];
             let is_active_row = row_idx == state.revenue_cursor_corp;
             let mut spans = Vec::new();
             let mut corp_style = Style::default().add_modifier(Modifier::BOLD);
@@ -2804,30 +5231,23 @@ impl Tui18App {
             lines.push(Line::from(spans));
         }
 
-        let active_value = state.current_revenue_value().unwrap_or_default();
-        let base_value = state.current_revenue_base().unwrap_or_default();
         let dividend_spans = {
             let mut spans = Vec::with_capacity(14);
             spans.push(Span::styled(
                 "Dividends ",
                 Style::default().add_modifier(Modifier::BOLD),
             ));
-            for step in 1..=6 {
-                let percent = step * 10;
-                let computed = base_value * percent / 100;
-                let label = if base_value == 0 {
-                    format!("{percent:>2}%:-")
-                } else {
-                    format!("{percent:>2}%:{}", format_currency(computed))
-                };
+            let entries = state.revenue_preset_entries(currency);
+            let last = entries.len().saturating_sub(1);
+            for (index, (label, active)) in entries.into_iter().enumerate() {
                 let mut style = Style::default();
-                if base_value != 0 && active_value == computed {
+                if active {
                     style = style
                         .fg(theme.warning)
                         .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
                 }
                 spans.push(Span::styled(label, style));
-                if step != 6 {
+                if index != last {
                     spans.push(Span::raw("  "));
                 }
             }
@@ -2839,7 +5259,12 @@ impl Tui18App {
             Span::styled("hjkl", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" move  "),
             Span::styled("1-6", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" set $10-$60  "),
+            Span::raw(match state.revenue_preset_mode {
+                RevenuePresetMode::Percent => " set 10-60% of last  ",
+                RevenuePresetMode::Absolute => " set $10-$60  ",
+            }),
+            Span::styled("%", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" presets mode  "),
             Span::styled("+/-", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" adjust  "),
             Span::styled("a", Style::default().add_modifier(Modifier::BOLD)),
@@ -2866,7 +5291,7 @@ impl Tui18App {
 
     fn render_play_title_banner(theme: &Theme, frame: &mut Frame, area: Rect, state: &PlayState) {
         let block = Block::default().borders(Borders::ALL).title("Stock Market");
-        let banner_lines = block_font::render(&state.session.info.title);
+        let banner_lines = block_font::render(state.game.session.info.banner_text());
         let styled_lines: Vec<Line> = banner_lines
             .into_iter()
             .map(|line| {
@@ -2921,7 +5346,13 @@ impl Tui18App {
                 Style::default().add_modifier(Modifier::BOLD),
             )]));
             if let Some(par) = corp.par_value {
-                lines.push(Line::from(format!("Par Value: ${par}")));
+                let zone = par_zone_label(corp)
+                    .map(|zone| format!(" ({zone} zone)"))
+                    .unwrap_or_default();
+                lines.push(Line::from(format!(
+                    "Par Value: {}{zone}",
+                    self.currency.format(par)
+                )));
             } else {
                 lines.push(Line::from("Par Value: unset"));
             }
@@ -2939,7 +5370,10 @@ impl Tui18App {
             } else {
                 lines.push(Line::from("Market Position: --"));
             }
-            lines.push(Line::from(format!("Last Revenue: ${}", corp.last_revenue)));
+            lines.push(Line::from(format!(
+                "Last Revenue: {}",
+                self.currency.format(corp.last_revenue)
+            )));
             if corp.trains.is_empty() {
                 lines.push(Line::from("Owned Trains: none"));
             } else {
@@ -2947,7 +5381,7 @@ impl Tui18App {
                 for owned in &corp.trains {
                     let price_text = owned
                         .price
-                        .map(|value| format!("cost=${value}"))
+                        .map(|value| format!("cost={}", self.currency.format(value)))
                         .unwrap_or_else(|| "cost=?".to_string());
                     let stops_render = if owned.revenue_stops.is_empty() {
                         "[--]".to_string()
@@ -2981,10 +5415,10 @@ impl Tui18App {
                     let usage_span = Span::styled(usage_text, usage_style);
                     lines.push(Line::from(vec![
                         Span::raw(format!(
-                            "  {}  dist={}  last=${}  {}  stops: {}",
+                            "  {}  dist={}  last={}  {}  stops: {}",
                             owned.name,
-                            format_distance(&owned.distance),
-                            owned.last_revenue,
+                            format_distance(&owned.distance, self.config.distance_width),
+                            self.currency.format(owned.last_revenue),
                             price_text,
                             stops_render
                         )),
@@ -2993,7 +5427,7 @@ impl Tui18App {
                 }
             }
         } else {
-            lines.push(Line::from("No corporation selected"));
+            lines.push(Line::from(state.missing_corporation_message()));
         }
 
         let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
@@ -3002,6 +5436,8 @@ impl Tui18App {
 
     fn render_train_purchase_modal(
         theme: &Theme,
+        currency: &CurrencyFormat,
+        distance_width: usize,
         frame: &mut Frame,
         area: Rect,
         state: &mut PlayState,
@@ -3010,19 +5446,22 @@ impl Tui18App {
         if available_entries.is_empty() {
             return;
         }
-        let available_strings: Vec<String> = available_entries
+        let matches = state.purchase_matches();
+        let available_strings: Vec<String> = matches
             .iter()
+            .filter_map(|&selection| available_entries.get(selection))
             .map(|(_, ty, remaining)| {
                 format!(
-                    "{}  dist={}  price=${}  ({} left)",
+                    "{}  dist={}  price={}  ({} left)",
                     ty.name,
-                    format_distance(&ty.distance),
-                    ty.price.unwrap_or(0),
+                    format_distance(&ty.distance, distance_width),
+                    currency.format(ty.price.unwrap_or(0)),
                     remaining
                 )
             })
             .collect();
         let len = available_strings.len();
+        let total = available_entries.len();
         drop(available_entries);
         let Some(modal) = state.train_purchase_modal.as_mut() else {
             return;
@@ -3034,9 +5473,9 @@ impl Tui18App {
             area.width.saturating_sub(2) as usize,
             (max_text_width + 6).max(40),
         ) as u16;
-        let header_lines = 4usize;
+        let header_lines = 5usize;
         let max_height = area.height.saturating_sub(2) as usize;
-        let mut modal_height = header_lines + available_strings.len();
+        let mut modal_height = header_lines + available_strings.len().max(1);
         if max_height > 0 {
             modal_height = modal_height.min(max_height).max(header_lines + 1);
         }
@@ -3046,11 +5485,27 @@ impl Tui18App {
         let block = Block::default()
             .borders(Borders::ALL)
             .title("Purchase Train");
-        let mut lines = Vec::new();
-        lines.push(Line::from("Select a train to buy"));
-        lines.push(Line::from("Enter confirm · Esc cancel"));
-        lines.push(Line::from("j/k move cursor"));
-        lines.push(Line::from(""));
+        let filter = if modal.query.is_empty() {
+            Span::styled("type to filter", Style::default().fg(theme.muted))
+        } else {
+            Span::styled(
+                format!("{}_  ({len}/{total})", modal.query),
+                Style::default().fg(theme.accent),
+            )
+        };
+        let mut lines = vec![
+            Line::from("Select a train to buy"),
+            Line::from("Enter confirm · Esc clear/cancel"),
+            Line::from("↑/↓ move cursor"),
+            Line::from(vec![Span::raw("Filter: "), filter]),
+            Line::from(""),
+        ];
+        if len == 0 {
+            lines.push(Line::from(Span::styled(
+                "No train types match",
+                Style::default().fg(theme.muted),
+            )));
+        }
         let visible = modal_height.saturating_sub(header_lines).max(1);
         if modal.cursor >= len {
             modal.cursor = len.saturating_sub(1);
@@ -3068,13 +5523,17 @@ impl Tui18App {
             }
         }
         let end = cmp::min(modal.offset + visible, len);
-        for idx in modal.offset..end {
+        for (idx, text) in available_strings
+            .iter()
+            .enumerate()
+            .take(end)
+            .skip(modal.offset)
+        {
             let pointer = if idx == modal.cursor {
                 Span::styled("▶ ", Style::default().fg(theme.accent))
             } else {
                 Span::raw("  ")
             };
-            let text = &available_strings[idx];
             lines.push(Line::from(vec![pointer, Span::raw(text.clone())]));
         }
         let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
@@ -3089,32 +5548,61 @@ impl Tui18App {
                     Line::from("Esc   return to game list"),
                     Line::from("q     quit application"),
                     Line::from("j/k   select corporation"),
+                    Line::from("e     next corporation without par"),
                     Line::from("Enter open market / set price"),
                     Line::from("p     set or update par price"),
                     Line::from("t     manage trains"),
+                    Line::from("X     reset corporation"),
+                    Line::from("C     clear all pars and prices"),
                     Line::from("Auto-save enabled"),
                     Line::from("u     undo (history)"),
                     Line::from("Ctrl+R redo history"),
+                    Line::from("R     replay save history"),
+                    Line::from("r     re-extract game from engine"),
+                    Line::from("S     text summary (screen reader)"),
                 ];
                 if state.revenue_view_enabled() {
                     lines.push(Line::from("m     show stock market"));
                     lines.push(Line::from("hjkl move payout cursor"));
-                    lines.push(Line::from("1-6  set $10-$60 payout"));
+                    lines.push(Line::from("HJKL move a page"));
+                    lines.push(Line::from("1-6  set payout preset"));
+                    lines.push(Line::from("%     percent/$ presets"));
+                    lines.push(Line::from("$     payout = share price"));
                     lines.push(Line::from("+/-  adjust payout by $10"));
                     lines.push(Line::from("0     clear payout"));
                     lines.push(Line::from("a     add operating round"));
+                    lines.push(Line::from("D     duplicate selected OR"));
                     lines.push(Line::from("[ ]   change phase"));
                 } else {
                     lines.push(Line::from("m     show revenue by OR"));
                     lines.push(Line::from("[ ]   change phase"));
                     lines.push(Line::from("a     add operating round"));
                 }
+                if state.revenue_view_enabled() {
+                    lines.push(Line::from("F1-F4 corps/market/revenue/trains"));
+                } else {
+                    lines.push(Line::from("1-4   corps/market/revenue/trains"));
+                }
+                lines.push(Line::from("g     go to phase"));
+                lines.push(Line::from("{ }   remove/add custom phase"));
+                lines.push(Line::from("y     copy game/commit id"));
+                lines.push(Line::from("E     export session for bug report"));
+                lines.push(Line::from("B     pin/unpin title banner"));
+                lines.push(Line::from("v     full/abbreviated corp names"));
+                lines.push(Line::from("o     buy/sell (-name) a share"));
+                lines.push(Line::from("Ctrl+Y copy status message"));
+                if self.debug {
+                    lines.push(Line::from("I     inspect raw engine data"));
+                }
                 lines
             }
             PlayMode::ParSelect => vec![
                 Line::from("Esc   cancel par selection"),
                 Line::from("hjkl move cursor"),
+                Line::from("HJKL move a page"),
+                Line::from("0/$   first/last par in row"),
                 Line::from("p     confirm par price"),
+                Line::from("#     toggle coordinate rulers"),
                 Line::from("Enter confirm par (first set)"),
                 Line::from("u     undo (history)"),
                 Line::from("Ctrl+R redo history"),
@@ -3122,20 +5610,26 @@ impl Tui18App {
             PlayMode::PriceSelect => vec![
                 Line::from("Esc   cancel stock selection"),
                 Line::from("hjkl move cursor"),
+                Line::from("HJKL move a page"),
+                Line::from("0/$   first/last column"),
                 Line::from("Enter set stock price"),
+                Line::from("1-9   type a price to jump to it"),
                 Line::from("p     adjust par price"),
+                Line::from("#     toggle coordinate rulers"),
                 Line::from("u     undo (history)"),
                 Line::from("Ctrl+R redo history"),
             ],
             PlayMode::TrainManage => vec![
                 Line::from("Esc/t exit train manager"),
-                Line::from("hl   switch section (arrows ok)"),
+                Line::from("hl   owned/pool (arrows ok)"),
+                Line::from("Tab  other section, if not empty"),
                 Line::from("jk   move selection"),
                 Line::from("Enter edit selected run"),
                 Line::from("b     buy train (modal)"),
                 Line::from("d     pay dividend"),
                 Line::from("w     withhold earnings"),
                 Line::from("r     rust selected train"),
+                Line::from("s     return train to pool"),
                 Line::from("u     undo (history)"),
                 Line::from("Ctrl+R redo history"),
             ],
@@ -3166,7 +5660,13 @@ impl Tui18App {
                 format!("{} ({})", corp.name, corp.sym),
                 Style::default().add_modifier(Modifier::BOLD),
             )]));
-            lines.push(Line::from("Owned:"));
+            match state.train_limit() {
+                Some(limit) => lines.push(Line::from(format!(
+                    "Owned ({}/{limit}):",
+                    corp.trains.len()
+                ))),
+                None => lines.push(Line::from("Owned:")),
+            }
             let owned_focus = state.train_focus() == TrainFocus::Owned;
             if corp.trains.is_empty() {
                 let marker = if owned_focus { "▶ " } else { "  " };
@@ -3181,7 +5681,7 @@ impl Tui18App {
                     };
                     let price = owned
                         .price
-                        .map(|value| format!("cost=${value}"))
+                        .map(|value| format!("cost={}", self.currency.format(value)))
                         .unwrap_or_else(|| "cost=?".to_string());
 
                     let (display_values, active_cursor, pending_input) =
@@ -3203,10 +5703,10 @@ impl Tui18App {
                     let mut spans = vec![
                         marker,
                         Span::raw(format!(
-                            "{} dist={} last=${} {}",
+                            "{} dist={} last={} {}",
                             owned.name,
-                            format_distance(&owned.distance),
-                            owned.last_revenue,
+                            format_distance(&owned.distance, self.config.distance_width),
+                            self.currency.format(owned.last_revenue),
                             price
                         )),
                         Span::raw("  stops: "),
@@ -3257,25 +5757,120 @@ impl Tui18App {
             }
             lines.push(Line::from(""));
         } else {
-            lines.push(Line::from("No corporation selected"));
+            lines.push(Line::from(state.missing_corporation_message()));
+        }
+
+        lines.push(Line::from("Pool:"));
+        let pool_focus = state.train_focus() == TrainFocus::Pool;
+        let focused_type = state
+            .available_trains()
+            .get(state.pool_train_cursor())
+            .map(|(idx, _, _)| *idx);
+        for (idx, (ty, remaining)) in state.train_supply().into_iter().enumerate() {
+            let selected = pool_focus && focused_type == Some(idx);
+            let marker = if selected { "▶ " } else { "  " };
+            let (supply, style) = if remaining > 0 {
+                let style = if selected {
+                    Style::default().fg(self.theme.accent)
+                } else {
+                    Style::default()
+                };
+                (format!("{remaining}/{}", ty.total), style)
+            } else {
+                (
+                    format!("0/{} sold out", ty.total),
+                    Style::default().fg(self.theme.muted),
+                )
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{marker}{}  {supply}", ty.name),
+                style,
+            )));
         }
 
         let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
         frame.render_widget(paragraph, area);
     }
 
+    /// Primary status text: the filter echo while typing, otherwise the
+    /// timestamped message with any loading spinner in front.
+    fn status_text(&self) -> String {
+        if self.state.mode == Mode::Filter {
+            return format!("Filter: {}", self.state.filter);
+        }
+        let stamp = self.state.status_at.format("%H:%M:%S");
+        match self.loading_indicator() {
+            Some(indicator) => format!("{indicator}  {stamp}  {}", self.state.status),
+            None => format!("{stamp}  {}", self.state.status),
+        }
+    }
+
+    /// Height of the status box for `width` columns, growing so a long
+    /// message can wrap onto up to three lines.
+    fn status_height(&self, width: u16) -> u16 {
+        let inner = usize::from(width.saturating_sub(2)).max(1);
+        let len = self.status_text().chars().count().max(1);
+        len.div_ceil(inner).clamp(1, 3) as u16 + 2
+    }
+
     fn render_status(&self, frame: &mut Frame, area: Rect) {
-        let block = Block::default().borders(Borders::ALL).title("Status");
-        let primary = if self.state.mode == Mode::Filter {
-            format!("Filter: {}", self.state.filter)
+        // The secondary line rarely fits, so a read-only instance says so in the title.
+        let title = if self.save_manager.is_read_only() {
+            Line::from(vec![
+                Span::raw("Status "),
+                Span::styled(
+                    "(read-only, not saving)",
+                    Style::default().fg(self.theme.warning),
+                ),
+            ])
         } else {
-            self.state.status.clone()
+            Line::from("Status")
         };
-        let secondary = format!("Saves tracked: {}  (auto-save enabled)", self.saves.len());
-        let paragraph = Paragraph::new(vec![Line::from(primary), Line::from(secondary)])
-            .block(block)
-            .wrap(Wrap { trim: true });
-        frame.render_widget(paragraph, area);
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let primary = self.status_text();
+        let secondary = if !self.ruby_available {
+            Line::from(Span::styled(
+                RUBY_MISSING_MESSAGE,
+                Style::default().fg(self.theme.warning),
+            ))
+        } else if self.save_manager.is_read_only() {
+            Line::from(Span::styled(
+                format!("Saves tracked: {}  (read-only)", self.saves.len()),
+                Style::default().fg(self.theme.warning),
+            ))
+        } else {
+            Line::from(format!(
+                "Saves tracked: {}  (auto-save enabled)",
+                self.saves.len()
+            ))
+        };
+        let mut style = Style::default();
+        if self.state.mode != Mode::Filter {
+            style = style.fg(self.state.status_level.color(&self.theme));
+        }
+        if self.status_flash {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        let primary = Line::from(Span::styled(primary, style));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let text_area = match &self.sync_progress {
+            Some((stage, percent)) => {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(10), Constraint::Percentage(40)])
+                    .split(inner);
+                let gauge = Gauge::default()
+                    .gauge_style(Style::default().fg(self.theme.accent))
+                    .percent(u16::from(*percent))
+                    .label(format!("Sync: {stage} {percent}%"));
+                frame.render_widget(gauge, columns[1]);
+                columns[0]
+            }
+            None => inner,
+        };
+        let paragraph = Paragraph::new(vec![primary, secondary]).wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, text_area);
     }
 
     fn render_banner(&self, frame: &mut Frame, area: Rect, lines: &[String]) {
@@ -3305,15 +5900,85 @@ fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Re
     Ok(())
 }
 
-/// Dedicated blocking thread that bridges Crossterm events into the async world via a channel.
-fn spawn_input_thread(sender: mpsc::Sender<AppEvent>) {
-    thread::spawn(move || loop {
-        match event::poll(TICK_RATE) {
-            Ok(true) => match event::read() {
-                Ok(evt) => {
-                    if sender.blocking_send(AppEvent::Input(evt)).is_err() {
-                        break;
-                    }
+/// Best-effort terminal reset for exits that bypass `restore_terminal` (panics, repeated
+/// signals): leaves raw mode and the alternate screen and shows the cursor.
+pub(crate) fn reset_terminal(out: &mut impl Write) -> io::Result<()> {
+    let raw = disable_raw_mode();
+    execute!(out, LeaveAlternateScreen, cursor::Show)?;
+    raw
+}
+
+/// Forwards SIGINT/SIGTERM as `AppEvent::Shutdown` so the loop can save and restore the
+/// terminal. A second signal means the loop is not draining events, so the terminal is
+/// restored here and the process exits without waiting on it.
+fn spawn_signal_task(sender: mpsc::Sender<AppEvent>) {
+    spawn(async move {
+        let mut signals = match ShutdownSignals::new() {
+            Ok(signals) => signals,
+            Err(err) => {
+                warn!(?err, "Failed to install signal handlers");
+                return;
+            }
+        };
+        signals.recv().await;
+        // `try_send` so a full channel can never block the second-signal escape hatch.
+        if sender.try_send(AppEvent::Shutdown).is_err() {
+            warn!("Event loop busy; shutdown on next signal");
+        }
+        signals.recv().await;
+        let _ = reset_terminal(&mut io::stdout());
+        lock::release_held();
+        std::process::exit(130);
+    });
+}
+
+/// Termination signals that should end the UI: SIGINT and SIGTERM on Unix, Ctrl+C
+/// elsewhere.
+struct ShutdownSignals {
+    #[cfg(unix)]
+    interrupt: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    terminate: tokio::signal::unix::Signal,
+}
+
+impl ShutdownSignals {
+    #[cfg(unix)]
+    fn new() -> io::Result<Self> {
+        use tokio::signal::unix::{signal, SignalKind};
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn new() -> io::Result<Self> {
+        Ok(Self {})
+    }
+
+    #[cfg(unix)]
+    async fn recv(&mut self) {
+        tokio::select! {
+            _ = self.interrupt.recv() => {}
+            _ = self.terminate.recv() => {}
+        }
+    }
+
+    #[cfg(not(unix))]
+    async fn recv(&mut self) {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+/// Dedicated blocking thread that bridges Crossterm events into the async world via a channel.
+fn spawn_input_thread(sender: mpsc::Sender<AppEvent>) {
+    thread::spawn(move || loop {
+        match event::poll(TICK_RATE) {
+            Ok(true) => match event::read() {
+                Ok(evt) => {
+                    if sender.blocking_send(AppEvent::Input(evt)).is_err() {
+                        break;
+                    }
                 }
                 Err(_) => break,
             },
@@ -3327,6 +5992,27 @@ fn spawn_input_thread(sender: mpsc::Sender<AppEvent>) {
     });
 }
 
+/// How much a status message matters: routine echoes can be silenced with
+/// `verbose_status = false`, errors always show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusLevel {
+    Routine,
+    Info,
+    Success,
+    Error,
+}
+
+impl StatusLevel {
+    /// Colour used for the primary status line at this severity.
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            StatusLevel::Routine | StatusLevel::Info => theme.muted,
+            StatusLevel::Success => theme.success,
+            StatusLevel::Error => theme.danger,
+        }
+    }
+}
+
 /// Lightweight navigation state for the menu/browse/continue screens.
 struct UiState {
     all_games: Vec<GameInfo>,
@@ -3336,12 +6022,23 @@ struct UiState {
     list_height: usize,
     filter: String,
     status: String,
+    status_level: StatusLevel,
+    status_at: DateTime<Local>,
+    /// UI ticks seen so far, and the tick the current status was set on.
+    tick: u32,
+    status_tick: u32,
     show_banner: bool,
     mode: Mode,
     should_quit: bool,
     menu_cursor: usize,
     continue_cursor: usize,
     continue_offset: usize,
+    /// `max_games`: how many games the unfiltered list shows; 0 shows all.
+    game_cap: usize,
+    /// Temporarily lift `game_cap` (`A` on the Browse screen).
+    show_all_games: bool,
+    /// Game ids with saves, most recently played first; they lead the capped list.
+    recent_game_ids: Vec<String>,
 }
 
 impl Default for UiState {
@@ -3353,13 +6050,20 @@ impl Default for UiState {
             offset: 0,
             list_height: 1,
             filter: String::new(),
-            status: "Ready".to_string(),
+            status: READY_STATUS.to_string(),
+            status_level: StatusLevel::Info,
+            status_at: Local::now(),
+            tick: 0,
+            status_tick: 0,
             show_banner: true,
             mode: Mode::Browse,
             should_quit: false,
             menu_cursor: 0,
             continue_cursor: 0,
             continue_offset: 0,
+            game_cap: 0,
+            show_all_games: false,
+            recent_game_ids: Vec::new(),
         }
     }
 }
@@ -3371,7 +6075,7 @@ impl UiState {
 
     fn apply_filter(&mut self) {
         if self.filter.trim().is_empty() {
-            self.filtered = self.all_games.clone();
+            self.filtered = self.capped_games();
         } else {
             let needle = self.filter.to_lowercase();
             self.filtered = self
@@ -3385,6 +6089,57 @@ impl UiState {
         self.offset = 0;
     }
 
+    /// Whether the unfiltered list is currently cut down to `game_cap` entries.
+    fn is_capped(&self) -> bool {
+        self.filter.trim().is_empty()
+            && self.game_cap > 0
+            && !self.show_all_games
+            && self.all_games.len() > self.game_cap
+    }
+
+    /// The unfiltered list: every game, or when capped the recently played games
+    /// followed by the rest in catalogue order, cut to `game_cap`.
+    fn capped_games(&self) -> Vec<GameInfo> {
+        if !self.is_capped() {
+            return self.all_games.clone();
+        }
+        let recent = self
+            .recent_game_ids
+            .iter()
+            .filter_map(|id| self.all_games.iter().find(|game| &game.id == id));
+        let rest = self
+            .all_games
+            .iter()
+            .filter(|game| !self.recent_game_ids.contains(&game.id));
+        recent.chain(rest).take(self.game_cap).cloned().collect()
+    }
+
+    /// Records which games were played most recently and, when capped, rebuilds the list
+    /// around them, keeping the highlighted game if it is still shown.
+    fn set_recent_games(&mut self, ids: Vec<String>) {
+        self.recent_game_ids = ids;
+        if self.is_capped() {
+            self.reapply_keeping_selection();
+        }
+    }
+
+    /// Flips between the capped list and every game, keeping the highlighted game.
+    fn toggle_show_all_games(&mut self) {
+        self.show_all_games = !self.show_all_games;
+        self.reapply_keeping_selection();
+    }
+
+    fn reapply_keeping_selection(&mut self) {
+        let selected = self.current_game().map(|game| game.id.clone());
+        self.apply_filter();
+        if let Some(pos) =
+            selected.and_then(|id| self.filtered.iter().position(|game| game.id == id))
+        {
+            self.cursor = pos;
+            self.ensure_cursor_visible();
+        }
+    }
+
     fn move_cursor(&mut self, delta: isize) {
         if self.filtered.is_empty() {
             return;
@@ -3444,35 +6199,68 @@ impl UiState {
         self.filtered.get(self.cursor)
     }
 
+    /// Moves the cursor to `game_id`, clearing a filter that hides it. Returns `false`
+    /// and leaves the selection alone when the game is not installed.
     fn select_game(&mut self, game_id: &str) -> bool {
-        if self.all_games.is_empty() {
+        if !self.all_games.iter().any(|game| game.id == game_id) {
             return false;
         }
-
-        if let Some(pos) = self.filtered.iter().position(|game| game.id == game_id) {
-            self.cursor = pos;
-            self.ensure_cursor_visible();
-            return true;
-        }
-
-        if let Some(pos) = self.all_games.iter().position(|game| game.id == game_id) {
+        if !self.filtered.iter().any(|game| game.id == game_id) {
             self.filter.clear();
             self.apply_filter();
-            if let Some(filtered_pos) = self.filtered.iter().position(|game| game.id == game_id) {
-                self.cursor = filtered_pos;
-                self.ensure_cursor_visible();
-                return true;
-            }
-            // fallback: set cursor near original position based on full list
-            self.cursor = pos.min(self.filtered.len().saturating_sub(1));
-            self.ensure_cursor_visible();
         }
-
-        false
+        if !self.filtered.iter().any(|game| game.id == game_id) {
+            self.show_all_games = true;
+            self.apply_filter();
+        }
+        let Some(pos) = self.filtered.iter().position(|game| game.id == game_id) else {
+            return false;
+        };
+        self.cursor = pos;
+        self.ensure_cursor_visible();
+        true
     }
 
     fn set_status(&mut self, message: String) {
+        self.set_status_level(message, StatusLevel::Info);
+    }
+
+    fn set_info(&mut self, message: String) {
+        self.set_status_level(message, StatusLevel::Routine);
+    }
+
+    fn set_success(&mut self, message: String) {
+        self.set_status_level(message, StatusLevel::Success);
+    }
+
+    fn set_error(&mut self, message: String) {
+        self.set_status_level(message, StatusLevel::Error);
+    }
+
+    fn set_status_level(&mut self, message: String, level: StatusLevel) {
         self.status = message;
+        self.status_level = level;
+        self.status_at = Local::now();
+        self.status_tick = self.tick;
+    }
+
+    /// Reverts an error status to "Ready".
+    fn clear_error_status(&mut self) {
+        if self.status_level == StatusLevel::Error {
+            self.set_status(READY_STATUS.to_string());
+        }
+    }
+
+    /// Counts one UI tick and reverts a status older than `timeout` ticks to "Ready".
+    /// Errors are left until something replaces them; a `timeout` of 0 never clears.
+    fn tick_status(&mut self, timeout: u32) {
+        self.tick = self.tick.wrapping_add(1);
+        if timeout == 0 || self.status_level == StatusLevel::Error || self.status == READY_STATUS {
+            return;
+        }
+        if self.tick.wrapping_sub(self.status_tick) >= timeout {
+            self.set_status(READY_STATUS.to_string());
+        }
     }
 
     fn clamp_cursor(&mut self) {
@@ -3552,74 +6340,96 @@ fn game_matches(game: &GameInfo, needle: &str) -> bool {
     candidates.iter().any(|value| value.contains(needle))
 }
 
-/// Serializable chunk of UI state saved/restored between runs.
+/// Serializable chunk of UI state saved/restored between runs. Every field but the
+/// session is defaulted so older or newer saves still load.
 #[derive(Clone, Serialize, Deserialize)]
 struct PlayState {
-    session: GameSession,
+    /// Rules state; flattened so saves keep `session`, `phases`, etc. at the top level.
+    #[serde(flatten)]
+    game: Game,
+    #[serde(default)]
     corporation_index: usize,
+    #[serde(default)]
     market_cursor: (usize, usize),
+    #[serde(default)]
     mode: PlayMode,
     #[serde(default)]
     title_banner_visible: bool,
+    /// Keeps the banner through ordinary keys; only the market and revenue views hide it.
+    #[serde(default)]
+    title_banner_pinned: bool,
+    #[serde(default)]
     train_focus: TrainFocus,
+    #[serde(default)]
     train_pool_cursor: usize,
+    #[serde(default)]
     train_owned_cursor: usize,
+    #[serde(default)]
     train_run: Option<TrainRunState>,
+    #[serde(default)]
     market_row_offset: usize,
+    #[serde(default)]
     market_col_offset: usize,
+    #[serde(default)]
     market_view_rows: usize,
+    #[serde(default)]
     market_view_cols: usize,
+    /// Draw row/column numbers around the market grid.
+    #[serde(default)]
+    market_rulers: bool,
+    /// Share price cell of the corporation last picked in the revenue view; the market
+    /// cursor moves there when the market view comes back.
+    #[serde(default)]
+    preferred_market_cursor: Option<(usize, usize)>,
+    #[serde(default)]
     train_purchase_modal: Option<TrainPurchaseModal>,
-    phases: Vec<PhaseInfo>,
-    phase_index: usize,
-    phase_rounds: Vec<Vec<OperatingRound>>,
+    #[serde(default)]
     revenue_view: bool,
+    #[serde(default)]
     revenue_cursor_corp: usize,
+    #[serde(default)]
     revenue_cursor_or: usize,
+    #[serde(default)]
     revenue_row_offset: usize,
+    #[serde(default)]
     revenue_col_offset: usize,
+    #[serde(default)]
     revenue_view_rows: usize,
+    #[serde(default)]
     revenue_view_cols: usize,
+    #[serde(default)]
     revenue_input: Option<String>,
     #[serde(default)]
+    revenue_preset_mode: RevenuePresetMode,
+    /// Payout edits re-sync the corporation list and market focus; set from
+    /// `focus_follows_revenue`.
+    #[serde(skip)]
+    focus_follows_revenue: bool,
+    /// Last key pressed on a market cell, the cell and when, for `double_key_confirm`.
+    #[serde(skip)]
+    last_market_key: Option<(KeyCode, (usize, usize), Instant)>,
+    /// Digits typed during price selection; the cursor follows the cell with that value.
+    #[serde(default)]
+    price_input: Option<String>,
+    #[serde(default)]
     token_map: HashMap<String, String>,
+    /// Fields written by newer versions; kept so a load/save round-trip preserves them.
+    #[serde(flatten)]
+    extra: serde_json::Map<String, Value>,
 }
 
 impl PlayState {
-    /// Seeds the runtime state from a freshly loaded `GameSession`.
-    fn new(session: GameSession) -> Self {
+    /// Seeds the runtime state from a freshly loaded `GameSession`; phases without an
+    /// explicit OR count get `default_rounds`.
+    fn new(session: GameSession, default_rounds: usize) -> Self {
         let market_cursor = default_market_cursor(&session);
-        let phases = if session.phases.is_empty() {
-            vec![PhaseInfo {
-                name: "Phase".to_string(),
-                operating_rounds: 2,
-                raw: Value::Null,
-            }]
-        } else {
-            session
-                .phases
-                .iter()
-                .map(|value| PhaseInfo::from_value(value))
-                .collect()
-        };
-        let corp_count = session.corporations.len();
-        let mut phase_rounds = Vec::new();
-        for phase in &phases {
-            let count = phase.operating_rounds.max(1);
-            let mut rounds = Vec::new();
-            for _ in 0..count {
-                rounds.push(OperatingRound {
-                    revenues: vec![0; corp_count],
-                });
-            }
-            phase_rounds.push(rounds);
-        }
         let mut state = Self {
-            session,
+            game: Game::new(session, default_rounds),
             corporation_index: 0,
             market_cursor,
             mode: PlayMode::Idle,
             title_banner_visible: true,
+            title_banner_pinned: false,
             train_focus: TrainFocus::Pool,
             train_pool_cursor: 0,
             train_owned_cursor: 0,
@@ -3628,10 +6438,9 @@ impl PlayState {
             market_col_offset: 0,
             market_view_rows: 0,
             market_view_cols: 0,
+            market_rulers: false,
+            preferred_market_cursor: None,
             train_purchase_modal: None,
-            phases,
-            phase_index: 0,
-            phase_rounds,
             revenue_view: false,
             revenue_cursor_corp: 0,
             revenue_cursor_or: 0,
@@ -3640,7 +6449,12 @@ impl PlayState {
             revenue_view_rows: 1,
             revenue_view_cols: 1,
             revenue_input: None,
+            revenue_preset_mode: RevenuePresetMode::default(),
+            focus_follows_revenue: false,
+            last_market_key: None,
+            price_input: None,
             token_map: HashMap::new(),
+            extra: serde_json::Map::new(),
         };
         state.bootstrap_revenue_from_corporations();
         state.ensure_token_map();
@@ -3648,14 +6462,15 @@ impl PlayState {
     }
 
     fn ensure_token_map(&mut self) {
-        let needs_refresh = self.token_map.len() != self.session.corporations.len()
+        let needs_refresh = self.token_map.len() != self.game.session.corporations.len()
             || self
+                .game
                 .session
                 .corporations
                 .iter()
                 .any(|corp| !self.token_map.contains_key(&corp.sym));
         if needs_refresh {
-            self.token_map = assign_unique_tokens(&self.session.corporations);
+            self.token_map = assign_unique_tokens(&self.game.session.corporations);
         }
     }
 
@@ -3667,50 +6482,58 @@ impl PlayState {
     }
 
     fn phase_count(&self) -> usize {
-        self.phases.len()
+        self.game.phase_count()
     }
 
     fn current_phase_index(&self) -> usize {
-        self.phase_index.min(self.phases.len().saturating_sub(1))
+        self.game.current_phase_index()
     }
 
     fn current_phase(&self) -> Option<&PhaseInfo> {
-        self.phases.get(self.current_phase_index())
+        self.game.current_phase()
     }
 
     fn phase_label(&self) -> String {
         self.current_phase()
-            .map(|phase| phase.name.clone())
+            .map(PhaseInfo::label)
             .unwrap_or_else(|| "Phase".to_string())
     }
 
     fn set_phase_index(&mut self, index: usize) {
-        if self.phases.is_empty() {
-            self.phase_index = 0;
-            return;
-        }
-        let clamped = index.min(self.phases.len() - 1);
-        if clamped != self.phase_index {
-            self.phase_index = clamped;
-            self.ensure_phase_round_capacity(self.phase_index);
-            self.revenue_cursor_or = self
-                .revenue_cursor_or
-                .min(self.current_phase_rounds().len().saturating_sub(1));
-            if self.current_phase_rounds().is_empty() {
-                self.revenue_cursor_or = 0;
-            }
-            self.revenue_row_offset = 0;
-            self.revenue_col_offset = 0;
-            self.ensure_revenue_cursor_visible();
+        if self.game.set_phase_index(index) {
+            self.reset_revenue_view_for_phase();
         }
     }
 
+    /// Re-clamps the OR cursor and scroll after the active phase changed.
+    fn reset_revenue_view_for_phase(&mut self) {
+        self.revenue_cursor_or = self
+            .revenue_cursor_or
+            .min(self.current_phase_rounds().len().saturating_sub(1));
+        self.revenue_row_offset = 0;
+        self.revenue_col_offset = 0;
+        self.ensure_revenue_cursor_visible();
+    }
+
+    /// Appends a player-defined phase and switches to it.
+    fn add_custom_phase(&mut self, name: String, operating_rounds: usize) {
+        let index = self.game.add_custom_phase(name, operating_rounds);
+        self.set_phase_index(index);
+    }
+
+    /// Drops the most recently added custom phase along with its revenue rows.
+    fn remove_last_custom_phase(&mut self) -> Option<PhaseInfo> {
+        let removed = self.game.remove_last_custom_phase()?;
+        self.reset_revenue_view_for_phase();
+        Some(removed)
+    }
+
     fn move_phase(&mut self, delta: isize) {
-        if self.phases.is_empty() {
+        if self.game.phases.is_empty() {
             return;
         }
-        let len = self.phases.len() as isize;
-        let mut idx = self.phase_index as isize + delta;
+        let len = self.game.phases.len() as isize;
+        let mut idx = self.game.phase_index as isize + delta;
         if idx < 0 {
             idx = 0;
         } else if idx >= len {
@@ -3720,32 +6543,11 @@ impl PlayState {
     }
 
     fn ensure_phase_round_capacity(&mut self, phase_idx: usize) {
-        while self.phase_rounds.len() <= phase_idx {
-            self.phase_rounds.push(Vec::new());
-        }
-        let corp_count = self.session.corporations.len();
-        let desired = self
-            .phases
-            .get(phase_idx)
-            .map(|phase| phase.operating_rounds.max(1))
-            .unwrap_or(1);
-        let rounds = &mut self.phase_rounds[phase_idx];
-        if rounds.is_empty() {
-            rounds.extend((0..desired).map(|_| OperatingRound::new(corp_count)));
-        } else if rounds.len() < desired {
-            for _ in rounds.len()..desired {
-                rounds.push(OperatingRound::new(corp_count));
-            }
-        }
-        for round in rounds.iter_mut() {
-            if round.revenues.len() < corp_count {
-                round.revenues.resize(corp_count, 0);
-            }
-        }
+        self.game.ensure_phase_round_capacity(phase_idx);
     }
 
     fn bootstrap_revenue_from_corporations(&mut self) {
-        if self.session.corporations.is_empty() {
+        if self.game.session.corporations.is_empty() {
             return;
         }
         let phase_idx = self.current_phase_index();
@@ -3757,6 +6559,7 @@ impl PlayState {
         let target_or = self.revenue_cursor_or.min(max_index);
         self.revenue_cursor_or = target_or;
         let revenues: Vec<i32> = self
+            .game
             .session
             .corporations
             .iter()
@@ -3769,32 +6572,29 @@ impl PlayState {
     }
 
     fn current_phase_rounds(&self) -> &[OperatingRound] {
-        let idx = self.current_phase_index();
-        self.phase_rounds
-            .get(idx)
-            .map(|rounds| rounds.as_slice())
-            .unwrap_or(&[])
-    }
-
-    fn current_phase_rounds_mut(&mut self) -> &mut Vec<OperatingRound> {
-        let idx = self.current_phase_index();
-        self.ensure_phase_round_capacity(idx);
-        &mut self.phase_rounds[idx]
+        self.game.current_phase_rounds()
     }
 
     fn add_operating_round(&mut self) {
-        let corp_count = self.session.corporations.len();
-        if corp_count == 0 {
-            return;
+        if let Some(index) = self.game.add_operating_round() {
+            self.revenue_cursor_or = index;
+            self.ensure_revenue_cursor_visible();
         }
-        let rounds = self.current_phase_rounds_mut();
-        rounds.push(OperatingRound::new(corp_count));
-        self.revenue_cursor_or = rounds.len().saturating_sub(1);
+    }
+
+    /// Appends an OR holding a copy of the selected OR's revenues and moves the cursor
+    /// onto it.
+    fn add_operating_round_copying_current(&mut self) -> Option<usize> {
+        let index = self
+            .game
+            .add_operating_round_copying(self.revenue_cursor_or)?;
+        self.revenue_cursor_or = index;
         self.ensure_revenue_cursor_visible();
+        Some(index)
     }
 
     fn revenue_dimensions(&self) -> (usize, usize) {
-        let rows = self.session.corporations.len();
+        let rows = self.game.session.corporations.len();
         let cols = self.current_phase_rounds().len();
         (rows, cols)
     }
@@ -3823,14 +6623,24 @@ impl PlayState {
                 self.revenue_view_cols = 1;
             }
             self.ensure_revenue_cursor_visible();
+        } else if let Some(cursor) = self.preferred_market_cursor.take() {
+            self.market_cursor = cursor;
+            self.ensure_market_cursor_visible();
         }
         self.revenue_view
     }
 
+    /// Turns the revenue view on or off, leaving it untouched when already in that state.
+    fn set_revenue_view(&mut self, enabled: bool) {
+        if self.revenue_view != enabled {
+            self.toggle_revenue_view();
+        }
+    }
+
     fn sync_revenue_cursor_with_corp(&mut self) {
         let corp_index = self
             .corporation_index
-            .min(self.session.corporations.len().saturating_sub(1));
+            .min(self.game.session.corporations.len().saturating_sub(1));
         self.revenue_cursor_corp = corp_index;
     }
 
@@ -3873,6 +6683,14 @@ impl PlayState {
         self.revenue_col_offset = self.revenue_col_offset.min(max_col_offset);
     }
 
+    /// Pulls saved scroll offsets back into range after a restore; the terminal may be
+    /// smaller than when the state was saved. The next draw re-applies the live view size.
+    fn clamp_restored_view(&mut self) {
+        self.clamp_market_offsets();
+        self.ensure_market_cursor_visible();
+        self.ensure_revenue_cursor_visible();
+    }
+
     fn set_revenue_view_dims(&mut self, rows: usize, cols: usize) {
         self.revenue_view_rows = rows.max(1);
         self.revenue_view_cols = cols.max(1);
@@ -3906,10 +6724,29 @@ impl PlayState {
 
         self.revenue_cursor_corp = row as usize;
         self.revenue_cursor_or = col as usize;
-        self.corporation_index = self.revenue_cursor_corp;
+        self.sync_focus_from_revenue();
         self.ensure_revenue_cursor_visible();
     }
 
+    /// Points the corporation list at the revenue cursor's row and remembers that
+    /// corporation's share price as the market cursor for when the market view returns.
+    fn sync_focus_from_revenue(&mut self) {
+        self.corporation_index = self.revenue_cursor_corp;
+        self.preferred_market_cursor = self
+            .current_corporation()
+            .and_then(|corp| corp.market_position.as_ref())
+            .map(|position| (position.row, position.col));
+    }
+
+    /// Moves the revenue cursor by whole visible pages of rows and/or columns, clamped
+    /// to the grid.
+    fn page_revenue_cursor(&mut self, row_pages: isize, col_pages: isize) {
+        self.move_revenue_cursor(
+            row_pages * self.revenue_view_rows.max(1) as isize,
+            col_pages * self.revenue_view_cols.max(1) as isize,
+        );
+    }
+
     fn advance_operating_round(&mut self) -> bool {
         let phase_idx = self.current_phase_index();
         self.ensure_phase_round_capacity(phase_idx);
@@ -3942,48 +6779,185 @@ impl PlayState {
         format!("OR {} of {}", current, total)
     }
 
-    fn set_revenue_value(&mut self, row: usize, col: usize, value: i32) {
-        let corp_count = self.session.corporations.len();
-        if corp_count == 0 || row >= corp_count {
-            return;
-        }
-        let phase_idx = self.current_phase_index();
-        self.ensure_phase_round_capacity(phase_idx);
-        if self.phase_rounds.len() <= phase_idx {
-            return;
-        }
-        let rounds = &mut self.phase_rounds[phase_idx];
-        if col >= rounds.len() {
-            let missing = col + 1 - rounds.len();
-            for _ in 0..missing {
-                rounds.push(OperatingRound::new(corp_count));
+    /// Linear, undecorated description of the game for screen readers: the selected
+    /// corporation, operating order, then every corporation in listing order.
+    fn accessible_summary(&self, currency: &CurrencyFormat) -> Vec<String> {
+        let price = |position: &MarketPosition| match position.numeric_value() {
+            Some(value) => currency.format(value),
+            None => display_price_label(&position.raw),
+        };
+        let mut lines = vec![
+            self.game.session.info.title.clone(),
+            format!(
+                "Phase: {}, {}",
+                self.phase_label(),
+                self.operating_round_summary()
+            ),
+            String::new(),
+        ];
+
+        match self.current_corporation() {
+            Some(corp) => {
+                lines.push(format!("Selected: {} ({})", corp.name, corp.sym));
+                lines.push(match corp.par_value {
+                    Some(par) => format!("Par: {}", currency.format(par)),
+                    None => "Par: not set".to_string(),
+                });
+                lines.push(match &corp.market_position {
+                    Some(position) => format!(
+                        "Share price: {}, market row {}, column {}",
+                        price(position),
+                        position.row + 1,
+                        position.col + 1
+                    ),
+                    None => "Share price: not on the market".to_string(),
+                });
+                lines.push(format!(
+                    "Last revenue: {}",
+                    currency.format(corp.last_revenue)
+                ));
+                let heading = match self.train_limit() {
+                    Some(limit) => format!("Trains ({} of {limit})", corp.trains.len()),
+                    None => "Trains".to_string(),
+                };
+                if corp.trains.is_empty() {
+                    lines.push(format!("{heading}: none"));
+                } else {
+                    let trains: Vec<String> = corp
+                        .trains
+                        .iter()
+                        .map(|train| match train.revenue_stops.is_empty() {
+                            true => format!("{} (no run yet)", train.name),
+                            false => format!(
+                                "{} (last run {})",
+                                train.name,
+                                currency.format(train.last_revenue)
+                            ),
+                        })
+                        .collect();
+                    lines.push(format!("{heading}: {}", trains.join(", ")));
+                }
             }
+            None => lines.push(format!(
+                "Selected: none. {}",
+                self.missing_corporation_message()
+            )),
+        }
+        lines.push(String::new());
+
+        let order = self.game.operating_order();
+        if order.is_empty() {
+            lines.push("Operating order: no corporation has a share price yet".to_string());
+        } else {
+            let ranked: Vec<String> = order
+                .iter()
+                .enumerate()
+                .filter_map(|(rank, idx)| {
+                    let corp = self.game.corporation(*idx)?;
+                    let position = corp.market_position.as_ref()?;
+                    Some(format!("{}. {} {}", rank + 1, corp.sym, price(position)))
+                })
+                .collect();
+            lines.push(format!("Operating order: {}", ranked.join("; ")));
         }
-        if let Some(round) = rounds.get_mut(col) {
-            if round.revenues.len() < corp_count {
-                round.revenues.resize(corp_count, 0);
+
+        lines.push("Corporations:".to_string());
+        for corp in &self.game.session.corporations {
+            let mut details = Vec::new();
+            match corp.par_value {
+                Some(par) => details.push(format!("par {}", currency.format(par))),
+                None => details.push("not parred".to_string()),
             }
-            round.revenues[row] = value;
+            if let Some(position) = &corp.market_position {
+                details.push(format!("price {}", price(position)));
+            }
+            match corp.trains.len() {
+                0 => {}
+                1 => details.push("1 train".to_string()),
+                count => details.push(format!("{count} trains")),
+            }
+            lines.push(format!(
+                "{} {}: {}",
+                corp.sym,
+                corp.name,
+                details.join(", ")
+            ));
         }
+        lines
+    }
+
+    fn set_revenue_value(&mut self, row: usize, col: usize, value: i32) {
+        self.game.set_revenue(row, col, value);
     }
 
     fn current_revenue_value(&self) -> Option<i32> {
         let (row, col) = self.revenue_cursor();
-        let phase_idx = self.current_phase_index();
-        self.phase_rounds
-            .get(phase_idx)
-            .and_then(|rounds| rounds.get(col))
-            .and_then(|round| round.revenues.get(row))
-            .copied()
+        self.game.revenue(row, col)
     }
 
     fn current_revenue_base(&self) -> Option<i32> {
         self.current_corporation().map(|corp| corp.last_revenue)
     }
 
+    /// Payout digit `digit` (1-6) enters under the current preset mode; `None` when a
+    /// percentage has no corporation to take it of.
+    fn revenue_preset_value(&self, digit: i32) -> Option<i32> {
+        match self.revenue_preset_mode {
+            RevenuePresetMode::Percent => self
+                .current_revenue_base()
+                .map(|base| revenue::dividend_per_share(base, digit * 10)),
+            RevenuePresetMode::Absolute => Some(digit * 10),
+        }
+    }
+
+    /// Labels for the preset line under the revenue grid, one per digit, with whether the
+    /// selected payout equals that preset. Follows the preset mode: percentages of the
+    /// last revenue (`-` without one), or the literal `$10`-`$60`.
+    fn revenue_preset_entries(&self, currency: &CurrencyFormat) -> Vec<(String, bool)> {
+        let active = self.current_revenue_value().unwrap_or_default();
+        let base = self.current_revenue_base().unwrap_or_default();
+        (1..=6)
+            .map(|digit| {
+                let value = self.revenue_preset_value(digit).unwrap_or_default();
+                let label = match self.revenue_preset_mode {
+                    RevenuePresetMode::Percent if base == 0 => format!("{:>2}%:-", digit * 10),
+                    RevenuePresetMode::Percent => {
+                        format!("{:>2}%:{}", digit * 10, currency.format(value))
+                    }
+                    RevenuePresetMode::Absolute => format!("{digit}:{}", currency.format(value)),
+                };
+                let usable = self.revenue_preset_mode == RevenuePresetMode::Absolute || base != 0;
+                (label, usable && value == active)
+            })
+            .collect()
+    }
+
+    /// Sets the selected payout to the corporation's current share price. Returns the
+    /// price, or `None` when the corporation has no numeric market position.
+    fn copy_market_price_to_revenue(&mut self) -> Option<i32> {
+        let price = self
+            .current_corporation()?
+            .market_position
+            .as_ref()?
+            .numeric_value()?;
+        self.set_current_revenue_value(price);
+        Some(price)
+    }
+
+    fn toggle_revenue_preset_mode(&mut self) -> RevenuePresetMode {
+        self.revenue_preset_mode = match self.revenue_preset_mode {
+            RevenuePresetMode::Percent => RevenuePresetMode::Absolute,
+            RevenuePresetMode::Absolute => RevenuePresetMode::Percent,
+        };
+        self.revenue_preset_mode
+    }
+
     fn set_current_revenue_value(&mut self, value: i32) {
         let (row, col) = self.revenue_cursor();
         self.set_revenue_value(row, col, value.max(0));
+        if self.focus_follows_revenue {
+            self.sync_focus_from_revenue();
+        }
     }
 
     fn adjust_current_revenue_value(&mut self, delta: i32) {
@@ -4006,19 +6980,148 @@ impl PlayState {
     }
 
     fn consume_title_banner(&mut self) {
-        self.title_banner_visible = false;
+        if !self.title_banner_pinned {
+            self.title_banner_visible = false;
+        }
+    }
+
+    /// Pins (and shows) or unpins (and hides) the title banner.
+    fn set_title_banner_pinned(&mut self, pinned: bool) {
+        self.title_banner_pinned = pinned;
+        self.title_banner_visible = pinned;
+    }
+
+    fn toggle_title_banner_pin(&mut self) -> bool {
+        self.set_title_banner_pinned(!self.title_banner_pinned);
+        self.title_banner_pinned
     }
 
     fn current_corporation(&self) -> Option<&Corporation> {
-        self.session.corporations.get(self.corporation_index)
+        self.game.corporation(self.corporation_index)
+    }
+
+    fn has_corporations(&self) -> bool {
+        !self.game.session.corporations.is_empty()
+    }
+
+    /// Values that differ between this state and `other`: the phase, then per corporation
+    /// (matched by symbol) its par, share price, last revenue and trains.
+    fn compare(&self, other: &PlayState, currency: &CurrencyFormat) -> Vec<ComparisonRow> {
+        let mut rows = Vec::new();
+        let mut push = |sym: &str, field, left: String, right: String| {
+            if left != right {
+                rows.push(ComparisonRow {
+                    sym: sym.to_string(),
+                    field,
+                    left,
+                    right,
+                });
+            }
+        };
+        push("", "Phase", self.phase_label(), other.phase_label());
+
+        let facts = |state: &PlayState, sym: &str| -> Vec<(&'static str, String)> {
+            let Some(corp) = state
+                .game
+                .session
+                .corporations
+                .iter()
+                .find(|corp| corp.sym == sym)
+            else {
+                return Vec::new();
+            };
+            let price = match &corp.market_position {
+                Some(position) => match position.numeric_value() {
+                    Some(value) => currency.format(value),
+                    None => display_price_label(&position.raw),
+                },
+                None => "-".to_string(),
+            };
+            let trains: Vec<&str> = corp
+                .trains
+                .iter()
+                .map(|train| train.name.as_str())
+                .collect();
+            vec![
+                (
+                    "par",
+                    corp.par_value
+                        .map_or("-".to_string(), |par| currency.format(par)),
+                ),
+                ("price", price),
+                ("revenue", currency.format(corp.last_revenue)),
+                (
+                    "trains",
+                    if trains.is_empty() {
+                        "-".to_string()
+                    } else {
+                        trains.join(" ")
+                    },
+                ),
+            ]
+        };
+        let mut symbols: Vec<&str> = Vec::new();
+        for corp in self
+            .game
+            .session
+            .corporations
+            .iter()
+            .chain(&other.game.session.corporations)
+        {
+            if !symbols.contains(&corp.sym.as_str()) {
+                symbols.push(&corp.sym);
+            }
+        }
+        for sym in symbols {
+            let (left, right) = (facts(self, sym), facts(other, sym));
+            if left.is_empty() || right.is_empty() {
+                let present = |facts: &[(&str, String)]| {
+                    if facts.is_empty() {
+                        "missing"
+                    } else {
+                        "present"
+                    }
+                    .to_string()
+                };
+                push(sym, "corporation", present(&left), present(&right));
+                continue;
+            }
+            for ((field, left), (_, right)) in left.into_iter().zip(right) {
+                push(sym, field, left, right);
+            }
+        }
+        rows
+    }
+
+    /// Status for corporation commands issued with nothing selected; games whose entities
+    /// yielded no corporations get an explanation instead.
+    fn missing_corporation_message(&self) -> &'static str {
+        if self.has_corporations() {
+            "No corporation selected"
+        } else {
+            NO_CORPORATIONS_MESSAGE
+        }
     }
 
     fn current_corporation_mut(&mut self) -> Option<&mut Corporation> {
-        self.session.corporations.get_mut(self.corporation_index)
+        self.game.corporation_mut(self.corporation_index)
+    }
+
+    /// Selects the next corporation without a par, searching forward from the current one
+    /// and wrapping once. Returns its index, or `None` when every corporation is parred.
+    fn next_unparred_corporation(&mut self) -> Option<usize> {
+        let len = self.game.session.corporations.len();
+        let index = (1..=len)
+            .map(|step| (self.corporation_index + step) % len)
+            .find(|&index| self.game.session.corporations[index].par_value.is_none())?;
+        self.corporation_index = index;
+        self.sync_revenue_cursor_with_corp();
+        self.ensure_revenue_cursor_visible();
+        Some(index)
     }
 
     fn move_corporation(&mut self, delta: isize) {
-        let len = self.session.corporations.len();
+        let len = self.game.session.corporations.len();
         if len == 0 {
             return;
         }
@@ -4038,30 +7141,24 @@ impl PlayState {
         self.market_cursor
     }
 
-    fn current_market_cell(&self) -> Option<&MarketCell> {
-        let (row, col) = self.market_cursor;
-        self.session.market_cell(row, col)
-    }
-
     fn move_market_cursor(&mut self, row_delta: isize, col_delta: isize) {
         if row_delta == 0 && col_delta == 0 {
             return;
         }
-        if self.session.market.is_empty() {
+        if self.game.session.market.is_empty() {
             return;
         }
 
-        let row_count = self.session.market.len();
+        let row_count = self.game.session.market.len();
         if row_count == 1 {
-            let row_len = self.session.market[0].len();
-            if row_len == 0 {
-                return;
-            }
-            if col_delta != 0 {
-                let len = row_len as isize;
-                let mut col = self.market_cursor.1 as isize + col_delta;
-                col = ((col % len) + len) % len;
-                self.market_cursor = (0, col as usize);
+            // Browsing a single row wraps around its ends; share price moves never do.
+            let next =
+                self.game
+                    .next_market_cell(0, self.market_cursor.1, col_delta, true, |cell| {
+                        self.mode != PlayMode::ParSelect || self.is_par_cell(cell.row, cell.col)
+                    });
+            if let Some(position) = next {
+                self.market_cursor = (position.row, position.col);
                 self.ensure_market_cursor_visible();
             }
             return;
@@ -4069,6 +7166,10 @@ impl PlayState {
 
         let mut row = self.market_cursor.0 as isize;
         let mut col = self.market_cursor.1 as isize;
+        // A cursor left past the end of a short row steps back in from the row's edge.
+        if let Some(cells) = self.game.session.market.get(self.market_cursor.0) {
+            col = col.min(cells.len() as isize);
+        }
         let mut attempts = 0usize;
         let max_attempts = row_count
             .saturating_mul(self.max_market_columns().max(1))
@@ -4084,27 +7185,30 @@ impl PlayState {
             if row_usize >= row_count {
                 break;
             }
-            let row_len = self.session.market[row_usize].len();
+            let row_len = self.game.session.market[row_usize].len();
             if row_len == 0 {
                 continue;
             }
-            if col as usize >= row_len {
-                if row_delta == 0 {
-                    break;
-                } else {
-                    col = (row_len - 1) as isize;
-                }
-            }
-            if col < 0 {
-                if row_delta == 0 {
-                    break;
-                } else {
-                    col = 0;
+            if row_delta != 0 {
+                // Moving into a shorter row lands on its last cell at or before the column.
+                match self.clamp_market_column(row_usize, col.max(0) as usize) {
+                    Some(clamped) => col = clamped as isize,
+                    None => continue,
                 }
+            } else if col < 0 || col as usize >= row_len {
+                break;
             }
             let col_usize = col as usize;
-            if let Some(cell) = self.session.market_cell(row_usize, col_usize) {
+            if let Some(cell) = self.game.session.market_cell(row_usize, col_usize) {
                 if self.mode == PlayMode::ParSelect && !self.is_par_cell(cell.row, cell.col) {
+                    // Vertical moves land on the closest par cell of the next row that has one.
+                    if row_delta != 0 {
+                        if let Some(par_col) = self.nearest_par_column(row_usize, col_usize) {
+                            self.market_cursor = (row_usize, par_col);
+                            self.ensure_market_cursor_visible();
+                            break;
+                        }
+                    }
                     continue;
                 }
                 self.market_cursor = (cell.row, cell.col);
@@ -4117,6 +7221,53 @@ impl PlayState {
         }
     }
 
+    /// Moves the market cursor one view height or width in the given direction, a cell at
+    /// a time so par-only and ragged rows are honoured; stops at the edge rather than
+    /// wrapping.
+    fn page_market_cursor(&mut self, row_dir: isize, col_dir: isize) {
+        let steps = if row_dir != 0 {
+            self.market_view_rows()
+        } else {
+            self.market_view_cols()
+        };
+        for _ in 0..steps {
+            let before = self.market_cursor;
+            self.move_market_cursor(row_dir, col_dir);
+            let (row, col) = self.market_cursor;
+            let forward = (row as isize - before.0 as isize) * row_dir >= 0
+                && (col as isize - before.1 as isize) * col_dir >= 0;
+            if self.market_cursor == before || !forward {
+                self.market_cursor = before;
+                self.ensure_market_cursor_visible();
+                break;
+            }
+        }
+    }
+
+    /// Moves the cursor to the first or last usable cell of its row; in par selection
+    /// only par cells qualify. Returns `false` when the row has no such cell.
+    fn jump_market_column(&mut self, to_end: bool) -> bool {
+        let row = self.market_cursor.0;
+        let Some(cells) = self.game.session.market.get(row) else {
+            return false;
+        };
+        let usable = |col: &usize| {
+            self.game.session.market_cell(row, *col).is_some()
+                && (self.mode != PlayMode::ParSelect || self.is_par_cell(row, *col))
+        };
+        let target = if to_end {
+            (0..cells.len()).rev().find(usable)
+        } else {
+            (0..cells.len()).find(usable)
+        };
+        let Some(col) = target else {
+            return false;
+        };
+        self.market_cursor = (row, col);
+        self.ensure_market_cursor_visible();
+        true
+    }
+
     fn enter_par_select(&mut self) -> bool {
         let corp_sym = self.current_corporation().map(|corp| corp.sym.clone());
         debug!(
@@ -4133,17 +7284,24 @@ impl PlayState {
         }
 
         if !self.is_par_cell(self.market_cursor.0, self.market_cursor.1) {
-            if let Some(cell) = self.session.par_cells.first() {
-                self.market_cursor = (cell.row, cell.col);
-            } else if let Some(cell) = self.session.market_cells.first() {
-                self.market_cursor = (cell.row, cell.col);
-            } else {
+            let (row, col) = self.market_cursor;
+            let nearest = self
+                .game
+                .session
+                .market_cells
+                .iter()
+                .filter(|cell| self.is_par_cell(cell.row, cell.col))
+                .min_by_key(|cell| cell.row.abs_diff(row) + cell.col.abs_diff(col));
+            let Some(cell) = nearest else {
                 debug!(?corp_sym, "enter_par_select: no market cells available");
                 return false;
-            }
+            };
+            self.market_cursor = (cell.row, cell.col);
         }
 
         self.mode = PlayMode::ParSelect;
+        // The market needs the banner's space, pinned or not.
+        self.title_banner_visible = false;
         debug!(
             ?corp_sym,
             cursor = ?self.market_cursor,
@@ -4164,6 +7322,8 @@ impl PlayState {
             }
         }
         self.mode = PlayMode::PriceSelect;
+        self.price_input = None;
+        self.title_banner_visible = false;
         debug!(
             cursor = ?self.market_cursor,
             "enter_price_select success"
@@ -4174,52 +7334,151 @@ impl PlayState {
     fn exit_market(&mut self) {
         debug!(previous_mode = ?self.mode, "exit_market called");
         self.mode = PlayMode::Idle;
+        self.price_input = None;
+        self.last_market_key = None;
+    }
+
+    /// Records a market key press on `cell` at `now`; true when the same key was pressed
+    /// on the same cell within [`DOUBLE_KEY_WINDOW`]. A double press starts a fresh window.
+    fn register_market_key(&mut self, code: KeyCode, cell: (usize, usize), now: Instant) -> bool {
+        let double = self.last_market_key.is_some_and(|(last, last_cell, at)| {
+            last == code
+                && last_cell == cell
+                && now.saturating_duration_since(at) <= DOUBLE_KEY_WINDOW
+        });
+        self.last_market_key = if double {
+            None
+        } else {
+            Some((code, cell, now))
+        };
+        double
+    }
+
+    /// Market cell holding exactly `value`, preferring the current corporation's price
+    /// row, then the first match in row order.
+    fn price_cell_for_value(&self, value: i32) -> Option<(usize, usize)> {
+        let current_row = self
+            .current_corporation()
+            .and_then(|corp| corp.market_position.as_ref())
+            .map(|pos| pos.row);
+        let mut matches = self
+            .game
+            .session
+            .market_cells
+            .iter()
+            .filter(|cell| cell.value == Some(value))
+            .map(|cell| (cell.row, cell.col));
+        let first = matches.next()?;
+        let in_row = std::iter::once(first)
+            .chain(matches)
+            .find(|&(row, _)| Some(row) == current_row);
+        Some(in_row.unwrap_or(first))
+    }
+
+    /// Appends a typed digit to the price entry and moves the cursor to the matching
+    /// cell. Returns the typed value and whether a cell holds it.
+    fn type_price_digit(&mut self, ch: char) -> Option<(i32, bool)> {
+        let mut input = self.price_input.take().unwrap_or_default();
+        input.push(ch);
+        let value: i32 = input.parse().ok()?;
+        self.price_input = Some(input);
+        let Some(cell) = self.price_cell_for_value(value) else {
+            return Some((value, false));
+        };
+        self.market_cursor = cell;
+        self.ensure_market_cursor_visible();
+        Some((value, true))
     }
 
     fn apply_par_selection(&mut self) -> Option<i32> {
-        let cell = self.current_market_cell()?.clone();
-        debug!(
-            cursor = ?self.market_cursor,
-            row = cell.row,
-            col = cell.col,
-            raw = %cell.raw,
-            "apply_par_selection start"
-        );
-        let value = cell.value.unwrap_or(0);
-        {
-            let corp = self.current_corporation_mut()?;
-            corp.par_value = Some(value);
-            corp.market_position = Some(cell_to_position(&cell));
-            debug!(sym = %corp.sym, value, "apply_par_selection updated corporation");
-        }
+        let (row, col) = self.market_cursor;
+        let value = self.game.set_par(self.corporation_index, row, col)?;
         self.mode = PlayMode::Idle;
+        self.last_market_key = None;
         self.ensure_market_cursor_visible();
-        debug!(
-            row = cell.row,
-            col = cell.col,
-            value,
-            "apply_par_selection completed"
-        );
+        debug!(row, col, value, "apply_par_selection completed");
         Some(value)
     }
 
     fn apply_price_selection(&mut self) -> Option<MarketPosition> {
-        let cell = self.current_market_cell()?.clone();
-        let position = cell_to_position(&cell);
-        {
-            let corp = self.current_corporation_mut()?;
-            corp.market_position = Some(position.clone());
-        }
+        let (row, col) = self.market_cursor;
+        let position = self.game.set_price(self.corporation_index, row, col)?;
         self.mode = PlayMode::Idle;
+        self.last_market_key = None;
         self.ensure_market_cursor_visible();
         Some(position)
     }
 
-    fn is_par_cell(&self, row: usize, col: usize) -> bool {
-        self.session
-            .par_cells
+    /// Whether the market flags any cell as a par slot. Without such flags every cell is a
+    /// valid par choice.
+    fn has_marked_par_cells(&self) -> bool {
+        self.game
+            .session
+            .market_cells
             .iter()
-            .any(|cell| cell.row == row && cell.col == col)
+            .any(|cell| cell.is_par)
+    }
+
+    /// One-line guide shown under the market during par selection.
+    fn par_legend(&self, currency: &CurrencyFormat) -> String {
+        let spaces = match self.game.session.par_cells.len() {
+            0 => "Any cell can be par".to_string(),
+            1 => "1 par space".to_string(),
+            count => format!("{count} par spaces"),
+        };
+        let mut legend = format!("{spaces} · hjkl to move · p/Enter to set");
+        if let Some(par) = self.current_corporation().and_then(|corp| corp.par_value) {
+            legend.push_str(&format!(" · current par {}", currency.format(par)));
+        }
+        legend
+    }
+
+    /// One-line guide shown under the market during price selection, with the place the
+    /// hovered price would give the corporation in operating order.
+    fn price_legend(&self, currency: &CurrencyFormat) -> String {
+        let (row, col) = self.market_cursor;
+        let price = self
+            .game
+            .session
+            .market_cell(row, col)
+            .and_then(|cell| cell.value);
+        let rank = self
+            .game
+            .operating_rank_at(self.corporation_index, row, col);
+        match (price, rank) {
+            (Some(price), Some(rank)) => format!(
+                "{} would be #{rank} in operating order · Enter to set",
+                currency.format(price)
+            ),
+            _ => "hjkl to move · Enter to set".to_string(),
+        }
+    }
+
+    fn is_par_cell(&self, row: usize, col: usize) -> bool {
+        match self.game.session.market_cell(row, col) {
+            Some(cell) if self.has_marked_par_cells() => cell.is_par,
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Last column of `row` at or before `col` that holds a market cell, falling back to
+    /// the row's first cell; `None` when the row has no cells at all.
+    fn clamp_market_column(&self, row: usize, col: usize) -> Option<usize> {
+        let len = self.game.session.market.get(row)?.len();
+        let has_cell = |candidate: &usize| self.game.session.market_cell(row, *candidate).is_some();
+        (0..len.min(col + 1))
+            .rev()
+            .find(has_cell)
+            .or_else(|| (0..len).find(has_cell))
+    }
+
+    /// Column of the par cell in `row` closest to `col`, if the row has any.
+    fn nearest_par_column(&self, row: usize, col: usize) -> Option<usize> {
+        let len = self.game.session.market.get(row)?.len();
+        (0..len)
+            .filter(|candidate| self.is_par_cell(row, *candidate))
+            .min_by_key(|candidate| candidate.abs_diff(col))
     }
 
     fn enter_train_manage(&mut self) -> bool {
@@ -4255,18 +7514,12 @@ impl PlayState {
         self.train_focus
     }
 
-    fn toggle_train_focus(&mut self) {
+    /// Moves focus to the other train section; unlike `h`/`l` it never falls back, so
+    /// focus stays put (and this returns false) when the other section is empty.
+    fn toggle_train_focus(&mut self) -> bool {
         match self.train_focus {
-            TrainFocus::Owned => {
-                if !self.focus_pool_internal() {
-                    self.focus_owned_internal();
-                }
-            }
-            TrainFocus::Pool => {
-                if !self.focus_owned_internal() {
-                    self.focus_pool_internal();
-                }
-            }
+            TrainFocus::Owned => self.focus_pool_internal(),
+            TrainFocus::Pool => self.focus_owned_internal(),
         }
     }
 
@@ -4314,8 +7567,26 @@ impl PlayState {
         }
     }
 
+    fn market_rulers(&self) -> bool {
+        self.market_rulers
+    }
+
+    fn toggle_market_rulers(&mut self) -> bool {
+        self.market_rulers = !self.market_rulers;
+        self.market_rulers
+    }
+
+    /// Width of the row-number gutter drawn when rulers are on, including its trailing space.
+    fn market_ruler_gutter(&self) -> usize {
+        if self.market_rulers {
+            self.game.session.market.len().max(1).to_string().len() + 1
+        } else {
+            0
+        }
+    }
+
     fn set_market_view(&mut self, rows: usize, cols: usize) {
-        let total_rows = self.session.market.len();
+        let total_rows = self.game.session.market.len();
         if total_rows > 0 && total_rows <= rows {
             self.market_view_rows = total_rows.max(1);
             self.market_row_offset = 0;
@@ -4332,9 +7603,66 @@ impl PlayState {
         }
 
         self.clamp_market_offsets();
+        self.snap_cursor_to_valid();
         self.ensure_market_cursor_visible();
     }
 
+    /// Whether the cursor may rest on `(row, col)`: a cell with text, and a par cell
+    /// while choosing a par.
+    fn is_cursor_cell(&self, row: usize, col: usize) -> bool {
+        self.game
+            .session
+            .market_cell(row, col)
+            .is_some_and(|cell| !cell.raw.trim().is_empty())
+            && (self.mode != PlayMode::ParSelect || self.is_par_cell(row, col))
+    }
+
+    /// Moves the market cursor to the closest cell it may rest on, searching outward
+    /// breadth-first across the whole grid. Returns `true` when the cursor moved.
+    fn snap_cursor_to_valid(&mut self) -> bool {
+        let rows = self.game.session.market.len();
+        let cols = self.max_market_columns();
+        if rows == 0 || cols == 0 {
+            return false;
+        }
+        let start = (
+            self.market_cursor.0.min(rows - 1),
+            self.market_cursor.1.min(cols - 1),
+        );
+        if start == self.market_cursor && self.is_cursor_cell(start.0, start.1) {
+            return false;
+        }
+
+        let mut seen = vec![vec![false; cols]; rows];
+        let mut queue = VecDeque::from([start]);
+        seen[start.0][start.1] = true;
+        while let Some((row, col)) = queue.pop_front() {
+            if self.is_cursor_cell(row, col) {
+                self.market_cursor = (row, col);
+                self.ensure_market_cursor_visible();
+                return true;
+            }
+            let neighbours = [
+                (row.checked_sub(1), Some(col)),
+                (Some(row + 1).filter(|row| *row < rows), Some(col)),
+                (Some(row), col.checked_sub(1)),
+                (Some(row), Some(col + 1).filter(|col| *col < cols)),
+            ];
+            for (next_row, next_col) in neighbours {
+                let (Some(next_row), Some(next_col)) = (next_row, next_col) else {
+                    continue;
+                };
+                if !seen[next_row][next_col] {
+                    seen[next_row][next_col] = true;
+                    queue.push_back((next_row, next_col));
+                }
+            }
+        }
+        // Nothing valid anywhere; at least keep the cursor inside the grid.
+        self.market_cursor = start;
+        false
+    }
+
     fn market_row_offset(&self) -> usize {
         self.market_row_offset
     }
@@ -4364,7 +7692,11 @@ impl PlayState {
                 .iter()
                 .position(|(idx, _, _)| *idx == self.pool_train_cursor())
                 .unwrap_or(0);
-            self.train_purchase_modal = Some(TrainPurchaseModal { cursor, offset: 0 });
+            self.train_purchase_modal = Some(TrainPurchaseModal {
+                cursor,
+                offset: 0,
+                query: String::new(),
+            });
         }
     }
 
@@ -4372,8 +7704,55 @@ impl PlayState {
         self.train_purchase_modal = None;
     }
 
+    /// Indices into `available_trains` whose names match the modal's filter.
+    fn purchase_matches(&self) -> Vec<usize> {
+        let query = self
+            .train_purchase_modal
+            .as_ref()
+            .map(|modal| modal.query.as_str())
+            .unwrap_or_default();
+        let available = self.available_trains();
+        prefix_matches(available.iter().map(|(_, ty, _)| ty.name.as_str()), query)
+    }
+
+    /// Selection index (into `available_trains`) of the highlighted filtered entry.
+    fn purchase_modal_selection(&self) -> Option<usize> {
+        let cursor = self.train_purchase_modal.as_ref()?.cursor;
+        self.purchase_matches().get(cursor).copied()
+    }
+
+    fn push_purchase_query(&mut self, ch: char) {
+        if let Some(modal) = &mut self.train_purchase_modal {
+            modal.query.push(ch);
+            modal.cursor = 0;
+            modal.offset = 0;
+        }
+    }
+
+    fn pop_purchase_query(&mut self) {
+        if let Some(modal) = &mut self.train_purchase_modal {
+            if modal.query.pop().is_some() {
+                modal.cursor = 0;
+                modal.offset = 0;
+            }
+        }
+    }
+
+    /// Empties the filter; returns `false` when there was nothing to clear.
+    fn clear_purchase_query(&mut self) -> bool {
+        match &mut self.train_purchase_modal {
+            Some(modal) if !modal.query.is_empty() => {
+                modal.query.clear();
+                modal.cursor = 0;
+                modal.offset = 0;
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn move_purchase_modal_cursor(&mut self, delta: isize) {
-        let len = self.available_trains().len();
+        let len = self.purchase_matches().len();
         if len == 0 {
             if let Some(modal) = &mut self.train_purchase_modal {
                 modal.cursor = 0;
@@ -4397,7 +7776,7 @@ impl PlayState {
     }
 
     fn train_type_for(&self, name: &str) -> Option<&TrainType> {
-        self.session.train_types.iter().find(|ty| ty.name == name)
+        self.game.train_type_for(name)
     }
 
     fn train_stop_limit_for(&self, name: &str) -> Option<usize> {
@@ -4477,44 +7856,49 @@ impl PlayState {
     }
 
     fn available_trains(&self) -> Vec<(usize, &TrainType, i64)> {
-        self.session
-            .train_types
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, ty)| {
-                let remaining = self
-                    .session
-                    .train_pool
-                    .get(idx)
-                    .map(|entry| entry.remaining)
-                    .unwrap_or(0);
-                if remaining > 0 {
-                    Some((idx, ty, remaining))
-                } else {
-                    None
-                }
-            })
-            .collect()
+        self.game.available_trains()
+    }
+
+    fn train_supply(&self) -> Vec<(&TrainType, i64)> {
+        self.game.train_supply()
+    }
+
+    fn train_limit(&self) -> Option<usize> {
+        self.game.train_limit(self.corporation_index)
+    }
+
+    fn at_train_limit(&self) -> bool {
+        self.game.at_train_limit(self.corporation_index)
     }
 
     fn purchase_available_train(&mut self, selection: usize) -> Option<CorporationTrain> {
-        let available = self.available_trains();
-        let (idx, _, _) = *available.get(selection)?;
-        drop(available);
+        self.game.purchase_train(selection)
+    }
 
-        let ty = self.session.train_types.get(idx)?.clone();
-        let pool_entry = self.session.train_pool.get_mut(idx)?;
-        if pool_entry.remaining <= 0 {
-            return None;
+    /// Raw engine data behind whatever the current mode has focused, for the debug inspector.
+    fn inspect_focus(&self) -> Option<(String, Value)> {
+        match self.mode {
+            PlayMode::ParSelect | PlayMode::PriceSelect => {
+                let (row, col) = self.market_cursor;
+                let cell = self.game.session.market_cell(row, col)?;
+                Some((format!("market cell {row},{col}"), to_value(cell).ok()?))
+            }
+            PlayMode::TrainManage | PlayMode::TrainRun => match self.train_focus {
+                TrainFocus::Owned => {
+                    let train = self.current_owned_train()?;
+                    Some((format!("train {}", train.name), to_value(train).ok()?))
+                }
+                TrainFocus::Pool => {
+                    let available = self.available_trains();
+                    let (_, ty, _) = available.get(self.train_pool_cursor)?;
+                    Some((format!("train type {}", ty.name), to_value(ty).ok()?))
+                }
+            },
+            PlayMode::Idle => {
+                let phase = self.current_phase()?;
+                Some((format!("phase {}", phase.name), phase.raw.clone()))
+            }
         }
-        pool_entry.remaining -= 1;
-        Some(CorporationTrain {
-            name: ty.name.clone(),
-            distance: ty.distance.clone(),
-            price: ty.price,
-            revenue_stops: Vec::new(),
-            last_revenue: 0,
-        })
     }
 
     fn current_owned_train(&self) -> Option<&CorporationTrain> {
@@ -4526,19 +7910,21 @@ impl PlayState {
         if self.train_focus != TrainFocus::Owned {
             return None;
         }
-        let cursor = self.train_owned_cursor;
-        let remaining: usize;
-        let removed = {
-            let corp = self.current_corporation_mut()?;
-            if corp.trains.is_empty() {
-                return None;
-            }
-            let idx = cursor.min(corp.trains.len() - 1);
-            let removed = corp.trains.remove(idx);
-            Self::update_corporation_revenue(corp);
-            remaining = corp.trains.len();
-            removed
-        };
+        self.scrap_train(self.train_owned_cursor, false)
+    }
+
+    /// Removes owned train `index` from the current corporation, returning its supply to
+    /// the pool when `to_pool` is set (rusting does not), and keeps the cursor in range.
+    fn scrap_train(&mut self, index: usize, to_pool: bool) -> Option<CorporationTrain> {
+        let owned = self.current_corporation()?.trains.len();
+        if owned == 0 {
+            return None;
+        }
+        let index = index.min(owned - 1);
+        let removed = self
+            .game
+            .scrap_train(self.corporation_index, index, to_pool)?;
+        let remaining = owned - 1;
 
         if remaining == 0 {
             self.train_owned_cursor = 0;
@@ -4549,14 +7935,66 @@ impl PlayState {
         Some(removed)
     }
 
-    fn update_corporation_revenue(corp: &mut Corporation) {
-        corp.last_revenue = corp.trains.iter().map(|train| train.last_revenue).sum();
+    /// Returns the selected corporation to its freshly loaded state and hands its trains
+    /// back to the pool. Yields the corporation symbol and how many trains were returned.
+    fn reset_current_corporation(&mut self) -> Option<(String, usize)> {
+        let (sym, returned) = self.game.reset_corporation(self.corporation_index)?;
+        self.train_run = None;
+        self.train_owned_cursor = 0;
+        if matches!(self.mode, PlayMode::TrainManage | PlayMode::TrainRun) {
+            self.exit_train_manage();
+        }
+        self.sync_pool_cursor();
+        Some((sym, returned))
+    }
+
+    /// Clears every corporation's par and share price in one step, also handing trains
+    /// back to the pool when `return_trains` is set. Returns how many corporations had
+    /// something to clear.
+    fn clear_all_market_state(&mut self, return_trains: bool) -> usize {
+        let mut cleared = 0;
+        for index in 0..self.game.session.corporations.len() {
+            let corp = &mut self.game.session.corporations[index];
+            let has_trains = return_trains && !corp.trains.is_empty();
+            if corp.par_value.is_none() && corp.market_position.is_none() && !has_trains {
+                continue;
+            }
+            cleared += 1;
+            if return_trains {
+                self.game.reset_corporation(index);
+            } else {
+                corp.par_value = None;
+                corp.market_position = None;
+                corp.par_cell = None;
+            }
+        }
+        if return_trains {
+            self.train_run = None;
+            self.train_owned_cursor = 0;
+            if matches!(self.mode, PlayMode::TrainManage | PlayMode::TrainRun) {
+                self.exit_train_manage();
+            }
+            self.sync_pool_cursor();
+        }
+        cleared
     }
 
     fn train_run_state(&self) -> Option<&TrainRunState> {
         self.train_run.as_ref()
     }
 
+    /// True when nothing is half-entered: no purchase modal open and no typed digits
+    /// waiting on a train run or revenue cell. History snapshots are only taken here so
+    /// undo never lands between a pool change and the matching corporation change.
+    fn at_commit_boundary(&self) -> bool {
+        self.train_purchase_modal.is_none()
+            && self.revenue_input.is_none()
+            && !self
+                .train_run
+                .as_ref()
+                .is_some_and(TrainRunState::has_pending_input)
+    }
+
     fn train_run_state_mut(&mut self) -> Option<&mut TrainRunState> {
         self.train_run.as_mut()
     }
@@ -4585,6 +8023,26 @@ impl PlayState {
         }
     }
 
+    /// Commits any half-typed number (train run stop or revenue entry) so it is not lost
+    /// if the app exits before the player confirms it. Returns `true` if anything changed.
+    fn commit_pending_input(&mut self) -> bool {
+        let mut committed = false;
+        if let Some(run) = self.train_run_state_mut() {
+            if run.has_pending_input() {
+                run.commit_input();
+                committed = true;
+            }
+        }
+        if let Some(input) = self.revenue_input.take() {
+            if let Ok(value) = input.trim().parse::<i32>() {
+                let (row, col) = self.revenue_cursor();
+                self.set_revenue_value(row, col, value);
+                committed = true;
+            }
+        }
+        committed
+    }
+
     fn train_run_add_stop(&mut self) -> bool {
         let Some(train_name) = self.train_run_state().map(|run| run.train_name.clone()) else {
             return false;
@@ -4659,12 +8117,12 @@ impl PlayState {
                 train.revenue_stops = run_state.values.clone();
                 train.last_revenue = total;
             }
-            Self::update_corporation_revenue(corp);
+            corp.recompute_revenue();
             let corp_last_revenue = corp.last_revenue;
             let corp_trains_len = corp.trains.len();
             (corp_sym, corp_trains_len, corp_last_revenue)
         };
-        let corp_count = self.session.corporations.len();
+        let corp_count = self.game.session.corporations.len();
         let corp_index = self.corporation_index.min(corp_count.saturating_sub(1));
         self.train_owned_cursor = run_state.train_index.min(corp_trains_len.saturating_sub(1));
         self.mode = PlayMode::TrainManage;
@@ -4679,76 +8137,23 @@ impl PlayState {
         &mut self,
         action: RevenueAction,
     ) -> Result<RevenueOutcome, RevenueError> {
-        let (corp_sym, total, current_position) = {
-            let corp = self
-                .current_corporation()
-                .ok_or(RevenueError::NoCorporation)?;
-            let position = corp
-                .market_position
-                .clone()
-                .ok_or(RevenueError::NoMarketPosition)?;
-            (corp.sym.clone(), corp.last_revenue, position)
-        };
-
-        let desired_position = match action {
-            RevenueAction::Dividend => self
-                .offset_market_position(&current_position, (0, 1))
-                .or_else(|| self.offset_market_position(&current_position, (-1, 0))),
-            RevenueAction::Withhold => self
-                .offset_market_position(&current_position, (0, -1))
-                .or_else(|| self.offset_market_position(&current_position, (1, 0))),
-        };
-        let moved = desired_position.is_some();
-
-        if let Some(new_pos) = desired_position.clone() {
-            if let Some(corp) = self.current_corporation_mut() {
-                corp.market_position = Some(new_pos);
-            }
-        }
-
-        let position = self
-            .current_corporation()
-            .and_then(|corp| corp.market_position.clone())
-            .unwrap_or(current_position);
-        let price_label = display_price_label(&position.raw);
-
+        let outcome = self
+            .game
+            .apply_revenue_action(self.corporation_index, action)?;
         debug!(
-            sym = %corp_sym,
-            total,
+            sym = %outcome.corp_sym,
+            total = outcome.total,
             action = ?action,
-            price = %price_label,
-            moved,
+            price = ?outcome.position.numeric_value(),
+            raw = %outcome.position.raw,
+            moved = outcome.moved,
             "apply_revenue_action outcome"
         );
-
-        Ok(RevenueOutcome {
-            corp_sym,
-            total,
-            price_label,
-            moved,
-            action,
-        })
-    }
-
-    fn offset_market_position(
-        &self,
-        position: &MarketPosition,
-        delta: (isize, isize),
-    ) -> Option<MarketPosition> {
-        let row = position.row as isize + delta.0;
-        let col = position.col as isize + delta.1;
-        if row < 0 || col < 0 {
-            return None;
-        }
-        let row = row as usize;
-        let col = col as usize;
-        self.session
-            .market_cell(row, col)
-            .map(|cell| cell_to_position(cell))
+        Ok(outcome)
     }
 
     fn clamp_market_offsets(&mut self) {
-        let total_rows = self.session.market.len();
+        let total_rows = self.game.session.market.len();
         if total_rows <= 1 {
             self.market_row_offset = 0;
         } else if self.market_view_rows > 0 {
@@ -4770,7 +8175,7 @@ impl PlayState {
     }
 
     fn ensure_market_cursor_visible(&mut self) {
-        let total_rows = self.session.market.len();
+        let total_rows = self.game.session.market.len();
         if total_rows == 0 {
             self.market_row_offset = 0;
             self.market_col_offset = 0;
@@ -4809,7 +8214,8 @@ impl PlayState {
 
     /// Returns the widest row in the market for scrolling calculations.
     fn max_market_columns(&self) -> usize {
-        self.session
+        self.game
+            .session
             .market
             .iter()
             .map(|row| row.len())
@@ -4838,22 +8244,13 @@ fn default_market_cursor(session: &GameSession) -> (usize, usize) {
     (0, 0)
 }
 
-/// Converts UI selections into the shared `MarketPosition` struct.
-fn cell_to_position(cell: &MarketCell) -> MarketPosition {
-    MarketPosition {
-        row: cell.row,
-        col: cell.col,
-        value: cell.value,
-        raw: cell.raw.clone(),
+/// Maps the market grid's letter codes to colors: `market_palette` overrides first, then
+/// the theme defaults.
+fn market_color(raw: &str, theme: &Theme, palette: &HashMap<char, Color>) -> Color {
+    let code = market_zone_code(raw);
+    if let Some(color) = code.and_then(|code| palette.get(&code)) {
+        return *color;
     }
-}
-
-/// Maps the market grid's letter codes to palette entries.
-fn market_color(raw: &str, theme: &Theme) -> Color {
-    let code = raw
-        .chars()
-        .find(|c| c.is_ascii_alphabetic())
-        .map(|c| c.to_ascii_lowercase());
     match code {
         Some('y') => theme.warning,
         Some('o') => theme.accent_alt,
@@ -4863,6 +8260,93 @@ fn market_color(raw: &str, theme: &Theme) -> Color {
     }
 }
 
+/// Lower-cased letter code marking a market cell's zone (`p` in `100p`); `None` for plain
+/// prices.
+fn market_zone_code(raw: &str) -> Option<char> {
+    raw.chars()
+        .find(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase())
+}
+
+/// Name of the zone a market cell's code puts it in; `None` for plain prices and codes
+/// without a known meaning.
+fn market_zone_label(raw: &str) -> Option<&'static str> {
+    match market_zone_code(raw)? {
+        'p' => Some("par"),
+        'y' => Some("yellow"),
+        'o' => Some("orange"),
+        'b' => Some("brown"),
+        _ => None,
+    }
+}
+
+/// Zone of the cell `corp` was parred at; `None` before a par is set (or in saves from
+/// before the par cell was recorded) and for codes without a known zone.
+fn par_zone_label(corp: &Corporation) -> Option<&'static str> {
+    market_zone_label(&corp.par_cell.as_ref()?.raw)
+}
+
+/// Packs token labels into lines no wider than `width`, one space apart, returning the
+/// label indices on each line. Labels wider than the cell get a line to themselves.
+fn pack_tokens(labels: &[&str], width: usize) -> Vec<Vec<usize>> {
+    let mut lines: Vec<Vec<usize>> = Vec::new();
+    let mut used = 0;
+    for (idx, label) in labels.iter().enumerate() {
+        let len = label.chars().count().min(width);
+        match lines.last_mut() {
+            Some(line) if used + 1 + len <= width => {
+                line.push(idx);
+                used += 1 + len;
+            }
+            _ => {
+                lines.push(vec![idx]);
+                used = len;
+            }
+        }
+    }
+    lines
+}
+
+/// Picks the token labels shown on a single line of `width`, returning their indices and
+/// how many are left over. When some are left over, room is kept for a ` +N` marker.
+fn fit_tokens(labels: &[&str], width: usize) -> (Vec<usize>, usize) {
+    let mut shown = pack_tokens(labels, width)
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    let mut hidden = labels.len() - shown.len();
+    while hidden > 0 {
+        let used: usize = shown
+            .iter()
+            .map(|&idx| labels[idx].chars().count().min(width) + 1)
+            .sum();
+        let marker = format!("+{hidden}").len();
+        if shown.is_empty() || used + marker <= width {
+            break;
+        }
+        shown.pop();
+        hidden += 1;
+    }
+    (shown, hidden)
+}
+
+/// Corporation colors for a full-symbol market token, falling back to the accent color.
+fn corporation_style(corp: &Corporation, theme: &Theme) -> Style {
+    match corp.color.as_deref().and_then(parse_color) {
+        Some(bg) => {
+            let fg = corp
+                .text_color
+                .as_deref()
+                .and_then(parse_color)
+                .unwrap_or_else(|| contrast_color(&bg, theme.primary_fg));
+            Style::default().fg(fg).bg(bg).add_modifier(Modifier::BOLD)
+        }
+        None => Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD),
+    }
+}
+
 /// Removes color codes/letters, leaving only the numeric portion of a cell.
 fn sanitize_market_text(raw: &str) -> String {
     let filtered: String = raw.chars().filter(|c| !c.is_ascii_alphabetic()).collect();
@@ -4879,6 +8363,26 @@ fn display_price_label(raw: &str) -> String {
     }
 }
 
+/// Positions of `names` starting with `query`, ignoring ASCII case.
+fn prefix_matches<'a>(names: impl IntoIterator<Item = &'a str>, query: &str) -> Vec<usize> {
+    let query = query.to_ascii_lowercase();
+    names
+        .into_iter()
+        .enumerate()
+        .filter(|(_, name)| name.to_ascii_lowercase().starts_with(&query))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Pretty-prints an engine value one JSON line per entry for the inspector overlay.
+fn value_lines(value: &Value) -> Vec<String> {
+    serde_json::to_string_pretty(value)
+        .unwrap_or_else(|_| value.to_string())
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
 /// Utility for popping modal windows directly in the centre of another rect.
 fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let width = width.min(area.width);
@@ -4888,11 +8392,6 @@ fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     Rect::new(x, y, width, height)
 }
 
-/// Formats revenue and cash values with the `$` prefix used by 18xx notation.
-fn format_currency(value: i32) -> String {
-    format!("${value}")
-}
-
 /// Mapping of all remaining subscript-sized glyphs we can hand out to corporations.
 const TOKEN_FALLBACKS: &[char] = &[
     '₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉', '₊', '₋', '₌', '₍', '₎', 'ₔ', 'ᵦ',
@@ -4917,7 +8416,7 @@ fn assign_unique_tokens(corporations: &[Corporation]) -> HashMap<String, String>
             }
         }
         if chosen.is_none() {
-            while let Some(candidate) = fallback_iter.next() {
+            for candidate in fallback_iter.by_ref() {
                 if used.insert(candidate) {
                     chosen = Some(candidate);
                     break;
@@ -4985,18 +8484,57 @@ fn subscript_char(ch: char) -> Option<char> {
     }
 }
 
+/// Compact span for save summaries: `45m`, `2h 15m`, `3d 4h`.
+fn format_play_duration(duration: TimeDelta) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+/// Train distance label, cut to `max_width` characters with a trailing `…` (0 keeps it
+/// whole).
+fn format_distance(value: &Value, max_width: usize) -> String {
+    let label = distance_label(value);
+    if max_width == 0 || label.chars().count() <= max_width {
+        return label;
+    }
+    let mut truncated: String = label.chars().take(max_width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
 /// Human-readable form of the `distance` field on train definitions.
-fn format_distance(value: &Value) -> String {
+fn distance_label(value: &Value) -> String {
     match value {
         Value::Null => "?".to_string(),
         Value::Number(num) => num.to_string(),
         Value::String(text) => text.clone(),
         Value::Array(items) => items
             .iter()
-            .map(format_distance)
+            .map(distance_label)
             .collect::<Vec<_>>()
             .join("/"),
-        Value::Object(_) => "map".to_string(),
+        // Numeric fields as count plus key initial: `{nodes: 3}` is "3n".
+        Value::Object(map) => {
+            let label: String = map
+                .iter()
+                .filter_map(|(key, value)| {
+                    let count = value.as_i64()?;
+                    Some(format!("{count}{}", key.chars().next()?))
+                })
+                .collect();
+            if label.is_empty() {
+                "map".to_string()
+            } else {
+                label
+            }
+        }
         Value::Bool(flag) => flag.to_string(),
     }
 }
@@ -5012,17 +8550,2282 @@ fn stop_limit_from_distance(value: &Value) -> Option<usize> {
     }
 }
 
+/// Status note naming phases whose engine OR count is missing or implausible.
+fn suspect_rounds_note(phases: &[&str]) -> Option<String> {
+    if phases.is_empty() {
+        return None;
+    }
+    let label = if phases.len() == 1 { "phase" } else { "phases" };
+    Some(format!(
+        "engine OR count missing or implausible for {label} {} (revenue columns may differ)",
+        phases.join(", ")
+    ))
+}
+
+/// President and per-share dividends when shareholders are recorded, else the per-share
+/// presets.
+fn dividend_payout_line(
+    currency: &CurrencyFormat,
+    total: i32,
+    corp: Option<&Corporation>,
+) -> String {
+    let breakdown = corp.and_then(|corp| {
+        let president = corp.president.as_deref()?;
+        revenue::dividend_breakdown(total, SHARE, &corp.shares, president)
+    });
+    let Some(breakdown) = breakdown else {
+        return share_payout_line(currency, total);
+    };
+    let mut line = format!(
+        "Dividends: Pres {} {} · each 10%: {}",
+        breakdown.president,
+        currency.format(breakdown.president_payout),
+        currency.format(breakdown.per_share)
+    );
+    for (owner, amount) in breakdown.others {
+        line.push_str(&format!(" · {owner} {}", currency.format(amount)));
+    }
+    line
+}
+
 /// Renders the line showing per-share dividends the corporation just paid.
-fn share_payout_line(total: i32) -> String {
+fn share_payout_line(currency: &CurrencyFormat, total: i32) -> String {
     if total <= 0 {
-        return "Dividends: $0".to_string();
+        return format!("Dividends: {}", currency.format(0));
     }
-    let values = (10..=60)
-        .step_by(10)
-        .map(|pct| {
-            let amount = ((total as f64) * (pct as f64) / 100.0).round() as i32;
-            format!("{pct}% {}", format_currency(amount))
-        })
+    let values = revenue::preset_payouts(total)
+        .into_iter()
+        .map(|(pct, amount)| format!("{pct}% {}", currency.format(amount)))
         .collect::<Vec<_>>();
     format!("Dividends: {}", values.join(" | "))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn sample_info() -> GameInfo {
+        GameInfo {
+            id: "sample".to_string(),
+            title: "Sample".to_string(),
+            subtitle: None,
+            folder: "g_sample".to_string(),
+            designer: None,
+            location: None,
+            rules_url: None,
+            commit: None,
+            updated_at: None,
+        }
+    }
+
+    fn sample_app() -> Tui18App {
+        let root = PathBuf::from("/nonexistent/18tui-engine");
+        let mut app = Tui18App::new(
+            AppConfig::default(),
+            ResourceLoader::new(root.clone(), ResourceMetadata::default()),
+            ResourceMetadata::default(),
+            SessionLoader::new(root),
+        );
+        app.state.set_games(vec![sample_info()]);
+        app.state.apply_filter();
+        app
+    }
+
+    #[test]
+    fn abbreviated_corporation_list_drops_names() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        let corp = state.game.session.corporations[0].clone();
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let entry = |app: &Tui18App| {
+            let line = app.corporation_list_line(app.play_state.as_ref().unwrap(), &corp);
+            let text: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            (text, line.width())
+        };
+
+        let (full, full_width) = entry(&app);
+        assert!(full.contains(&corp.name), "{full}");
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('v'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert_eq!(app.corp_names, CorpNameDisplay::Abbreviated);
+        let (short, short_width) = entry(&app);
+        assert_eq!(short, "PRR [ₚ] P:$100 M:100 ");
+        assert!(short_width < full_width);
+    }
+
+    #[test]
+    fn engine_switcher_repoints_the_loader() -> Result<()> {
+        let data = tempfile::tempdir()?;
+        let engine = tempfile::tempdir()?;
+        let metadata = ResourceMetadata {
+            commit: Some("abcdef123456".to_string()),
+            ..ResourceMetadata::default()
+        };
+        metadata.persist(manifest::manifest_path(engine.path()))?;
+        let config = AppConfig {
+            data_dir: Some(data.path().to_path_buf()),
+            ..AppConfig::default()
+        };
+        let mut recent = RecentEngines::default();
+        recent.record(engine.path());
+        recent.record(Path::new("/nonexistent/18tui-engine"));
+        recent.save(&config.recent_engines_path())?;
+
+        let mut app = sample_app();
+        app.config = config;
+        let press = |app: &mut Tui18App, code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+        };
+        press(&mut app, KeyCode::Char('e'));
+        let switcher = app.engine_switcher.as_ref().expect("switcher open");
+        assert_eq!(switcher.cursor, 0, "the current engine is preselected");
+        assert_eq!(switcher.entries[1].1.as_deref(), Some("abcdef123456"));
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Enter);
+        assert!(app.engine_switcher.is_none());
+        assert_eq!(app.loader.root_path(), engine.path());
+        assert_eq!(app.metadata.commit.as_deref(), Some("abcdef123456"));
+        assert_eq!(app.screen, Screen::Browse);
+        let recent = RecentEngines::load(&app.config.recent_engines_path())?;
+        assert_eq!(
+            recent.paths.first().map(PathBuf::as_path),
+            Some(engine.path())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn startup_sync_does_not_undo_an_engine_switch() -> Result<()> {
+        let data = tempfile::tempdir()?;
+        let engine = tempfile::tempdir()?;
+        let configured = tempfile::tempdir()?;
+        let mut app = sample_app();
+        app.config = AppConfig {
+            data_dir: Some(data.path().to_path_buf()),
+            ..AppConfig::default()
+        };
+        app.switch_engine(engine.path().to_path_buf());
+        assert_eq!(app.loader.root_path(), engine.path());
+
+        let metadata = ResourceMetadata {
+            commit: Some("fedcba654321".to_string()),
+            ..ResourceMetadata::default()
+        };
+        app.handle_sync_event(SyncEvent::Success {
+            path: configured.path().to_path_buf(),
+            metadata,
+        });
+        assert_eq!(app.loader.root_path(), engine.path());
+        assert_ne!(app.metadata.commit.as_deref(), Some("fedcba654321"));
+        Ok(())
+    }
+
+    #[test]
+    fn new_game_is_disabled_without_ruby() {
+        let mut app = sample_app();
+        app.set_ruby_available(false);
+        app.prompt_new_game();
+        assert!(app.name_prompt.is_none());
+        app.start_session_load();
+        assert!(!app.pending_session);
+        assert_eq!(app.state.status, RUBY_MISSING_MESSAGE);
+    }
+
+    #[test]
+    fn long_distances_are_truncated_and_short_ones_kept() {
+        use serde_json::json;
+        assert_eq!(format_distance(&json!(2), 12), "2");
+        assert_eq!(format_distance(&json!(["2", "3", "4"]), 12), "2/3/4");
+        let wide = json!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_eq!(format_distance(&wide, 12), "1/2/3/4/5/6…");
+        assert_eq!(format_distance(&wide, 0), "1/2/3/4/5/6/7/8/9/10");
+    }
+
+    #[test]
+    fn object_distances_show_counts_with_key_initials() {
+        use serde_json::json;
+        assert_eq!(format_distance(&json!({ "nodes": 3 }), 12), "3n");
+        let engine = json!([
+            { "nodes": ["city", "offboard"], "pay": 2, "visit": 2 },
+            { "nodes": ["town"], "pay": 99 },
+        ]);
+        assert_eq!(format_distance(&engine, 0), "2p2v/99p");
+        assert_eq!(format_distance(&json!({ "nodes": ["city"] }), 12), "map");
+    }
+
+    #[test]
+    fn phases_without_round_count_use_configured_default() {
+        let phase = PhaseInfo::from_value(&serde_json::json!({ "name": "3" }), 3);
+        assert_eq!(phase.operating_rounds, 3);
+        let explicit = PhaseInfo::from_value(&serde_json::json!({ "operating_rounds": 1 }), 3);
+        assert_eq!(explicit.operating_rounds, 1);
+        assert_eq!(
+            PhaseInfo::from_value(&Value::from("4"), 0).operating_rounds,
+            1
+        );
+
+        let mut session = GameSession::fixture();
+        session.phases = vec![serde_json::json!({ "name": "2" })];
+        let state = PlayState::new(session, 3);
+        assert_eq!(state.game.phase_rounds[0].len(), 3);
+        assert_eq!(
+            suspect_rounds_note(&state.game.suspect_round_phases()).as_deref(),
+            Some("engine OR count missing or implausible for phase 2 (revenue columns may differ)")
+        );
+        assert_eq!(suspect_rounds_note(&[]), None);
+    }
+
+    #[test]
+    fn custom_phases_add_and_remove_keep_index_valid() {
+        let mut session = GameSession::fixture();
+        session
+            .phases
+            .push(serde_json::json!({ "name": "3", "operating_rounds": 2 }));
+        let mut state = PlayState::new(session, 2);
+        let prompt = PhasePromptModal {
+            input: "Late game 3".to_string(),
+        };
+        let (name, rounds) = prompt.parse(2).expect("parsed prompt");
+        assert_eq!((name.as_str(), rounds), ("Late game", 3));
+
+        state.add_custom_phase(name, rounds);
+        assert_eq!(state.phase_count(), 3);
+        assert_eq!(state.current_phase_index(), 2);
+        assert_eq!(state.phase_label(), "Late game (custom)");
+        assert_eq!(state.game.phase_rounds[2].len(), 3);
+        state.revenue_cursor_or = 2;
+
+        state.add_custom_phase("D".to_string(), 1);
+        state.set_phase_index(1);
+        let removed = state.remove_last_custom_phase().expect("custom phase");
+        assert_eq!(removed.name, "D");
+        assert_eq!(state.current_phase_index(), 1);
+        assert_eq!(state.game.phase_rounds.len(), state.phase_count());
+
+        state.set_phase_index(2);
+        state.revenue_cursor_or = 2;
+        state.remove_last_custom_phase().expect("custom phase");
+        assert_eq!(state.phase_count(), 2);
+        assert_eq!(state.current_phase_index(), 1);
+        assert!(state.revenue_cursor_or < state.current_phase_rounds().len());
+        assert!(state.remove_last_custom_phase().is_none());
+    }
+
+    #[test]
+    fn phase_picker_jumps_and_clamps_revenue_cursor() {
+        let mut app = sample_app();
+        let mut session = GameSession::fixture();
+        session.phases = vec![
+            serde_json::json!({ "name": "2", "operating_rounds": 3 }),
+            serde_json::json!({ "name": "3", "operating_rounds": 1 }),
+            serde_json::json!({ "name": "4", "operating_rounds": 2 }),
+        ];
+        let mut state = PlayState::new(session, 2);
+        state.revenue_cursor_or = 2;
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        let press = |app: &mut Tui18App, code: KeyCode| {
+            app.process_app_event(Some(AppEvent::Input(Event::Key(KeyEvent::new(
+                code,
+                KeyModifiers::NONE,
+            )))));
+        };
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(
+            app.phase_picker.as_ref().map(|picker| picker.cursor),
+            Some(0)
+        );
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Enter);
+        assert!(app.phase_picker.is_none());
+        let state = app.play_state.as_ref().expect("play state");
+        assert_eq!(state.current_phase_index(), 1);
+        assert_eq!(state.revenue_cursor_or, 0);
+    }
+
+    #[test]
+    fn column_jumps_follow_ragged_rows_and_par_cells() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.enter_price_select();
+        state.market_cursor = (1, 1);
+        assert!(state.jump_market_column(true));
+        assert_eq!(state.market_cursor, (1, 2));
+        assert!(state.jump_market_column(false));
+        assert_eq!(state.market_cursor, (1, 0));
+        state.market_cursor = (0, 1);
+        assert!(state.jump_market_column(true));
+        assert_eq!(state.market_cursor, (0, 3));
+
+        assert!(state.enter_par_select());
+        state.market_cursor = (0, 2);
+        assert!(state.jump_market_column(true));
+        assert_eq!(state.market_cursor, (0, 0));
+        state.market_cursor = (2, 1);
+        assert!(!state.jump_market_column(false));
+        assert_eq!(state.market_cursor, (2, 1));
+    }
+
+    #[test]
+    fn dividends_move_right_and_withholds_move_left() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert_eq!(
+            state
+                .apply_revenue_action(RevenueAction::Dividend)
+                .unwrap_err(),
+            RevenueError::NoMarketPosition
+        );
+        assert!(state.enter_par_select());
+        assert_eq!(state.apply_par_selection(), Some(100));
+
+        let outcome = state.apply_revenue_action(RevenueAction::Dividend).unwrap();
+        assert!(outcome.moved);
+        assert_eq!(display_price_label(&outcome.position.raw), "110");
+        let position = state
+            .current_corporation()
+            .unwrap()
+            .market_position
+            .clone()
+            .unwrap();
+        assert_eq!((position.row, position.col), (0, 1));
+
+        state.apply_revenue_action(RevenueAction::Withhold).unwrap();
+        // At the left edge a withhold drops a row instead.
+        let outcome = state.apply_revenue_action(RevenueAction::Withhold).unwrap();
+        assert!(outcome.moved);
+        let position = state
+            .current_corporation()
+            .unwrap()
+            .market_position
+            .clone()
+            .unwrap();
+        assert_eq!((position.row, position.col), (1, 0));
+    }
+
+    #[test]
+    fn par_select_stays_on_marked_par_cells() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert!(state.has_marked_par_cells());
+        state.market_cursor = (2, 2);
+        assert!(state.enter_par_select());
+        assert_eq!(state.market_cursor, (1, 0));
+
+        state.move_market_cursor(0, 1);
+        assert_eq!(state.market_cursor, (1, 0));
+        state.move_market_cursor(1, 0);
+        assert_eq!(state.market_cursor, (1, 0));
+        state.move_market_cursor(-1, 0);
+        assert_eq!(state.market_cursor, (0, 0));
+        assert!(!state.is_par_cell(2, 0));
+    }
+
+    fn ragged_session() -> GameSession {
+        // Rows of 5, 2 and 8 columns; the second row ends in a blank cell.
+        GameSession::fixture_with_market(&[
+            &["100p", "110", "120", "130", "140"],
+            &["90", ""],
+            &["70", "80", "90", "100", "110", "120", "130", "140"],
+        ])
+    }
+
+    #[test]
+    fn single_row_navigation_wraps_but_price_moves_do_not() {
+        let session = GameSession::fixture_with_market(&[&["100p", "110", "120", "130"]]);
+        let mut state = PlayState::new(session, 2);
+
+        state.market_cursor = (0, 3);
+        state.move_market_cursor(0, 1);
+        assert_eq!(state.market_cursor, (0, 0));
+        state.move_market_cursor(0, -1);
+        assert_eq!(state.market_cursor, (0, 3));
+
+        state.game.set_price(0, 0, 3);
+        let outcome = state
+            .apply_revenue_action(RevenueAction::Dividend)
+            .expect("priced");
+        assert!(!outcome.moved);
+        assert_eq!(outcome.position.col, 3);
+    }
+
+    #[test]
+    fn ragged_market_rows_clamp_the_cursor_column() {
+        let mut state = PlayState::new(ragged_session(), 2);
+        state.enter_price_select();
+        let in_range = |state: &PlayState| {
+            let (row, col) = state.market_cursor;
+            state.game.session.market_cell(row, col).is_some()
+        };
+
+        state.market_cursor = (0, 4);
+        state.move_market_cursor(1, 0);
+        assert_eq!(
+            state.market_cursor,
+            (1, 0),
+            "skips the blank tail of the short row"
+        );
+        state.move_market_cursor(0, 1);
+        assert_eq!(state.market_cursor, (1, 0));
+        state.move_market_cursor(1, 0);
+        assert_eq!(state.market_cursor, (2, 0));
+        for _ in 0..10 {
+            state.move_market_cursor(0, 1);
+            assert!(in_range(&state), "{:?}", state.market_cursor);
+        }
+        assert_eq!(state.market_cursor, (2, 7));
+        state.move_market_cursor(-1, 0);
+        assert_eq!(state.market_cursor, (1, 0));
+        state.move_market_cursor(-1, 0);
+        assert_eq!(state.market_cursor, (0, 0));
+
+        // A cursor stranded past a row's end can still walk back into it.
+        state.market_cursor = (1, 6);
+        state.move_market_cursor(0, -1);
+        assert_eq!(state.market_cursor, (1, 0));
+        state.market_cursor = (0, 7);
+        state.move_market_cursor(0, -1);
+        assert_eq!(state.market_cursor, (0, 4));
+        for (row_delta, col_delta) in [(1, 0), (0, 1), (1, 0), (-1, 0), (0, -1), (-1, 0)] {
+            state.move_market_cursor(row_delta, col_delta);
+            assert!(in_range(&state), "{:?}", state.market_cursor);
+        }
+    }
+
+    #[test]
+    fn price_cell_lookup_prefers_the_current_row() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert_eq!(state.price_cell_for_value(130), Some((0, 3)));
+        assert_eq!(state.price_cell_for_value(70), None);
+        // 100 sits in all three rows; without a price the first one wins.
+        assert_eq!(state.price_cell_for_value(100), Some((0, 0)));
+        state.game.set_price(0, 2, 0);
+        assert_eq!(state.price_cell_for_value(100), Some((2, 2)));
+        assert_eq!(state.price_cell_for_value(110), Some((0, 1)));
+    }
+
+    #[test]
+    fn typed_prices_move_the_cursor_and_esc_clears_them() {
+        let mut app = sample_app();
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        app.play_state.as_mut().unwrap().enter_price_select();
+        let press = |app: &mut Tui18App, code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+            let state = app.play_state.as_ref().unwrap();
+            (state.market_cursor, state.mode())
+        };
+
+        press(&mut app, KeyCode::Char('1'));
+        assert_eq!(app.state.status, "No market cell at $1");
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(press(&mut app, KeyCode::Char('0')).0, (0, 2));
+        assert_eq!(app.state.status, "$120 selected · Enter to set");
+
+        // Esc drops the typed value first, so 7 starts afresh; the next Esc cancels.
+        press(&mut app, KeyCode::Esc);
+        assert!(app.play_state.as_ref().unwrap().price_input.is_none());
+        press(&mut app, KeyCode::Char('7'));
+        press(&mut app, KeyCode::Char('0'));
+        assert_eq!(app.state.status, "No market cell at $70");
+        assert_eq!(
+            press(&mut app, KeyCode::Esc),
+            ((0, 2), PlayMode::PriceSelect)
+        );
+        assert_eq!(press(&mut app, KeyCode::Esc).1, PlayMode::Idle);
+    }
+
+    #[test]
+    fn double_press_needs_the_same_key_inside_the_window() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let start = Instant::now();
+        let later = |millis| start + Duration::from_millis(millis);
+        let cell = (1, 1);
+        assert!(!state.register_market_key(KeyCode::Enter, cell, start));
+        assert!(state.register_market_key(KeyCode::Enter, cell, later(400)));
+        // The pair is used up: a third press starts over.
+        assert!(!state.register_market_key(KeyCode::Enter, cell, later(600)));
+        assert!(
+            !state.register_market_key(KeyCode::Enter, cell, later(1200)),
+            "too slow"
+        );
+        assert!(
+            !state.register_market_key(KeyCode::Char('p'), cell, later(1300)),
+            "other key"
+        );
+        assert!(!state.register_market_key(KeyCode::Enter, cell, later(1400)));
+        assert!(
+            !state.register_market_key(KeyCode::Enter, (1, 2), later(1500)),
+            "other cell"
+        );
+        state.exit_market();
+        assert!(!state.register_market_key(KeyCode::Enter, (1, 2), later(1600)));
+    }
+
+    #[test]
+    fn double_enter_updates_a_set_par_only_when_enabled() {
+        for enabled in [false, true] {
+            let mut app = sample_app();
+            app.config.double_key_confirm = enabled;
+            let mut state = PlayState::new(GameSession::fixture(), 2);
+            state.game.set_par(0, 0, 0);
+            app.play_state = Some(state);
+            app.screen = Screen::Play;
+            let press = |app: &mut Tui18App, code| {
+                app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                    .expect("key handled");
+            };
+            press(&mut app, KeyCode::Char('p'));
+            press(&mut app, KeyCode::Char('j'));
+            press(&mut app, KeyCode::Enter);
+            press(&mut app, KeyCode::Enter);
+            let state = app.play_state.as_ref().unwrap();
+            let par = state.game.session.corporations[0].par_value;
+            if enabled {
+                assert_eq!((state.mode(), par), (PlayMode::Idle, Some(90)));
+            } else {
+                assert_eq!((state.mode(), par), (PlayMode::ParSelect, Some(100)));
+            }
+        }
+    }
+
+    #[test]
+    fn price_enter_cannot_pair_with_a_par_enter() {
+        let mut app = sample_app();
+        app.config.double_key_confirm = true;
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App, code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+        };
+        // Idle Enter opens price selection and one more Enter sets the price.
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Enter);
+        let state = app.play_state.as_ref().unwrap();
+        assert_eq!(state.mode(), PlayMode::Idle);
+        assert!(state.last_market_key.is_none());
+        // A par Enter right after it still only prompts.
+        press(&mut app, KeyCode::Char('p'));
+        press(&mut app, KeyCode::Enter);
+        let state = app.play_state.as_ref().unwrap();
+        assert_eq!(state.mode(), PlayMode::ParSelect);
+        assert_eq!(
+            app.state.status,
+            "Par already set; press Enter again or 'p' to update"
+        );
+    }
+
+    #[test]
+    fn header_cells_are_never_selected() {
+        // The top row holds engine labels rather than prices.
+        let session = GameSession::fixture_with_market(&[
+            &["Par", "Open", "Bank", "Pool"],
+            &["90p", "100", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        let mut state = PlayState::new(session, 2);
+        state.enter_price_select();
+        state.market_cursor = (1, 1);
+        state.move_market_cursor(-1, 0);
+        assert_eq!(state.market_cursor, (1, 1));
+        assert!(state.game.session.is_market_header(0, 1));
+        assert!(state.game.session.market_cell(0, 1).is_none());
+        assert!(state.game.set_price(0, 0, 2).is_none());
+    }
+
+    #[test]
+    fn par_select_allows_any_cell_without_par_markers() {
+        let session = GameSession::fixture_with_market(&[
+            &["100", "110", "120", "130"],
+            &["90", "100", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        let mut state = PlayState::new(session, 2);
+        assert!(!state.has_marked_par_cells());
+        state.market_cursor = (2, 2);
+        assert!(state.enter_par_select());
+        assert_eq!(state.market_cursor, (2, 2));
+        state.move_market_cursor(0, -1);
+        assert_eq!(state.market_cursor, (2, 1));
+        state.move_market_cursor(-1, 0);
+        assert_eq!(state.market_cursor, (1, 1));
+    }
+
+    #[test]
+    fn inspector_shows_value_behind_focus() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let (title, value) = state.inspect_focus().expect("phase focus");
+        assert_eq!(title, "phase 2");
+        assert_eq!(value, state.game.phases[0].raw);
+
+        state.enter_price_select();
+        state.market_cursor = (2, 1);
+        let (title, value) = state.inspect_focus().expect("cell focus");
+        assert_eq!(title, "market cell 2,1");
+        assert_eq!(value["raw"], "90y");
+
+        let lines = value_lines(&serde_json::json!({"distance": [{"nodes": ["city"], "pay": 2}]}));
+        assert_eq!(lines.first().map(String::as_str), Some("{"));
+        assert!(lines.iter().any(|line| line.trim() == "\"pay\": 2"));
+        assert_eq!(lines.last().map(String::as_str), Some("}"));
+    }
+
+    #[test]
+    fn terminal_title_tracks_active_session() {
+        let mut app = sample_app();
+        assert_eq!(app.desired_terminal_title(), "18TUI");
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        assert_eq!(app.desired_terminal_title(), "18TUI — Sample");
+        app.screen = Screen::Browse;
+        assert_eq!(app.desired_terminal_title(), "18TUI");
+    }
+
+    #[test]
+    fn error_status_flashes_until_next_tick() {
+        let mut app = sample_app();
+        app.set_error_status("Unable to do that".to_string());
+        assert!(!app.status_flash && !app.pending_bell);
+
+        app.config.failure_alert = FailureAlert::Flash;
+        app.set_error_status("Unable to do that".to_string());
+        assert!(app.status_flash);
+        assert_eq!(app.state.status, "Unable to do that");
+        app.handle_tick();
+        assert!(!app.status_flash);
+
+        app.config.failure_alert = FailureAlert::Bell;
+        app.set_error_status("Unable to do that".to_string());
+        assert!(app.pending_bell && !app.status_flash);
+    }
+
+    #[test]
+    fn tab_toggles_train_sections_without_falling_back() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert!(state.enter_train_manage());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let mut press = |code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+            let focus = app.play_state.as_ref().unwrap().train_focus;
+            (focus, app.state.status.clone())
+        };
+
+        // Nothing owned yet: Tab stays on the pool and says why; h falls back to it.
+        let (focus, status) = press(KeyCode::Tab);
+        assert_eq!(
+            (focus, status.as_str()),
+            (TrainFocus::Pool, "No owned trains to switch to")
+        );
+        assert_eq!(press(KeyCode::Char('h')).0, TrainFocus::Pool);
+    }
+
+    #[test]
+    fn tab_stays_on_owned_trains_when_the_pool_is_empty() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let train = state.purchase_available_train(0).expect("train available");
+        state.current_corporation_mut().unwrap().trains.push(train);
+        assert!(state.enter_train_manage());
+        state.focus_owned();
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let tab = |app: &mut Tui18App| {
+            app.handle_input(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)))
+                .expect("key handled");
+            app.play_state.as_ref().unwrap().train_focus
+        };
+
+        assert_eq!(tab(&mut app), TrainFocus::Pool);
+        assert_eq!(tab(&mut app), TrainFocus::Owned);
+
+        let state = app.play_state.as_mut().unwrap();
+        for entry in &mut state.game.session.train_pool {
+            entry.remaining = 0;
+        }
+        assert_eq!(tab(&mut app), TrainFocus::Owned);
+        assert_eq!(app.state.status, "No trains left in the pool to switch to");
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert_eq!(
+            app.play_state.as_ref().unwrap().train_focus,
+            TrainFocus::Owned
+        );
+    }
+
+    #[test]
+    fn statuses_revert_to_ready_after_the_timeout() {
+        let mut app = sample_app();
+        app.state.set_status("Saved".to_string());
+        for _ in 0..5 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, "Saved", "0 keeps the status");
+
+        app.config.status_timeout_ticks = 3;
+        app.state.set_status("Saved".to_string());
+        app.handle_tick();
+        app.handle_tick();
+        assert_eq!(app.state.status, "Saved");
+        app.handle_tick();
+        assert_eq!(app.state.status, READY_STATUS);
+        assert_eq!(app.state.status_level, StatusLevel::Info);
+
+        app.set_error_status("Save failed".to_string());
+        for _ in 0..10 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, "Save failed");
+        app.handle_input(Event::Key(KeyEvent::new(KeyCode::Null, KeyModifiers::NONE)))
+            .expect("key handled");
+        assert_eq!(
+            app.state.status, READY_STATUS,
+            "the next key clears an error"
+        );
+        app.state.set_success("Saved".to_string());
+        for _ in 0..3 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, READY_STATUS);
+
+        // Load progress stays up while the spinner runs.
+        app.pending_session = true;
+        app.state
+            .set_status("Loading 1889… (Esc to cancel)".to_string());
+        for _ in 0..5 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, "Loading 1889… (Esc to cancel)");
+        app.pending_session = false;
+        app.sync_progress = Some(("fetch".to_string(), 40));
+        for _ in 0..5 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, "Loading 1889… (Esc to cancel)");
+        app.sync_progress = None;
+        app.state.set_status("Synced".to_string());
+        for _ in 0..3 {
+            app.handle_tick();
+        }
+        assert_eq!(app.state.status, READY_STATUS);
+    }
+
+    #[test]
+    fn idle_autosave_commits_pending_input() {
+        let mut app = sample_app();
+        app.config.autosave_idle_ticks = 2;
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let train = state.purchase_available_train(0).expect("train available");
+        state.current_corporation_mut().unwrap().trains.push(train);
+        assert!(state.enter_train_manage());
+        state.train_owned_cursor = 0;
+        state.train_focus = TrainFocus::Owned;
+        assert!(state.start_train_run());
+        state.train_run_append_digit('4');
+        state.train_run_append_digit('0');
+        state.revenue_input = Some("70".to_string());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        app.handle_tick();
+        let run = app.play_state.as_ref().unwrap().train_run_state().unwrap();
+        assert!(run.has_pending_input());
+
+        app.handle_tick();
+        let state = app.play_state.as_ref().expect("play state");
+        let run = state.train_run_state().expect("train run");
+        assert!(!run.has_pending_input());
+        assert_eq!(run.current_value(), 40);
+        assert!(state.revenue_input.is_none());
+        assert_eq!(state.current_phase_rounds()[0].revenues[0], 70);
+    }
+
+    #[test]
+    fn reset_terminal_leaves_the_alternate_screen() {
+        let mut out = Vec::new();
+        reset_terminal(&mut out).expect("reset terminal");
+        let written = String::from_utf8(out).expect("escape sequences");
+        assert!(written.contains("\x1b[?1049l"), "{written:?}");
+        assert!(written.contains("\x1b[?25h"), "{written:?}");
+    }
+
+    #[test]
+    fn shutdown_event_saves_pending_input_and_ends_the_loop() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.save_manager = SaveManager::new(dir.path());
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("signal"), to_value(&state).unwrap())
+            .expect("create save");
+        state.revenue_input = Some("70".to_string());
+        app.active_save = Some(entry.clone());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        assert!(!app.process_app_event(Some(AppEvent::Shutdown)));
+        let payload = app.save_manager.load(&entry).expect("load save");
+        let saved: PlayState = from_value(payload.state().clone()).expect("saved state");
+        assert!(saved.revenue_input.is_none());
+        assert_eq!(saved.current_phase_rounds()[0].revenues[0], 70);
+    }
+
+    #[test]
+    fn undo_restores_pool_and_corporation_trains_together() {
+        let mut app = sample_app();
+        app.save_manager = SaveManager::in_memory();
+        let state = PlayState::new(GameSession::fixture(), 2);
+        let entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("undo"), to_value(&state).unwrap())
+            .expect("create save");
+        app.active_save = Some(entry);
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let mut press = |code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+        };
+
+        press(KeyCode::Char('t'));
+        press(KeyCode::Char('b'));
+        // Typing into the purchase filter is neither a snapshot nor an undo.
+        press(KeyCode::Char('u'));
+        press(KeyCode::Backspace);
+        press(KeyCode::Enter);
+
+        let active = app.active_save.clone().expect("active save");
+        let payload = app.save_manager.load(&active).expect("load");
+        let snapshots: Vec<PlayState> = (0..payload.history_len())
+            .map(|index| {
+                let (_, payload) = app.save_manager.set_history_index(&active, index).unwrap();
+                from_value(payload.state().clone()).unwrap()
+            })
+            .collect();
+        assert!(snapshots
+            .iter()
+            .all(|state| state.train_purchase_modal.is_none()));
+        app.save_manager
+            .set_history_index(&active, payload.history_len() - 1)
+            .unwrap();
+
+        let state = app.play_state.as_ref().expect("play state");
+        assert_eq!(state.game.session.train_pool[0].remaining, 2);
+        assert_eq!(state.game.session.corporations[0].trains.len(), 1);
+
+        app.apply_history_step(-1).expect("undo");
+        let state = app.play_state.as_ref().expect("play state");
+        assert_eq!(state.game.session.train_pool[0].remaining, 3);
+        assert!(state.game.session.corporations[0].trains.is_empty());
+    }
+
+    #[test]
+    fn game_cap_limits_the_list_but_not_search() {
+        let mut state = UiState {
+            game_cap: 3,
+            ..UiState::default()
+        };
+        let game = |id: &str| GameInfo {
+            id: id.to_string(),
+            title: format!("18{id}"),
+            ..sample_info()
+        };
+        state.set_games(["30", "40", "45", "60", "65", "89"].map(game).to_vec());
+        state.apply_filter();
+        let ids = |state: &UiState| -> Vec<String> {
+            state.filtered.iter().map(|game| game.id.clone()).collect()
+        };
+        assert!(state.is_capped());
+        assert_eq!(ids(&state), ["30", "40", "45"]);
+
+        // Recently played games lead the capped list; the selection follows its game.
+        state.move_to(1);
+        state.set_recent_games(vec!["89".to_string(), "40".to_string()]);
+        assert_eq!(ids(&state), ["89", "40", "30"]);
+        assert_eq!(state.current_game().unwrap().id, "40");
+
+        // Searching covers every game, including the ones the cap hides.
+        state.filter = "6".to_string();
+        state.apply_filter();
+        assert!(!state.is_capped());
+        assert_eq!(ids(&state), ["60", "65"]);
+
+        state.filter.clear();
+        state.toggle_show_all_games();
+        assert_eq!(state.filtered.len(), 6);
+        state.toggle_show_all_games();
+        assert_eq!(state.filtered.len(), 3);
+
+        // Selecting a hidden game lifts the cap instead of failing.
+        assert!(state.select_game("65"));
+        assert_eq!(state.filtered.len(), 6);
+    }
+
+    #[test]
+    fn most_recent_save_preselects_its_game() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        let other = GameInfo {
+            id: "other".to_string(),
+            title: "Other".to_string(),
+            folder: "g_other".to_string(),
+            ..sample_info()
+        };
+        app.state.set_games(vec![sample_info(), other.clone()]);
+        app.state.filter = "sample".to_string();
+        app.state.apply_filter();
+        app.save_manager = SaveManager::new(dir.path());
+        let older = app
+            .save_manager
+            .create_save(&other, Some("Other game"), Value::Null)
+            .expect("create save");
+        app.save_manager
+            .create_save(&sample_info(), Some("Sample game"), Value::Null)
+            .expect("create save");
+        app.save_manager
+            .update_save(&older, serde_json::json!({ "turn": 2 }))
+            .expect("update save");
+        app.refresh_saves().expect("saves");
+        // Scramble the in-memory order; the newest save on disk is still Other's.
+        app.saves.reverse();
+
+        app.restore_last_selection();
+        assert_eq!(
+            app.state.current_game().map(|game| game.id.as_str()),
+            Some("other")
+        );
+        assert!(app.state.filter.is_empty());
+        assert_eq!(app.state.status, "Restored saved selection: Other game");
+
+        app.config.select_last_played = false;
+        app.restore_last_selection();
+        assert_eq!(
+            app.state.current_game().map(|game| game.id.as_str()),
+            Some("sample")
+        );
+    }
+
+    #[test]
+    fn sync_progress_shows_a_gauge_until_the_sync_ends() {
+        let mut app = sample_app();
+        app.screen = Screen::Browse;
+        app.handle_sync_event(SyncEvent::Progress {
+            stage: "Receiving objects".to_string(),
+            percent: 45,
+        });
+        let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+        let rendered = |terminal: &mut Terminal<TestBackend>, app: &mut Tui18App| {
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            let buffer = terminal.backend().buffer();
+            buffer
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert!(rendered(&mut terminal, &mut app).contains("Sync: Receiving objects 45%"));
+
+        app.handle_sync_event(SyncEvent::Error(anyhow!("network down")));
+        assert!(app.sync_progress.is_none());
+        assert!(!rendered(&mut terminal, &mut app).contains("Sync:"));
+    }
+
+    #[test]
+    fn play_duration_uses_the_two_largest_units() {
+        assert_eq!(format_play_duration(TimeDelta::seconds(20)), "0m");
+        assert_eq!(format_play_duration(TimeDelta::minutes(45)), "45m");
+        assert_eq!(format_play_duration(TimeDelta::minutes(135)), "2h 15m");
+        assert_eq!(format_play_duration(TimeDelta::hours(76)), "3d 4h");
+    }
+
+    #[test]
+    fn replay_advances_through_history_without_writing() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.save_manager = SaveManager::new(dir.path());
+        app.config.autosave_idle_ticks = 0;
+        app.config.replay_step_ticks = 2;
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        let mut entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("replay"), to_value(&state).unwrap())
+            .expect("create save");
+        for _ in 0..2 {
+            let train = state.purchase_available_train(0).expect("train available");
+            state.current_corporation_mut().unwrap().trains.push(train);
+            entry = app
+                .save_manager
+                .update_save(&entry, to_value(&state).unwrap())
+                .expect("update save");
+        }
+        let before = fs::read(&entry.path).expect("read save");
+        app.active_save = Some(entry.clone());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let key = |code| {
+            Some(AppEvent::Input(Event::Key(KeyEvent::new(
+                code,
+                KeyModifiers::NONE,
+            ))))
+        };
+        let owned = |app: &Tui18App| {
+            app.play_state.as_ref().unwrap().game.session.corporations[0]
+                .trains
+                .len()
+        };
+
+        app.process_app_event(key(KeyCode::Char('R')));
+        assert_eq!(app.replay.as_ref().map(|replay| replay.index), Some(0));
+        assert_eq!(owned(&app), 0);
+        app.handle_tick();
+        app.handle_tick();
+        assert_eq!(app.replay.as_ref().map(|replay| replay.index), Some(1));
+        assert_eq!(owned(&app), 1);
+        app.process_app_event(key(KeyCode::Right));
+        let replay = app.replay.as_ref().expect("replaying");
+        assert_eq!((replay.index, replay.playing), (2, false));
+        app.process_app_event(key(KeyCode::Char('u')));
+        assert_eq!(app.replay.as_ref().map(|replay| replay.index), Some(2));
+
+        app.process_app_event(key(KeyCode::Esc));
+        assert!(app.replay.is_none());
+        assert_eq!(owned(&app), 2);
+        assert_eq!(fs::read(&entry.path).expect("read save"), before);
+    }
+
+    #[test]
+    fn accessible_summary_lists_selection_and_operating_order() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        state.game.set_price(0, 0, 1);
+        state.game.set_par(1, 1, 0);
+        let train = state.purchase_available_train(0).expect("train available");
+        state.current_corporation_mut().unwrap().trains.push(train);
+
+        let lines = state.accessible_summary(&CurrencyFormat::default());
+        assert_eq!(
+            lines,
+            [
+                "Sample",
+                "Phase: 2, OR 1 of 1",
+                "",
+                "Selected: Pennsylvania (PRR)",
+                "Par: $100",
+                "Share price: $110, market row 1, column 2",
+                "Last revenue: $0",
+                "Trains: 2 (no run yet)",
+                "",
+                "Operating order: 1. PRR $110; 2. NYC $90",
+                "Corporations:",
+                "PRR Pennsylvania: par $100, price $110, 1 train",
+                "NYC New York Central: par $90, price $90",
+            ]
+        );
+
+        let mut app = sample_app();
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('S'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        let overlay = app.inspector.as_ref().expect("summary overlay");
+        assert_eq!(overlay.title, "Summary");
+        assert_eq!(overlay.lines, lines);
+    }
+
+    #[test]
+    fn view_jumps_switch_between_play_views() {
+        let mut app = sample_app();
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        let mut press = |code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+            let state = app.play_state.as_ref().expect("play state");
+            (state.mode(), state.revenue_view_enabled())
+        };
+
+        assert_eq!(press(KeyCode::Char('3')), (PlayMode::Idle, true));
+        // In the revenue view digits are payouts, so only the function keys jump.
+        assert_eq!(press(KeyCode::Char('4')), (PlayMode::Idle, true));
+        assert_eq!(press(KeyCode::F(4)), (PlayMode::TrainManage, false));
+        assert_eq!(press(KeyCode::Esc), (PlayMode::Idle, false));
+        assert_eq!(press(KeyCode::F(3)), (PlayMode::Idle, true));
+        assert_eq!(press(KeyCode::F(2)), (PlayMode::ParSelect, false));
+        assert_eq!(press(KeyCode::Esc), (PlayMode::Idle, false));
+        assert_eq!(press(KeyCode::Char('3')), (PlayMode::Idle, true));
+        assert_eq!(press(KeyCode::F(1)), (PlayMode::Idle, false));
+    }
+
+    #[test]
+    fn par_selection_refuses_cells_without_a_value() {
+        // A par flag with no price in front of it.
+        let session = GameSession::fixture_with_market(&[
+            &["100p", "110", "120", "130"],
+            &["-p", "100", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        let mut app = sample_app();
+        let mut state = PlayState::new(session, 2);
+        assert!(state.enter_par_select());
+        state.move_market_cursor(1, 0);
+        assert_eq!(state.market_cursor(), (1, 0));
+
+        app.apply_par_selection(&mut state);
+        assert_eq!(app.state.status, "Selected cell has no par value");
+        assert_eq!(state.mode(), PlayMode::ParSelect);
+        assert_eq!(state.current_corporation().unwrap().par_value, None);
+
+        state.move_market_cursor(-1, 0);
+        app.apply_par_selection(&mut state);
+        assert_eq!(state.current_corporation().unwrap().par_value, Some(100));
+    }
+
+    #[test]
+    fn unknown_play_state_fields_survive_round_trip() {
+        let state = PlayState::new(GameSession::fixture(), 2);
+        let mut payload = to_value(&state).expect("serialize play state");
+        let object = payload.as_object_mut().expect("object payload");
+        object.insert(
+            "future_field".to_string(),
+            serde_json::json!({ "notes": [1, 2] }),
+        );
+        object.remove("revenue_view");
+
+        let restored: PlayState = from_value(payload).expect("tolerant load");
+        assert!(!restored.revenue_view);
+        let saved = to_value(&restored).expect("re-serialize play state");
+        assert_eq!(
+            saved["future_field"],
+            serde_json::json!({ "notes": [1, 2] })
+        );
+        assert_eq!(saved["revenue_view"], Value::Bool(false));
+    }
+
+    #[test]
+    fn play_state_save_keeps_game_fields_at_top_level() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_revenue(0, 1, 70);
+        let payload = to_value(&state).expect("serialize play state");
+        for key in [
+            "session",
+            "phases",
+            "phase_index",
+            "phase_rounds",
+            "revenue_view",
+        ] {
+            assert!(payload.get(key).is_some(), "missing top-level {key}");
+        }
+        assert!(payload.get("game").is_none());
+
+        let restored: PlayState = from_value(payload).expect("load play state");
+        assert_eq!(restored.game.revenue(0, 1), Some(70));
+        let saved = to_value(&restored).expect("re-serialize play state");
+        assert_eq!(saved, to_value(&state).expect("serialize original"));
+    }
+
+    #[test]
+    fn scrapping_to_pool_restores_supply_and_clamps_cursor() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        for selection in [0, 0, 1] {
+            let train = state.purchase_available_train(selection).expect("train");
+            state.current_corporation_mut().unwrap().trains.push(train);
+        }
+        assert_eq!(state.game.session.train_pool[0].remaining, 1);
+        state.set_owned_cursor(2);
+
+        let train = state.scrap_train(2, true).expect("returned 3 train");
+        assert_eq!(train.name, "3");
+        assert_eq!(state.game.session.train_pool[1].remaining, 2);
+        assert_eq!(state.owned_train_cursor(), 1);
+
+        let train = state.scrap_train(1, false).expect("rusted 2 train");
+        assert_eq!(train.name, "2");
+        assert_eq!(state.game.session.train_pool[0].remaining, 1);
+        assert_eq!(state.owned_train_cursor(), 0);
+
+        state.scrap_train(0, true).expect("returned 2 train");
+        assert_eq!(state.game.session.train_pool[0].remaining, 2);
+        assert_eq!(state.current_corporation().unwrap().last_revenue, 0);
+        assert!(state.scrap_train(0, true).is_none());
+    }
+
+    #[test]
+    fn purchase_filter_maps_back_to_available_index() {
+        assert_eq!(prefix_matches(["2", "2+", "3", "3+", "4"], "3"), [2, 3]);
+        assert_eq!(prefix_matches(["2", "D", "4d"], "d"), [1]);
+        assert_eq!(prefix_matches(["2", "3"], ""), [0, 1]);
+        assert!(prefix_matches(["2", "3"], "8").is_empty());
+
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.open_train_purchase_modal();
+        state.move_purchase_modal_cursor(1);
+        assert_eq!(state.purchase_modal_selection(), Some(1));
+        state.push_purchase_query('3');
+        assert_eq!(state.purchase_matches(), [1]);
+        state.move_purchase_modal_cursor(1);
+        assert_eq!(state.purchase_modal_selection(), Some(1));
+        state.push_purchase_query('x');
+        assert_eq!(state.purchase_modal_selection(), None);
+        assert!(state.clear_purchase_query());
+        assert!(!state.clear_purchase_query());
+        assert_eq!(state.purchase_modal_selection(), Some(0));
+    }
+
+    #[test]
+    fn corporation_commands_explain_games_without_corporations() {
+        let mut session = GameSession::fixture();
+        session.corporations.clear();
+        let mut app = sample_app();
+        let loaded = AppEvent::SessionLoaded(app.session_generation, Box::new(Ok(session)));
+        assert!(app.process_app_event(Some(loaded)));
+        assert_eq!(app.screen, Screen::Play);
+        assert_eq!(app.state.status, NO_CORPORATIONS_MESSAGE);
+        let press = |app: &mut Tui18App, code: KeyCode| {
+            app.handle_play_key(KeyEvent::new(code, KeyModifiers::NONE))
+                .expect("key handled");
+        };
+
+        for code in [
+            KeyCode::Char('p'),
+            KeyCode::Char('t'),
+            KeyCode::Enter,
+            KeyCode::Char('X'),
+            KeyCode::Char('e'),
+        ] {
+            press(&mut app, code);
+            assert_eq!(app.state.status, NO_CORPORATIONS_MESSAGE);
+        }
+        press(&mut app, KeyCode::Char('m'));
+        for code in [KeyCode::Char('+'), KeyCode::Char('3'), KeyCode::Char('a')] {
+            press(&mut app, code);
+            assert_eq!(app.state.status, NO_CORPORATIONS_MESSAGE);
+        }
+        press(&mut app, KeyCode::Char(']'));
+        press(&mut app, KeyCode::Char('j'));
+
+        let state = app.play_state.as_mut().expect("still playing");
+        assert_eq!(state.mode(), PlayMode::Idle);
+        assert!(state.enter_par_select());
+        assert!(state.apply_par_selection().is_none());
+        assert_eq!(
+            state
+                .apply_revenue_action(RevenueAction::Dividend)
+                .unwrap_err(),
+            RevenueError::NoCorporation
+        );
+        assert!(state.reset_current_corporation().is_none());
+    }
+
+    #[test]
+    fn market_palette_overrides_theme_zone_colors() {
+        let theme = Theme::default();
+        let config = AppConfig {
+            market_palette: HashMap::from([
+                ("Y".to_string(), "#e8c547".to_string()),
+                ("b".to_string(), "lightblue".to_string()),
+                ("o".to_string(), "not-a-color".to_string()),
+                ("yo".to_string(), "#000000".to_string()),
+            ]),
+            ..AppConfig::default()
+        };
+        let market = MarketOptions::from_config(&config);
+        assert_eq!(market.palette.len(), 2);
+
+        assert_eq!(
+            market_color("90y", &theme, &market.palette),
+            Color::Rgb(0xe8, 0xc5, 0x47)
+        );
+        assert_eq!(
+            market_color("60b", &theme, &market.palette),
+            Color::LightBlue
+        );
+        assert_eq!(
+            market_color("100o", &theme, &market.palette),
+            theme.accent_alt
+        );
+        assert_eq!(
+            market_color("80", &theme, &market.palette),
+            theme.primary_fg
+        );
+        assert_eq!(market_color("90y", &theme, &HashMap::new()), theme.warning);
+    }
+
+    #[test]
+    fn market_layout_respects_configured_minimum_and_spacing() {
+        let auto = MarketOptions::default();
+        assert_eq!(auto.layout(3, 40, 20), (5, 8));
+        assert_eq!(auto.layout(1, 40, 20), (4, 10));
+
+        let floor = MarketOptions {
+            min_cell_width: 8,
+            cell_spacing: 0,
+            ..MarketOptions::default()
+        };
+        assert_eq!(floor.layout(3, 40, 20), (8, 5));
+        assert_eq!(floor.layout(9, 40, 20).0, 11);
+
+        let spaced = MarketOptions {
+            min_cell_width: 0,
+            cell_spacing: 2,
+            ..MarketOptions::default()
+        };
+        assert_eq!(spaced.layout(3, 40, 20), (5, 6));
+        assert_eq!(spaced.layout(3, 40, 4), (5, 4));
+        assert_eq!(spaced.layout(3, 0, 4), (5, 1));
+    }
+
+    #[test]
+    fn market_zone_labels_follow_the_cell_code() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert_eq!(state.game.set_par(0, 0, 0), Some(100));
+        let raw = &state
+            .current_corporation()
+            .unwrap()
+            .market_position
+            .as_ref()
+            .unwrap()
+            .raw;
+        assert_eq!(market_zone_label(raw), Some("par"));
+        state.game.set_price(0, 2, 1);
+        let raw = &state
+            .current_corporation()
+            .unwrap()
+            .market_position
+            .as_ref()
+            .unwrap()
+            .raw;
+        assert_eq!(market_zone_label(raw), Some("yellow"));
+        // The par zone comes from the cell actually parred at, even when another cell
+        // holds the same value, and stays put while the price moves.
+        assert_eq!(state.game.set_par(0, 2, 2), Some(100));
+        state.game.set_price(0, 2, 1);
+        let corp = state.current_corporation().unwrap();
+        assert_eq!(par_zone_label(corp), Some("orange"));
+        state.game.reset_corporation(0);
+        assert_eq!(par_zone_label(state.current_corporation().unwrap()), None);
+        assert_eq!(market_zone_label("100O"), Some("orange"));
+        assert_eq!(market_zone_label("120"), None);
+        assert_eq!(market_zone_label("80x"), None);
+    }
+
+    #[test]
+    fn symbol_tokens_wrap_when_a_cell_is_crowded() {
+        assert_eq!(pack_tokens(&["PRR"], 8), [vec![0]]);
+        assert_eq!(pack_tokens(&["PRR", "NYC"], 8), [vec![0, 1]]);
+        assert_eq!(
+            pack_tokens(&["PRR", "NYC", "B&O"], 8),
+            [vec![0, 1], vec![2]]
+        );
+        assert_eq!(
+            pack_tokens(&["PRR", "NYC", "B&O"], 5),
+            [vec![0], vec![1], vec![2]]
+        );
+        assert_eq!(pack_tokens(&["LNWR", "GW"], 4), [vec![0], vec![1]]);
+        assert!(pack_tokens(&[], 8).is_empty());
+    }
+
+    #[test]
+    fn symbol_tokens_keep_one_line_with_an_overflow_marker() {
+        assert_eq!(fit_tokens(&["PRR", "NYC"], 8), (vec![0, 1], 0));
+        // "PRR +2" fits in eight columns; "PRR NYC +1" would not.
+        assert_eq!(fit_tokens(&["PRR", "NYC", "B&O"], 8), (vec![0], 2));
+        assert_eq!(fit_tokens(&["PRR", "NYC", "B&O"], 10), (vec![0, 1], 1));
+        assert_eq!(fit_tokens(&["PRR", "NYC"], 3), (vec![], 2));
+        assert_eq!(fit_tokens(&[], 8), (vec![], 0));
+    }
+
+    #[test]
+    fn reset_corporation_clears_state_and_returns_trains() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert!(state.enter_par_select());
+        assert_eq!(state.apply_par_selection(), Some(100));
+        let train = state.purchase_available_train(0).expect("train available");
+        state.current_corporation_mut().unwrap().trains.push(train);
+        assert_eq!(state.game.session.train_pool[0].remaining, 2);
+
+        let (sym, returned) = state
+            .reset_current_corporation()
+            .expect("corporation reset");
+        assert_eq!(sym, "PRR");
+        assert_eq!(returned, 1);
+        let corp = state.current_corporation().unwrap();
+        assert_eq!(corp.name, "Pennsylvania");
+        assert!(corp.par_value.is_none());
+        assert!(corp.market_position.is_none());
+        assert!(corp.trains.is_empty());
+        assert_eq!(corp.last_revenue, 0);
+        assert_eq!(state.game.session.train_pool[0].remaining, 3);
+    }
+
+    #[test]
+    fn clear_all_market_state_resets_every_corporation() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        state.game.set_price(0, 0, 2);
+        state.game.set_par(1, 1, 0);
+        let train = state.purchase_available_train(0).expect("train available");
+        state.current_corporation_mut().unwrap().trains.push(train);
+
+        assert_eq!(state.clear_all_market_state(false), 2);
+        assert!(state
+            .game
+            .session
+            .corporations
+            .iter()
+            .all(|corp| { corp.par_value.is_none() && corp.market_position.is_none() }));
+        assert_eq!(state.game.session.corporations[0].trains.len(), 1);
+        assert_eq!(state.clear_all_market_state(false), 0);
+
+        assert_eq!(state.clear_all_market_state(true), 1);
+        assert!(state.game.session.corporations[0].trains.is_empty());
+        assert_eq!(state.game.session.train_pool[0].remaining, 3);
+    }
+
+    #[test]
+    fn clearing_the_market_is_one_confirmed_history_step() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.save_manager = SaveManager::new(dir.path());
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        state.game.set_par(1, 1, 0);
+        let entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("clear"), to_value(&state).unwrap())
+            .expect("create save");
+        app.active_save = Some(entry);
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let key = |code| {
+            Some(AppEvent::Input(Event::Key(KeyEvent::new(
+                code,
+                KeyModifiers::NONE,
+            ))))
+        };
+
+        app.process_app_event(key(KeyCode::Char('C')));
+        assert!(app.confirm.is_some());
+        app.process_app_event(key(KeyCode::Char('y')));
+        assert_eq!(
+            app.state.status,
+            "Cleared pars and share prices for 2 corporations"
+        );
+        let parred = |app: &Tui18App| {
+            let state = app.play_state.as_ref().expect("play state");
+            state
+                .game
+                .session
+                .corporations
+                .iter()
+                .filter(|corp| corp.par_value.is_some())
+                .count()
+        };
+        assert_eq!(parred(&app), 0);
+
+        app.apply_history_step(-1).expect("undo");
+        assert_eq!(parred(&app), 2);
+    }
+
+    #[test]
+    fn export_session_writes_into_the_data_dir() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.config.data_dir = Some(dir.path().to_path_buf());
+        app.export_session(&GameSession::fixture());
+        assert_eq!(
+            app.state.status_level,
+            StatusLevel::Success,
+            "{}",
+            app.state.status
+        );
+        let exported = std::fs::read_dir(dir.path().join("exports"))
+            .expect("exports")
+            .count();
+        assert_eq!(exported, 1);
+    }
+
+    #[test]
+    fn confirm_modal_accepts_cancels_and_ignores_other_keys() {
+        let answer = |code| ConfirmModal::answer(&KeyEvent::new(code, KeyModifiers::NONE));
+        for code in [KeyCode::Char('y'), KeyCode::Char('Y'), KeyCode::Enter] {
+            assert_eq!(answer(code), Some(true));
+        }
+        for code in [KeyCode::Char('n'), KeyCode::Char('N'), KeyCode::Esc] {
+            assert_eq!(answer(code), Some(false));
+        }
+        for code in [KeyCode::Char('x'), KeyCode::Tab, KeyCode::Down] {
+            assert_eq!(answer(code), None);
+        }
+
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        app.play_state = Some(state);
+        app.confirm = Some(ConfirmModal {
+            action: ConfirmAction::ClearMarketState,
+            message: "Clear?".to_string(),
+        });
+        let press = |app: &mut Tui18App, code| {
+            app.handle_confirm_key(KeyEvent::new(code, KeyModifiers::NONE))
+                .expect("key handled")
+        };
+
+        press(&mut app, KeyCode::Char('x'));
+        assert!(app.confirm.is_some());
+        press(&mut app, KeyCode::Esc);
+        assert!(app.confirm.is_none());
+        assert_eq!(app.state.status, "Action cancelled");
+        let state = app.play_state.as_ref().unwrap();
+        assert!(state.game.session.corporations[0].par_value.is_some());
+    }
+
+    #[test]
+    fn destructive_actions_skip_the_prompt_when_confirmation_is_off() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.config.confirm_destructive = false;
+        app.save_manager = SaveManager::new(dir.path());
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_par(0, 0, 0);
+        let entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("fast"), to_value(&state).unwrap())
+            .expect("create save");
+        app.active_save = Some(entry);
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('X'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert!(app.confirm.is_none());
+        assert!(
+            app.state.status.starts_with("PRR reset"),
+            "{}",
+            app.state.status
+        );
+        let par = |app: &Tui18App| {
+            app.play_state.as_ref().unwrap().game.session.corporations[0].par_value
+        };
+        assert_eq!(par(&app), None);
+
+        app.apply_history_step(-1).expect("undo");
+        assert!(par(&app).is_some());
+    }
+
+    #[test]
+    fn comparing_divergent_states_lists_each_difference() {
+        let currency = CurrencyFormat::default();
+        let mut left = PlayState::new(GameSession::fixture(), 2);
+        left.game.set_par(0, 0, 0);
+        let mut right = left.clone();
+        assert!(left.compare(&right, &currency).is_empty());
+
+        right.game.set_price(0, 0, 2);
+        right.game.set_par(1, 1, 0);
+        right.game.session.corporations[0].last_revenue = 60;
+        let train = right.game.purchase_train(0).expect("train in pool");
+        right.game.session.corporations[0].trains.push(train);
+
+        let row = |sym: &str, field, left: &str, right: &str| ComparisonRow {
+            sym: sym.to_string(),
+            field,
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+        assert_eq!(
+            left.compare(&right, &currency),
+            [
+                row("PRR", "price", "$100", "$120"),
+                row("PRR", "revenue", "$0", "$60"),
+                row("PRR", "trains", "-", "2"),
+                row("NYC", "par", "-", "$90"),
+                row("NYC", "price", "-", "$90"),
+            ]
+        );
+    }
+
+    #[test]
+    fn marking_two_saves_opens_a_comparison() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut app = sample_app();
+        app.save_manager = SaveManager::new(dir.path());
+        let base = PlayState::new(GameSession::fixture(), 2);
+        let mut parred = base.clone();
+        parred.game.set_par(0, 0, 0);
+        // Save files are named by game id and second, so give each save its own id.
+        for (name, state) in [("base", &base), ("parred", &parred)] {
+            let info = GameInfo {
+                id: name.to_string(),
+                ..sample_info()
+            };
+            app.save_manager
+                .create_save(&info, Some(name), to_value(state).unwrap())
+                .expect("create save");
+        }
+        app.refresh_saves().expect("refresh saves");
+        app.screen = Screen::Continue;
+        let press = |app: &mut Tui18App, code| {
+            app.handle_continue_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled")
+        };
+
+        press(&mut app, KeyCode::Char('m'));
+        assert!(app.compare_mark.is_some() && app.comparison.is_none());
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('m'));
+        let comparison = app.comparison.as_ref().expect("comparison open");
+        assert_eq!(comparison.rows.len(), 2, "{:?}", comparison.rows);
+        assert!(app.compare_mark.is_none());
+
+        press(&mut app, KeyCode::Esc);
+        assert!(app.comparison.is_none());
+        assert_eq!(app.screen, Screen::Continue);
+    }
+
+    #[test]
+    fn duplicated_operating_round_copies_the_selected_column() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_revenue(0, 0, 50);
+        state.game.set_revenue(1, 0, 80);
+        state.add_operating_round();
+        state.revenue_cursor_or = 0;
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('D'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert_eq!(
+            app.state.status,
+            "Added operating round OR3 copied from OR1"
+        );
+        let state = app.play_state.as_ref().expect("play state");
+        assert_eq!(state.revenue_cursor_or, 2);
+        let rounds = state.current_phase_rounds();
+        assert_eq!(rounds[2].revenues, rounds[0].revenues);
+        assert_eq!(rounds[1].revenues, [0, 0]);
+    }
+
+    #[test]
+    fn quiet_status_drops_routine_messages_only() {
+        let mut app = sample_app();
+        app.config.verbose_status = false;
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        app.state.set_status("Session loaded".to_string());
+
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('m'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert!(app.play_state.as_ref().unwrap().revenue_view_enabled());
+        assert_eq!(app.state.status, "Session loaded");
+        assert_eq!(app.state.status_level, StatusLevel::Info);
+
+        app.set_error_status("Auto-save failed: disk full".to_string());
+        assert_eq!(app.state.status, "Auto-save failed: disk full");
+        assert_eq!(app.state.status_level, StatusLevel::Error);
+
+        app.config.verbose_status = true;
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('m'),
+            KeyModifiers::NONE,
+        )))
+        .expect("key handled");
+        assert_eq!(app.state.status, "Stock market view enabled");
+        assert_eq!(app.state.status_level, StatusLevel::Routine);
+    }
+
+    #[test]
+    fn status_levels_map_to_theme_colors() {
+        let theme = Theme::default();
+        assert_eq!(StatusLevel::Error.color(&theme), theme.danger);
+        assert_eq!(StatusLevel::Info.color(&theme), theme.muted);
+        assert_eq!(StatusLevel::Routine.color(&theme), theme.muted);
+        assert_eq!(StatusLevel::Success.color(&theme), theme.success);
+    }
+
+    #[test]
+    fn status_line_is_colored_and_wraps_long_errors() {
+        let mut app = sample_app();
+        app.set_error_status(format!("Auto-save failed: {}", "disk full ".repeat(8)));
+        assert_eq!(app.status_height(40), 5);
+        assert!(app.status_text().ends_with(&app.state.status));
+
+        let backend = TestBackend::new(40, 5);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        terminal
+            .draw(|frame| app.render_status(frame, frame.size()))
+            .expect("draw");
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.get(1, 1).fg, app.theme.danger);
+        let rows: Vec<String> = (1..4)
+            .map(|y| (1..39).map(|x| buffer.get(x, y).symbol()).collect())
+            .collect();
+        assert!(rows[0].contains("Auto-save failed"));
+        assert!(rows[2].contains("disk full"));
+
+        app.state.set_success("Session loaded".to_string());
+        assert_eq!(app.status_height(40), 3);
+
+        app.set_read_only(true);
+        app.state.set_success("Session loaded".to_string());
+        terminal
+            .draw(|frame| app.render_status(frame, frame.size()))
+            .expect("draw");
+        let buffer = terminal.backend().buffer();
+        let title: String = (0..40).map(|x| buffer.get(x, 0).symbol()).collect();
+        assert!(title.contains("Status (read-only, not saving)"), "{title}");
+    }
+
+    #[test]
+    fn par_legend_only_shows_during_par_selection() {
+        let mut app = sample_app();
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        let mut rendered = |app: &mut Tui18App| {
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+            let buffer = terminal.backend().buffer();
+            buffer
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let legend = "2 par spaces · hjkl to move · p/Enter to set";
+
+        app.play_state.as_mut().unwrap().consume_title_banner();
+        assert!(!rendered(&mut app).contains(legend));
+        let state = app.play_state.as_mut().unwrap();
+        assert!(state.enter_par_select());
+        assert!(rendered(&mut app).contains(legend));
+
+        let state = app.play_state.as_mut().unwrap();
+        state.game.set_par(0, 1, 0);
+        assert_eq!(
+            state.par_legend(&CurrencyFormat::default()),
+            format!("{legend} · current par $90")
+        );
+    }
+
+    #[test]
+    fn price_legend_shows_the_hovered_operating_rank() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_price(1, 0, 1);
+        state.enter_price_select();
+        let currency = CurrencyFormat::default();
+        state.market_cursor = (0, 2);
+        assert_eq!(
+            state.price_legend(&currency),
+            "$120 would be #1 in operating order · Enter to set"
+        );
+        state.market_cursor = (1, 0);
+        assert_eq!(
+            state.price_legend(&currency),
+            "$90 would be #2 in operating order · Enter to set"
+        );
+    }
+
+    #[test]
+    fn market_rulers_shrink_the_visible_grid() {
+        let app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.consume_title_banner();
+        assert!(state.enter_par_select());
+        let mut terminal = Terminal::new(TestBackend::new(21, 6)).unwrap();
+        let mut view = |state: &mut PlayState| {
+            terminal
+                .draw(|frame| {
+                    Tui18App::render_play_market(
+                        &app.theme,
+                        &app.currency,
+                        &app.market_options,
+                        frame,
+                        frame.size(),
+                        state,
+                    )
+                })
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            let rows: Vec<String> = (0..6)
+                .map(|y| (0..21).map(|x| buffer.get(x, y).symbol()).collect())
+                .collect();
+            ((state.market_view_rows(), state.market_view_cols()), rows)
+        };
+
+        let (plain, _) = view(&mut state);
+        assert_eq!(plain, (3, 4));
+        assert!(state.toggle_market_rulers());
+        let ((rows, cols), lines) = view(&mut state);
+        assert!(rows < plain.0 && cols < plain.1, "{rows}x{cols}");
+        assert!(
+            lines[1].contains('1') && lines[1].contains('2'),
+            "{lines:?}"
+        );
+        assert!(lines[2].starts_with("│1 "), "{lines:?}");
+    }
+
+    #[test]
+    fn page_moves_cover_a_view_of_the_market_and_stop_at_edges() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.enter_price_select();
+        state.set_market_view(2, 2);
+        state.market_cursor = (0, 0);
+        let mut page = |rows, cols| {
+            state.page_market_cursor(rows, cols);
+            state.market_cursor()
+        };
+        assert_eq!(page(0, 1), (0, 2));
+        assert_eq!(page(0, 1), (0, 3));
+        // Down through the ragged rows, then clamped at the bottom.
+        assert_eq!(page(1, 0), (2, 2));
+        assert_eq!(page(1, 0), (2, 2));
+        assert_eq!(page(-1, 0), (0, 2));
+        assert_eq!(page(0, -1), (0, 0));
+        assert_eq!(page(0, -1), (0, 0));
+    }
+
+    #[test]
+    fn page_moves_cover_a_view_of_the_revenue_grid() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        for _ in 0..4 {
+            state.add_operating_round();
+        }
+        state.set_revenue_view_dims(1, 2);
+        state.revenue_cursor_corp = 0;
+        state.revenue_cursor_or = 0;
+        let mut page = |rows, cols| {
+            state.page_revenue_cursor(rows, cols);
+            state.revenue_cursor()
+        };
+        assert_eq!(page(0, 1), (0, 2));
+        assert_eq!(page(0, 1), (0, 4));
+        assert_eq!(page(0, 1), (0, 4));
+        assert_eq!(page(1, 0), (1, 4));
+        assert_eq!(page(1, 0), (1, 4));
+        assert_eq!(page(0, -1), (1, 2));
+    }
+
+    #[test]
+    fn next_unparred_corporation_wraps_and_reports_when_done() {
+        let mut session = GameSession::fixture();
+        let mut third = session.corporations[0].clone();
+        third.sym = "B&O".to_string();
+        session.corporations.push(third);
+        let mut state = PlayState::new(session, 2);
+
+        // Some unparred: skips the parred NYC.
+        state.game.set_par(1, 0, 0);
+        assert_eq!(state.next_unparred_corporation(), Some(2));
+        // Wraparound back to PRR.
+        assert_eq!(state.next_unparred_corporation(), Some(0));
+        // The current corporation is found again when it is the only one left.
+        state.game.set_par(2, 0, 0);
+        assert_eq!(state.next_unparred_corporation(), Some(0));
+
+        state.game.set_par(0, 1, 0);
+        assert_eq!(state.next_unparred_corporation(), None);
+        assert_eq!(state.corporation_index, 0);
+    }
+
+    #[test]
+    fn dividends_are_split_across_recorded_owners() {
+        let mut app = sample_app();
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        let give_share = |app: &mut Tui18App, name: &str| {
+            let codes = std::iter::once(KeyCode::Char('o'))
+                .chain(name.chars().map(KeyCode::Char))
+                .chain([KeyCode::Enter]);
+            for code in codes {
+                let key = Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+                app.process_app_event(Some(AppEvent::Input(key)));
+            }
+            app.state.status.clone()
+        };
+        assert_eq!(give_share(&mut app, "Ann"), "Ann now holds 20% of PRR");
+        assert_eq!(give_share(&mut app, "Bo"), "Bo now holds 10% of PRR");
+        give_share(&mut app, "Bo");
+        give_share(&mut app, "Cy");
+        assert_eq!(
+            give_share(&mut app, "-Cy"),
+            "Cy sells a PRR share, now holds 0%"
+        );
+
+        let state = app.play_state.as_ref().unwrap();
+        let corp = &state.game.session.corporations[0];
+        assert_eq!(
+            dividend_payout_line(&app.currency, 95, Some(corp)),
+            "Dividends: Pres Ann $18 · each 10%: $9 · Bo $18"
+        );
+        let unowned = &state.game.session.corporations[1];
+        assert_eq!(
+            dividend_payout_line(&app.currency, 100, Some(unowned)),
+            share_payout_line(&app.currency, 100)
+        );
+    }
+
+    #[test]
+    fn share_payout_line_rounds_down_like_the_presets() {
+        let currency = CurrencyFormat::default();
+        assert_eq!(
+            share_payout_line(&currency, 95),
+            "Dividends: 10% $9 | 20% $19 | 30% $28 | 40% $38 | 50% $47 | 60% $57"
+        );
+        assert_eq!(share_payout_line(&currency, 0), "Dividends: $0");
+    }
+
+    #[test]
+    fn pinned_title_banner_survives_keys_until_the_market_opens() {
+        let mut app = sample_app();
+        app.play_state = Some(PlayState::new(GameSession::fixture(), 2));
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App, code| {
+            app.handle_input(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+                .expect("key handled");
+            app.play_state.as_ref().unwrap().should_show_title_banner()
+        };
+
+        // Auto-hide: the first ordinary key clears it, B brings it back pinned.
+        assert!(!press(&mut app, KeyCode::Char('y')));
+        assert!(press(&mut app, KeyCode::Char('B')));
+        assert!(press(&mut app, KeyCode::Char('y')));
+        assert!(press(&mut app, KeyCode::Char('j')));
+        // Opening the market needs the space even while pinned.
+        assert!(!press(&mut app, KeyCode::Enter));
+        press(&mut app, KeyCode::Esc);
+        assert!(!press(&mut app, KeyCode::Char('y')));
+
+        let state = app.play_state.as_mut().unwrap();
+        assert!(!state.toggle_title_banner_pin());
+        assert!(!state.should_show_title_banner());
+    }
+
+    #[test]
+    fn revenue_presets_follow_the_preset_mode() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.session.corporations[0].last_revenue = 95;
+        assert_eq!(state.revenue_preset_mode, RevenuePresetMode::Percent);
+        assert_eq!(state.revenue_preset_value(3), Some(28));
+        assert_eq!(state.revenue_preset_value(6), Some(57));
+        assert!(state.toggle_revenue_view());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App, ch| {
+            app.handle_input(Event::Key(KeyEvent::new(
+                KeyCode::Char(ch),
+                KeyModifiers::NONE,
+            )))
+            .expect("key handled");
+            app.play_state.as_ref().unwrap().current_revenue_value()
+        };
+
+        assert_eq!(press(&mut app, '2'), Some(19));
+        assert_eq!(app.state.status, "PRR OR1 payout set to $19 (20%)");
+        press(&mut app, '%');
+        let state = app.play_state.as_ref().unwrap();
+        assert_eq!(state.revenue_preset_mode, RevenuePresetMode::Absolute);
+        assert_eq!(state.revenue_preset_value(3), Some(30));
+        assert_eq!(press(&mut app, '4'), Some(40));
+        assert_eq!(app.state.status, "PRR OR1 payout set to $40");
+        let state = app.play_state.as_ref().unwrap();
+        let entries = state.revenue_preset_entries(&app.currency);
+        assert_eq!(entries[0], ("1:$10".to_string(), false));
+        assert_eq!(entries[3], ("4:$40".to_string(), true));
+        assert_eq!(entries.iter().filter(|(_, active)| *active).count(), 1);
+        press(&mut app, '%');
+        assert_eq!(press(&mut app, '1'), Some(9));
+        let state = app.play_state.as_ref().unwrap();
+        let entries = state.revenue_preset_entries(&app.currency);
+        assert_eq!(entries[0], ("10%:$9".to_string(), true));
+        assert_eq!(entries[3], ("40%:$38".to_string(), false));
+    }
+
+    #[test]
+    fn share_price_is_copied_into_the_selected_payout() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert!(state.toggle_revenue_view());
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App| {
+            app.handle_input(Event::Key(KeyEvent::new(
+                KeyCode::Char('$'),
+                KeyModifiers::NONE,
+            )))
+            .expect("key handled");
+            app.play_state.as_ref().unwrap().current_revenue_value()
+        };
+
+        assert_eq!(press(&mut app), Some(0));
+        assert_eq!(app.state.status, "PRR has no share price to copy");
+        app.play_state.as_mut().unwrap().game.set_price(0, 0, 2);
+        assert_eq!(press(&mut app), Some(120));
+        assert_eq!(app.state.status, "PRR OR1 payout set to share price $120");
+    }
+
+    #[test]
+    fn revenue_edits_sync_focus_when_enabled() {
+        let mut app = sample_app();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_price(1, 2, 1);
+        assert!(state.toggle_revenue_view());
+        // The corporation list drifted away from the revenue row being edited.
+        state.revenue_cursor_corp = 1;
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App| {
+            app.handle_input(Event::Key(KeyEvent::new(
+                KeyCode::Char('+'),
+                KeyModifiers::NONE,
+            )))
+            .expect("key handled");
+        };
+
+        press(&mut app);
+        let state = app.play_state.as_mut().unwrap();
+        assert_eq!(state.corporation_index, 0);
+        assert_eq!(state.preferred_market_cursor, None);
+
+        state.focus_follows_revenue = true;
+        press(&mut app);
+        let state = app.play_state.as_ref().unwrap();
+        assert_eq!(state.game.revenue(1, 0), Some(20));
+        assert_eq!(state.corporation_index, 1);
+        assert_eq!(state.preferred_market_cursor, Some((2, 1)));
+    }
+
+    #[test]
+    fn revenue_focus_sync_survives_undo() {
+        let mut app = sample_app();
+        app.config.focus_follows_revenue = true;
+        app.save_manager = SaveManager::in_memory();
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_price(1, 2, 1);
+        assert!(state.toggle_revenue_view());
+        state.revenue_cursor_corp = 1;
+        let entry = app
+            .save_manager
+            .create_save(&sample_info(), Some("focus"), to_value(&state).unwrap())
+            .expect("create save");
+        app.active_save = Some(entry);
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+        let press = |app: &mut Tui18App| {
+            app.handle_input(Event::Key(KeyEvent::new(
+                KeyCode::Char('+'),
+                KeyModifiers::NONE,
+            )))
+            .expect("key handled");
+        };
+
+        press(&mut app);
+        press(&mut app);
+        app.apply_history_step(-1).expect("undo");
+        let state = app.play_state.as_mut().unwrap();
+        assert_eq!(state.game.revenue(1, 0), Some(10));
+        state.corporation_index = 0;
+        press(&mut app);
+        let state = app.play_state.as_ref().unwrap();
+        assert_eq!(state.game.revenue(1, 0), Some(20));
+        assert_eq!(state.corporation_index, 1);
+    }
+
+    #[test]
+    fn leaving_revenue_view_focuses_the_selected_corporations_price() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.game.set_price(1, 2, 1);
+        let start = state.market_cursor();
+
+        assert!(state.toggle_revenue_view());
+        state.move_revenue_cursor(1, 0);
+        assert!(!state.toggle_revenue_view());
+        assert_eq!(state.market_cursor(), (2, 1));
+
+        // A corporation without a share price leaves the market cursor alone.
+        state.market_cursor = start;
+        assert!(state.toggle_revenue_view());
+        state.move_revenue_cursor(-1, 0);
+        assert!(!state.toggle_revenue_view());
+        assert_eq!(state.market_cursor(), start);
+    }
+
+    #[test]
+    fn cursor_snaps_to_the_nearest_usable_cell() {
+        // (1, 1) is blank so the middle of the grid is empty.
+        let session = GameSession::fixture_with_market(&[
+            &["100p", "110", "120", "130"],
+            &["90p", "", "110"],
+            &["80", "90y", "100o"],
+        ]);
+        let mut state = PlayState::new(session, 2);
+
+        state.market_cursor = (1, 1);
+        assert!(state.snap_cursor_to_valid());
+        assert_eq!(state.market_cursor, (0, 1));
+        assert!(!state.snap_cursor_to_valid());
+
+        // Past the ragged end of row 1: the closest cell is in the same row.
+        state.market_cursor = (1, 3);
+        assert!(state.snap_cursor_to_valid());
+        assert_eq!(state.market_cursor, (0, 3));
+
+        state.market_cursor = (9, 9);
+        assert!(state.snap_cursor_to_valid());
+        assert_eq!(state.market_cursor, (2, 2));
+
+        state.mode = PlayMode::ParSelect;
+        state.market_cursor = (2, 1);
+        assert!(state.snap_cursor_to_valid());
+        assert_eq!(state.market_cursor, (1, 0));
+
+        state.mode = PlayMode::Idle;
+        state.market_cursor = (1, 1);
+        state.set_market_view(2, 2);
+        assert_eq!(state.market_cursor, (0, 1));
+    }
+
+    #[test]
+    fn restored_scroll_offsets_are_clamped_to_view() {
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        state.market_cursor = (2, 2);
+        state.market_row_offset = 40;
+        state.market_col_offset = 40;
+        state.market_view_rows = 2;
+        state.market_view_cols = 2;
+        state.revenue_cursor_corp = 1;
+        state.revenue_row_offset = 40;
+        state.revenue_col_offset = 40;
+        let saved = to_value(&state).expect("serialize play state");
+
+        let mut restored: PlayState = from_value(saved).expect("deserialize play state");
+        assert_eq!(restored.market_row_offset, 40);
+        restored.clamp_restored_view();
+        assert_eq!(restored.market_row_offset, 1);
+        assert_eq!(restored.market_col_offset, 2);
+        assert_eq!(restored.revenue_row_offset, 1);
+        assert_eq!(restored.revenue_col_offset, 0);
+
+        restored.set_market_view(1, 1);
+        assert_eq!(
+            (restored.market_row_offset, restored.market_col_offset),
+            (2, 2)
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_session_load_discards_stale_result() {
+        let mut app = sample_app();
+        let (tx, _rx) = mpsc::channel(4);
+        app.event_tx = Some(tx);
+
+        app.start_session_load();
+        assert!(app.pending_session);
+        assert!(app.load_started.is_some());
+        app.handle_tick();
+        assert_eq!(app.spinner_frame, 1);
+        let cancelled = app.session_generation;
+        app.handle_input(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)))
+            .expect("cancel load");
+        assert!(!app.pending_session);
+        assert!(app.loading_indicator().is_none());
+        assert_eq!(app.screen, Screen::Browse);
+
+        app.start_session_load();
+        assert!(app.pending_session);
+        let stale = AppEvent::SessionLoaded(cancelled, Box::new(Ok(GameSession::fixture())));
+        assert!(app.process_app_event(Some(stale)));
+        assert!(app.pending_session);
+        assert!(app.play_state.is_none());
+
+        let current =
+            AppEvent::SessionLoaded(app.session_generation, Box::new(Ok(GameSession::fixture())));
+        assert!(app.process_app_event(Some(current)));
+        assert!(!app.pending_session);
+        assert!(app.load_started.is_none());
+        assert_eq!(app.screen, Screen::Play);
+        assert!(app.play_state.is_some());
+    }
+
+    #[tokio::test]
+    async fn refresh_swaps_engine_data_into_the_open_game() {
+        let mut app = sample_app();
+        let (tx, _rx) = mpsc::channel(4);
+        app.event_tx = Some(tx);
+        let mut state = PlayState::new(GameSession::fixture(), 2);
+        assert_eq!(state.game.set_par(0, 0, 0), Some(100));
+        app.play_state = Some(state);
+        app.screen = Screen::Play;
+
+        app.handle_input(Event::Key(KeyEvent::new(
+            KeyCode::Char('r'),
+            KeyModifiers::NONE,
+        )))
+        .expect("refresh started");
+        assert!(app.pending_session && app.refreshing_session);
+        assert!(
+            app.state.status.starts_with("Refreshing"),
+            "{}",
+            app.state.status
+        );
+
+        let mut fresh = GameSession::fixture();
+        fresh.corporations[0].name = "Pennsy".to_string();
+        let loaded = AppEvent::SessionLoaded(app.session_generation, Box::new(Ok(fresh)));
+        assert!(app.process_app_event(Some(loaded)));
+        assert!(!app.pending_session && !app.refreshing_session);
+        assert_eq!(app.screen, Screen::Play);
+        let corp = app
+            .play_state
+            .as_ref()
+            .unwrap()
+            .current_corporation()
+            .unwrap();
+        assert_eq!((corp.name.as_str(), corp.par_value), ("Pennsy", Some(100)));
+        assert!(
+            app.state.status.starts_with("Refreshed"),
+            "{}",
+            app.state.status
+        );
+
+        // A failed refresh keeps the game open.
+        app.refresh_active_session();
+        let failed =
+            AppEvent::SessionLoaded(app.session_generation, Box::new(Err(anyhow!("boom"))));
+        assert!(app.process_app_event(Some(failed)));
+        assert_eq!(app.screen, Screen::Play);
+        assert_eq!(app.state.status, "Refresh failed: boom");
+    }
+}
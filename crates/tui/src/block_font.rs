@@ -481,11 +481,10 @@ pub fn render(text: &str) -> Vec<String> {
             rows[row_idx].push_str(segment);
             let pad = glyph_width.saturating_sub(segment.chars().count());
             if pad > 0 {
-                rows[row_idx].extend(std::iter::repeat(' ').take(pad));
+                rows[row_idx].extend(std::iter::repeat_n(' ', pad));
             }
             if index < content.len() - 1 {
-                rows[row_idx]
-                    .extend(std::iter::repeat(' ').take(GLYPH_SPACING));
+                rows[row_idx].extend(std::iter::repeat_n(' ', GLYPH_SPACING));
             }
         }
     }
@@ -0,0 +1,110 @@
+//! Copying short snippets (bug-report identifiers) to the user's clipboard.
+//!
+//! OSC 52 is the default because it reaches the local clipboard through SSH and tmux;
+//! the `system` mode shells out to the platform tool instead.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// Formats the identifier users paste into bug reports.
+pub(crate) fn bug_report_line(
+    game_id: &str,
+    commit: Option<&str>,
+    at: Option<DateTime<Utc>>,
+) -> String {
+    let commit = commit
+        .map(|commit| commit.trim().chars().take(7).collect::<String>())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    let at = at
+        .map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| "unknown".to_string());
+    format!("game={game_id} commit={commit} at={at}")
+}
+
+/// Status text flattened to one line for pasting; `None` when there is nothing to copy.
+pub(crate) fn status_payload(status: &str) -> Option<String> {
+    let text = status.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Escape sequence asking the terminal to place `text` on the system clipboard.
+pub(crate) fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+/// Pipes `text` into the first clipboard tool available on this platform.
+pub(crate) fn copy_with_system_tool(text: &str) -> Result<()> {
+    let candidates: &[(&str, &[&str])] = if cfg!(target_os = "windows") {
+        &[("clip", &[])]
+    } else if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["-ib"]),
+        ]
+    };
+    for (program, args) in candidates {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .with_context(|| format!("failed to write to {program}"))?;
+        }
+        let status = child
+            .wait()
+            .with_context(|| format!("failed to wait for {program}"))?;
+        if status.success() {
+            return Ok(());
+        }
+        bail!("{program} exited with {status}");
+    }
+    bail!("no clipboard tool found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn formats_bug_report_line() {
+        let at = Utc.with_ymd_and_hms(2024, 3, 9, 14, 5, 0).unwrap();
+        assert_eq!(
+            bug_report_line("1889", Some("abc1234def5678"), Some(at)),
+            "game=1889 commit=abc1234 at=2024-03-09T14:05:00Z"
+        );
+        assert_eq!(
+            bug_report_line("18Chesapeake", None, None),
+            "game=18Chesapeake commit=unknown at=unknown"
+        );
+        assert_eq!(osc52_sequence("hi"), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn status_payload_is_a_single_trimmed_line() {
+        let payload = status_payload("  Sync failed:\n  git fetch exited   with 128 ").unwrap();
+        assert_eq!(payload, "Sync failed: git fetch exited with 128");
+        assert_eq!(
+            osc52_sequence(&payload),
+            format!("\x1b]52;c;{}\x07", STANDARD.encode(&payload))
+        );
+        assert_eq!(status_payload(" \n "), None);
+    }
+}
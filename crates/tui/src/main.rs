@@ -6,51 +6,183 @@
 
 mod app;
 mod block_font;
+mod clipboard;
+mod opener;
+mod validate;
 
-use anyhow::Result;
-use std::fs::{self, OpenOptions};
+use anyhow::{bail, Result};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, BufRead, Write},
+    panic,
+    path::Path,
+    process, thread,
+};
 
 use tokio::sync::mpsc;
 use tracing_subscriber::{prelude::*, EnvFilter};
 use tui18_core::{
     config::{self, AppConfig},
+    lock::{self, InstanceLock, LockStatus},
+    manifest::{self, ResourceMetadata},
     resource::{ResourceLoader, ResourceSync},
-    session::SessionLoader,
+    session::{self, SessionLoader},
 };
 
 /// Boots the async runtime, prepares shared services, and hands control over to the UI loop.
 #[tokio::main]
 async fn main() -> Result<()> {
-    init_logging()?;
+    let debug = std::env::args().skip(1).any(|arg| arg == "--debug");
+    let watch = std::env::args().skip(1).any(|arg| arg == "--watch");
+    let validate = std::env::args().skip(1).any(|arg| arg == "--validate");
+    let json = std::env::args().skip(1).any(|arg| arg == "--json");
+    let print_manifest = std::env::args().skip(1).any(|arg| arg == "--manifest");
 
-    // Configuration drives where the Ruby engine lives and where saves are stored.
+    // Configuration drives where the Ruby engine lives and where saves and logs are stored.
     config::ensure_default_config()?;
     let config = AppConfig::load()?;
+    init_logging(&config)?;
+    install_panic_hook();
+
+    // `--manifest` prints the engine snapshot metadata as JSON for scripts and exits.
+    if print_manifest {
+        let repo_path = ResourceSync::new(config.clone()).repo_path();
+        match manifest_json(&repo_path)? {
+            Some(json) => println!("{json}"),
+            None => {
+                let path = manifest::manifest_path(&repo_path);
+                eprintln!(
+                    "No manifest at {}; run 18tui once to sync the engine",
+                    path.display()
+                );
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // One instance owns saves and the cache; a second one may only look. Validation
+    // never writes, so it skips the lock. Held until `main` returns.
+    let mut read_only = false;
+    let _lock = if validate {
+        None
+    } else {
+        match InstanceLock::acquire(&config.cache_dir())? {
+            LockStatus::Acquired(lock) => Some(lock),
+            LockStatus::HeldBy(pid) => {
+                if !offer_read_only(pid)? {
+                    return Ok(());
+                }
+                read_only = true;
+                None
+            }
+        }
+    };
 
     // The resource sync keeps the Ruby data repo fresh in the background.
+    // A missing checkout is cloned by the background task instead, so the UI can show
+    // progress for the initial (large) download. A `local_engine_path` is never synced.
+    // Read-only and validation runs must not clone or write the manifest, so they only
+    // read the one already on disk.
     let sync = ResourceSync::new(config.clone());
-    let metadata = sync.prepare().await?;
+    let metadata = if read_only || validate {
+        ResourceMetadata::load(manifest::manifest_path(sync.repo_path()))?.unwrap_or_default()
+    } else if sync.is_local() || sync.repo_path().exists() {
+        sync.prepare().await?
+    } else {
+        ResourceMetadata::default()
+    };
     let repo_path = sync.repo_path();
-    let loader = ResourceLoader::new(repo_path, metadata.clone());
-    let session_loader = SessionLoader::new(loader.root_path());
+    let loader = ResourceLoader::new(&repo_path, metadata.clone());
+    let mut session_loader = SessionLoader::new(loader.root_path());
+    session_loader.set_market_variant(config.market_variant.clone());
+
+    // `--validate` loads every game once and exits instead of starting the UI.
+    if validate {
+        if !repo_path.exists() {
+            bail!(
+                "engine checkout missing at {}; run 18tui once to clone it",
+                repo_path.display()
+            );
+        }
+        if session::probe_ruby(session::RUBY_PROGRAM).await.is_none() {
+            bail!("Ruby is required for --validate");
+        }
+        let passed = validate::run(&loader, session_loader, json).await?;
+        process::exit(if passed { 0 } else { 1 });
+    }
 
     // Wire the long-running sync task to a channel so we can surface progress in the UI.
     let (sync_tx, sync_rx) = mpsc::channel(8);
-    tokio::spawn(async move {
-        if let Err(err) = sync.run(sync_tx).await {
-            tracing::error!("Resource sync task error: {err}");
-        }
-    });
+    // The app owns the engine watcher so it can follow engine switches.
+    let watch_tx = (watch || config.watch_engine).then(|| sync_tx.clone());
+    if !read_only {
+        tokio::spawn(async move {
+            if let Err(err) = sync.run(sync_tx).await {
+                tracing::error!("Resource sync task error: {err}");
+            }
+        });
+    }
+
+    // Sessions are extracted by Ruby; probe once so the UI can explain a missing install.
+    let ruby_version = session::probe_ruby(session::RUBY_PROGRAM).await;
+    match &ruby_version {
+        Some(version) => tracing::info!(%version, "Ruby detected"),
+        None => tracing::warn!("Ruby not found; session loading disabled"),
+    }
 
-    let mut app = app::Tui18App::new(loader, metadata, session_loader);
+    let mut app = app::Tui18App::new(config, loader, metadata, session_loader);
     app.attach_sync(sync_rx);
+    if let Some(sender) = watch_tx {
+        app.watch_engine(sender);
+    }
+    app.set_ruby_available(ruby_version.is_some());
+    app.set_debug(debug);
+    app.set_read_only(read_only);
     app.run().await
 }
 
+/// The manifest of the engine checkout at `repo_path` as pretty JSON, or `None` when it
+/// has not been written yet.
+fn manifest_json(repo_path: &Path) -> Result<Option<String>> {
+    let Some(metadata) = ResourceMetadata::load(manifest::manifest_path(repo_path))? else {
+        return Ok(None);
+    };
+    Ok(Some(serde_json::to_string_pretty(&metadata)?))
+}
+
+/// Asks on the terminal whether to continue read-only while instance `pid` holds the lock.
+fn offer_read_only(pid: u32) -> Result<bool> {
+    eprint!("Another 18tui instance (pid {pid}) is running. Open read-only? [y/N] ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Puts the terminal back to normal before a panic message is printed, so it is readable
+/// and the shell is usable, then logs the panic and hands over to the default hook.
+///
+/// Only a panic on the main thread, which runs the UI loop, ends the app. Tokio catches
+/// panics in spawned tasks and the UI keeps running, so those leave the terminal and the
+/// instance lock alone.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() == Some("main") {
+            let _ = app::reset_terminal(&mut io::stdout());
+            lock::release_held();
+        }
+        tracing::error!(panic = %info, "Application panicked");
+        default_hook(info);
+    }));
+}
+
 /// Installs both stdout and file-based logging layers so tracing spans remain
-/// available while debugging user terminals or later via `logs/tui18.log`.
-fn init_logging() -> Result<()> {
-    let log_dir = std::env::current_dir()?.join("logs");
+/// available while debugging user terminals or later via `logs/tui18.log` (under
+/// `data_dir` when one is configured).
+fn init_logging(config: &AppConfig) -> Result<()> {
+    let log_dir = config.log_dir()?;
     fs::create_dir_all(&log_dir)?;
     let log_path = log_dir.join("tui18.log");
 
@@ -74,3 +206,26 @@ fn init_logging() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_json_prints_a_written_manifest() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(manifest_json(dir.path())?, None);
+
+        let metadata = ResourceMetadata {
+            commit: Some("abc1234".to_string()),
+            branch: Some("master".to_string()),
+            ..ResourceMetadata::default()
+        };
+        metadata.persist(manifest::manifest_path(dir.path()))?;
+        let json = manifest_json(dir.path())?.expect("manifest present");
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value["commit"], "abc1234");
+        assert_eq!(value["branch"], "master");
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! Hands URLs and paths to the desktop's default application.
+//!
+//! We shell out to the platform launcher (`xdg-open`, `open`, or `explorer`) instead of
+//! pulling in a crate; the child runs detached so the TUI never blocks on it.
+
+use std::{
+    ffi::{OsStr, OsString},
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+};
+
+use anyhow::{Context, Result};
+
+/// Builds the launcher invocation for `target` on the current platform.
+pub(crate) fn open_command(target: impl AsRef<OsStr>) -> Command {
+    let target = target.as_ref();
+    let mut command = if cfg!(target_os = "windows") {
+        // Not `cmd /C start`: cmd would interpret `&`, `|` and `^` in engine-supplied URLs.
+        let mut command = Command::new("explorer");
+        command.arg(target);
+        command
+    } else if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(target);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(target);
+        command
+    };
+    detach(&mut command);
+    command
+}
+
+/// Builds the file-manager invocation that shows `file`: selected in Finder or Explorer,
+/// its containing directory elsewhere since `xdg-open` cannot select.
+pub(crate) fn reveal_command(file: &Path) -> Command {
+    let mut command = if cfg!(target_os = "windows") {
+        // Explorer wants the flag and path as a single `/select,<path>` argument.
+        let mut select = OsString::from("/select,");
+        select.push(file);
+        let mut command = Command::new("explorer");
+        command.arg(select);
+        command
+    } else if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg("-R").arg(file);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(file.parent().unwrap_or(file));
+        command
+    };
+    detach(&mut command);
+    command
+}
+
+fn detach(command: &mut Command) {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+}
+
+/// Launches `target` with the default handler without waiting for it to exit.
+pub(crate) fn open_target(target: impl AsRef<OsStr>) -> Result<()> {
+    spawn(open_command(&target))
+}
+
+/// Shows `file` in the platform file manager without waiting for it to exit.
+pub(crate) fn reveal_file(file: &Path) -> Result<()> {
+    spawn(reveal_command(file))
+}
+
+fn spawn(mut command: Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("failed to run {program}"))?;
+    // Reap the launcher in the background so it doesn't linger as a zombie.
+    thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_platform_launcher() {
+        for url in [
+            "https://example.com/rules.pdf",
+            "https://example.com/rules?game=1889&page=2|3^4",
+        ] {
+            let command = open_command(url);
+            let program = command.get_program().to_string_lossy().into_owned();
+            let args: Vec<String> = command
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect();
+            // The URL is always one untouched argument, never shell input.
+            assert_eq!(args, [url]);
+            if cfg!(target_os = "windows") {
+                assert_eq!(program, "explorer");
+            } else if cfg!(target_os = "macos") {
+                assert_eq!(program, "open");
+            } else {
+                assert_eq!(program, "xdg-open");
+            }
+        }
+    }
+
+    #[test]
+    fn builds_platform_file_reveal() {
+        let file = Path::new("/saves/1889/evening.json");
+        let command = reveal_command(file);
+        let program = command.get_program().to_string_lossy().into_owned();
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        if cfg!(target_os = "windows") {
+            assert_eq!(program, "explorer");
+            assert_eq!(args, ["/select,/saves/1889/evening.json"]);
+        } else if cfg!(target_os = "macos") {
+            assert_eq!(program, "open");
+            assert_eq!(args, ["-R", "/saves/1889/evening.json"]);
+        } else {
+            assert_eq!(program, "xdg-open");
+            assert_eq!(args, ["/saves/1889"]);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! `--validate`: loads every discovered game through Ruby and reports the ones that fail.
+//!
+//! Games are loaded a few at a time; each result is funnelled through a channel so the
+//! progress readout on stderr stays in order while loads finish out of order.
+
+use std::{
+    io::{self, Write},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+use tokio::sync::{mpsc, Semaphore};
+use tui18_core::{resource::ResourceLoader, session::SessionLoader};
+
+/// How many Ruby processes run at once while validating.
+const VALIDATE_CONCURRENCY: usize = 4;
+
+/// Outcome of loading one game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct GameResult {
+    id: String,
+    folder: String,
+    /// Load error, `None` when the game loaded.
+    error: Option<String>,
+}
+
+/// Totals printed once every game has been checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Summary {
+    checked: usize,
+    passed: usize,
+    failed: usize,
+    /// Folders discovery skipped before validation (see `SkippedGame`).
+    skipped: usize,
+    elapsed_ms: u128,
+    failures: Vec<GameResult>,
+}
+
+impl Summary {
+    fn new(results: Vec<GameResult>, skipped: usize, elapsed: Duration) -> Self {
+        let checked = results.len();
+        let mut failures: Vec<GameResult> = results
+            .into_iter()
+            .filter(|result| result.error.is_some())
+            .collect();
+        failures.sort_by(|a, b| a.folder.cmp(&b.folder));
+        Self {
+            checked,
+            passed: checked - failures.len(),
+            failed: failures.len(),
+            skipped,
+            elapsed_ms: elapsed.as_millis(),
+            failures,
+        }
+    }
+
+    fn text(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Validated {} games in {:.1}s: {} passed, {} failed, {} skipped",
+            self.checked,
+            self.elapsed_ms as f64 / 1000.0,
+            self.passed,
+            self.failed,
+            self.skipped
+        )];
+        for failure in &self.failures {
+            let error = failure.error.as_deref().unwrap_or_default();
+            lines.push(format!("FAIL {}: {}", failure.folder, first_line(error)));
+        }
+        lines
+    }
+}
+
+/// Loads every game, printing progress to stderr and the summary to stdout (as JSON
+/// with `json`). Returns `true` when no game failed.
+pub(crate) async fn run(
+    loader: &ResourceLoader,
+    session_loader: SessionLoader,
+    json: bool,
+) -> Result<bool> {
+    let games = loader.games()?;
+    let skipped = loader.skipped_games().len();
+    let total = games.len();
+    let started = Instant::now();
+    let permits = Arc::new(Semaphore::new(VALIDATE_CONCURRENCY));
+    let session_loader = Arc::new(session_loader);
+    let (tx, mut rx) = mpsc::channel(VALIDATE_CONCURRENCY);
+
+    for game in games {
+        let permits = Arc::clone(&permits);
+        let session_loader = Arc::clone(&session_loader);
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let error = session_loader
+                .load(&game)
+                .await
+                .err()
+                .map(|err| format!("{err:#}"));
+            let _ = tx
+                .send(GameResult {
+                    id: game.id,
+                    folder: game.folder,
+                    error,
+                })
+                .await;
+        });
+    }
+    drop(tx);
+
+    let mut results = Vec::with_capacity(total);
+    let mut stderr = io::stderr();
+    while let Some(result) = rx.recv().await {
+        let line = progress_line(results.len() + 1, total, started.elapsed(), &result);
+        let _ = writeln!(stderr, "{line}");
+        results.push(result);
+    }
+
+    let summary = Summary::new(results, skipped, started.elapsed());
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        for line in summary.text() {
+            println!("{line}");
+        }
+    }
+    Ok(summary.failed == 0)
+}
+
+/// `[done/total] folder ok (elapsed)` for one finished game.
+fn progress_line(done: usize, total: usize, elapsed: Duration, result: &GameResult) -> String {
+    let outcome = if result.error.is_some() { "FAIL" } else { "ok" };
+    format!(
+        "[{done}/{total}] {} {outcome} ({:.1}s elapsed)",
+        result.folder,
+        elapsed.as_secs_f64()
+    )
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(folder: &str, error: Option<&str>) -> GameResult {
+        GameResult {
+            id: folder.trim_start_matches("g_").to_string(),
+            folder: folder.to_string(),
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn summary_counts_failures_and_serializes() {
+        let results = vec![
+            result("g_1889", None),
+            result("g_broken", Some("MARKET data missing\nbacktrace")),
+            result("g_1830", None),
+        ];
+        let summary = Summary::new(results, 2, Duration::from_millis(2500));
+        assert_eq!((summary.checked, summary.passed, summary.failed), (3, 2, 1));
+        assert_eq!(
+            summary.text(),
+            [
+                "Validated 3 games in 2.5s: 2 passed, 1 failed, 2 skipped",
+                "FAIL g_broken: MARKET data missing",
+            ]
+        );
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["elapsed_ms"], 2500);
+        assert_eq!(json["failures"][0]["folder"], "g_broken");
+    }
+
+    #[test]
+    fn progress_line_reports_position_and_elapsed() {
+        let line = progress_line(3, 10, Duration::from_millis(1300), &result("g_1889", None));
+        assert_eq!(line, "[3/10] g_1889 ok (1.3s elapsed)");
+        let line = progress_line(4, 10, Duration::from_secs(2), &result("g_x", Some("boom")));
+        assert_eq!(line, "[4/10] g_x FAIL (2.0s elapsed)");
+    }
+}